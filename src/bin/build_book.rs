@@ -0,0 +1,122 @@
+//! 기록된 대국으로 오프닝 정석 파일을 만들거나 늘리는 도구
+//!
+//! ```text
+//! cargo run --bin build_book -- [--size <가로> <세로>] [--depth <수>] [--book <정석 파일>] <대국 파일>...
+//! ```
+//!
+//! 대국 파일은 한 줄에 한 대국씩 흑부터 둔 좌표를 공백으로 나누어 적습니다 (예: `h8 i9 j8 i8`,
+//! 차례를 넘긴 수는 `pass`). 화면이나 토너먼트에서 저장한 좌표 표기 기보도 그대로 읽습니다.
+//! `#`부터 줄 끝까지는 주석입니다. 확장자가 `.lib`인 파일은 RenLib 라이브러리로 읽어
+//! 변화도의 모든 수순(뿌리에서 잎까지)을 한 대국씩으로 봅니다.
+//! 각 대국의 처음 `--depth`수(기본 12수)를 정석에 더하며,
+//! `--book`의 파일이 있으면 그 정석에 더해 같은 파일에 저장하고, 없으면 표준 출력으로 내보냅니다.
+
+use std::process::ExitCode;
+
+use bevy_examples::the_devils_plan::blind_gomoku::book::OpeningBook;
+use bevy_examples::the_devils_plan::blind_gomoku::engine::DEFAULT_BOARD_SIZE;
+use bevy_examples::the_devils_plan::blind_gomoku::record;
+use bevy_examples::the_devils_plan::blind_gomoku::renlib::Library;
+
+/// 대국마다 정석에 더할 기본 수
+const DEFAULT_DEPTH: usize = 12;
+
+/// 명령행 인자
+struct Args {
+    size: (usize, usize), // 판 크기 (새 정석을 만들 때만 사용)
+    depth: usize,         // 대국마다 정석에 더할 수
+    book: Option<String>, // 늘릴 정석 파일 경로
+    games: Vec<String>,   // 대국 파일 경로
+}
+
+impl Args {
+    /// 명령행 인자를 읽음
+    fn parse() -> Result<Self, String> {
+        let mut args = Args {
+            size: (DEFAULT_BOARD_SIZE, DEFAULT_BOARD_SIZE),
+            depth: DEFAULT_DEPTH,
+            book: None,
+            games: Vec::new(),
+        };
+        let mut iter = std::env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            let mut value = |name: &str| iter.next().ok_or(format!("missing value for {name}"));
+            match arg.as_str() {
+                "--size" => {
+                    let width = value("--size")?;
+                    let height = value("--size")?;
+                    args.size = (
+                        width.parse().map_err(|_| "invalid --size width")?,
+                        height.parse().map_err(|_| "invalid --size height")?,
+                    );
+                }
+                "--depth" => {
+                    args.depth = value("--depth")?.parse().map_err(|_| "invalid --depth")?;
+                }
+                "--book" => args.book = Some(value("--book")?),
+                _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+                _ => args.games.push(arg),
+            }
+        }
+        if args.games.is_empty() {
+            return Err("no game files given".to_string());
+        }
+        Ok(args)
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    // 기존 정석이 있으면 이어서 늘림
+    let mut book = match &args.book {
+        Some(path) if std::path::Path::new(path).exists() => {
+            let text = std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+            OpeningBook::parse(&text).map_err(|err| format!("{path}: {err}"))?
+        }
+        _ => OpeningBook::new(args.size.0, args.size.1),
+    };
+
+    let mut games = 0;
+    for path in &args.games {
+        if path.ends_with(".lib") {
+            let bytes = std::fs::read(path).map_err(|err| format!("{path}: {err}"))?;
+            let library = Library::parse(&bytes).map_err(|err| format!("{path}: {err}"))?;
+            for line in library.lines() {
+                book.add_game(&line, args.depth);
+                games += 1;
+            }
+            continue;
+        }
+
+        let text = std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let moves = record::parse_moves(line)
+                .map_err(|point| format!("{path}:{}: invalid point \"{point}\"", index + 1))?;
+            book.add_game(&moves, args.depth);
+            games += 1;
+        }
+    }
+
+    match &args.book {
+        Some(path) => {
+            std::fs::write(path, book.to_text()).map_err(|err| format!("{path}: {err}"))?;
+            eprintln!("{path}: {games} games added, {} positions", book.len());
+        }
+        None => print!("{}", book.to_text()),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse().and_then(|args| run(&args));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("build_book: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Gomocup (Piskvork) 프로토콜로 동작하는 화면 없는 오목 엔진
+//!
+//! ```text
+//! cargo run --release --bin pbrain
+//! ```
+//!
+//! 표준 입력으로 명령을 한 줄씩 받아 표준 출력으로 응답합니다. 명령은 `protocol` 모듈을 참고하세요.
+
+use std::io::{self, BufRead, Write};
+
+use bevy_examples::the_devils_plan::blind_gomoku::protocol::Brain;
+
+fn main() -> io::Result<()> {
+    let mut brain = Brain::new();
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        for reply in brain.handle(&line?) {
+            writeln!(stdout, "{reply}")?;
+        }
+        // 관리 프로그램이 바로 읽을 수 있도록 줄마다 내보냄
+        stdout.flush()?;
+        if brain.is_finished() {
+            break;
+        }
+    }
+    Ok(())
+}
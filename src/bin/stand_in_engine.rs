@@ -0,0 +1,171 @@
+//! 외부 엔진 연결을 시험하기 위한 아주 단순한 Gomocup (Piskvork) 엔진
+//!
+//! ```text
+//! cargo run --bin stand_in_engine -- [--delay <밀리초>] [--crash-after <수>]
+//! ```
+//!
+//! 판 가운데에 가장 가까운 빈 자리에 두며, 수마다 MESSAGE 줄로 생각한 내용을 알립니다.
+//! `--delay`로 응답을 늦춰 시간 초과를, `--crash-after`로 그 수만큼 둔 뒤 응답 없이 끝나
+//! 비정상 종료를 시험할 수 있습니다. 게임의 규칙 엔진을 쓰지 않으므로 금수는 모릅니다.
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+/// 명령행 설정
+#[derive(Default)]
+struct Options {
+    delay: Duration,            // 수마다 응답 전에 기다리는 시간
+    crash_after: Option<usize>, // 이만큼 둔 다음 수에서 응답 없이 종료
+}
+
+impl Options {
+    fn parse() -> Self {
+        let mut options = Options::default();
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let value = args.next().and_then(|value| value.parse::<u64>().ok());
+            match (arg.as_str(), value) {
+                ("--delay", Some(millis)) => options.delay = Duration::from_millis(millis),
+                ("--crash-after", Some(moves)) => options.crash_after = Some(moves as usize),
+                _ => eprintln!("stand_in_engine: ignoring argument {arg}"),
+            }
+        }
+        options
+    }
+}
+
+/// 엔진 상태 (칸마다 0 = 빈칸, 1 = 자신, 2 = 상대)
+struct StandIn {
+    options: Options,
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+    played: usize,  // 지금까지 둔 수
+    in_board: bool, // BOARD 명령의 돌 목록을 받는 중인지 여부
+}
+
+impl StandIn {
+    /// 명령 한 줄에 대한 응답 (`None`이면 종료)
+    fn handle(&mut self, line: &str) -> Option<Vec<String>> {
+        let line = line.trim();
+        if self.in_board {
+            if line.eq_ignore_ascii_case("DONE") {
+                self.in_board = false;
+                return self.think();
+            }
+            if let [x, y, field] = numbers(line)[..] {
+                self.set(x, y, field as u8);
+            }
+            return Some(Vec::new());
+        }
+
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let replies = match command.to_ascii_uppercase().as_str() {
+            "START" | "RECTSTART" => match numbers(args)[..] {
+                [size] => self.start(size, size),
+                [width, height] => self.start(width, height),
+                _ => vec!["ERROR invalid size".to_string()],
+            },
+            "RESTART" => self.start(self.width, self.height),
+            "BEGIN" => return self.think(),
+            "TURN" => {
+                if let [x, y] = numbers(args)[..] {
+                    self.set(x, y, 2);
+                }
+                return self.think();
+            }
+            "BOARD" => {
+                self.cells.fill(0);
+                self.in_board = true;
+                Vec::new()
+            }
+            "TAKEBACK" => {
+                if let [x, y] = numbers(args)[..] {
+                    self.set(x, y, 0);
+                }
+                vec!["OK".to_string()]
+            }
+            "INFO" => Vec::new(),
+            "ABOUT" => {
+                vec!["name=\"stand-in\", version=\"1.0\", author=\"bevy_examples\"".to_string()]
+            }
+            "END" => return None,
+            _ => vec![format!("UNKNOWN {command}")],
+        };
+        Some(replies)
+    }
+
+    fn start(&mut self, width: usize, height: usize) -> Vec<String> {
+        if width == 0 || height == 0 {
+            return vec!["ERROR invalid size".to_string()];
+        }
+        (self.width, self.height) = (width, height);
+        self.cells = vec![0; width * height];
+        vec!["OK".to_string()]
+    }
+
+    fn set(&mut self, x: usize, y: usize, field: u8) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = field;
+        }
+    }
+
+    /// 가운데에 가장 가까운 빈 자리에 둠
+    fn think(&mut self) -> Option<Vec<String>> {
+        if self.crash_due() {
+            return None;
+        }
+        std::thread::sleep(self.options.delay);
+
+        let (cx, cy) = (self.width / 2, self.height / 2);
+        let best = (0..self.width * self.height)
+            .filter(|&index| self.cells[index] == 0)
+            .map(|index| (index % self.width, index / self.width))
+            .min_by_key(|&(x, y)| x.abs_diff(cx).pow(2) + y.abs_diff(cy).pow(2));
+        let Some((x, y)) = best else {
+            return Some(vec!["ERROR board is full".to_string()]);
+        };
+        self.set(x, y, 1);
+        self.played += 1;
+        Some(vec![
+            format!("MESSAGE move {} closest to the center", self.played),
+            format!("{x},{y}"),
+        ])
+    }
+
+    /// `--crash-after`만큼 두었는지 확인
+    fn crash_due(&self) -> bool {
+        self.options
+            .crash_after
+            .is_some_and(|limit| self.played >= limit)
+    }
+}
+
+/// `,` 또는 공백으로 나눈 숫자 목록
+fn numbers(text: &str) -> Vec<usize> {
+    text.split([',', ' '])
+        .filter_map(|value| value.trim().parse().ok())
+        .collect()
+}
+
+fn main() -> io::Result<()> {
+    let mut engine = StandIn {
+        options: Options::parse(),
+        width: 0,
+        height: 0,
+        cells: Vec::new(),
+        played: 0,
+        in_board: false,
+    };
+    let mut stdout = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let Some(replies) = engine.handle(&line?) else {
+            break;
+        };
+        for reply in replies {
+            writeln!(stdout, "{reply}")?;
+        }
+        stdout.flush()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//! 창을 열지 않고 AI 설정과 외부 엔진끼리 대국을 진행하는 토너먼트 도구
+//!
+//! ```text
+//! cargo run --release --bin tournament -- [옵션] <참가자> <참가자>...
+//!
+//! 참가자: ai:<난이도>[:belief] | engine:<실행 파일>
+//! 옵션:
+//!   --format round-robin|gauntlet   대진 방식 (기본 round-robin)
+//!   --games <수>                    짝마다 둘 대국 수, 먼저 두는 쪽을 번갈아 바꿈 (기본 2)
+//!   --size <가로> <세로>            판 크기 (기본 15 15)
+//!   --rule <규칙>                   freestyle, standard, renju, caro (기본 freestyle)
+//!   --opening <오프닝>              none, swap, swap2, soosorv-8, taraguchi-10 (기본 none)
+//!   --blind                         블라인드 규칙으로 대국 (참가자는 자신의 시야만 봄)
+//!   --collision <규칙>              lose-turn, reveal, reveal-both, strikes[-<한도>] (기본 reveal)
+//!   --timeout-ms <밀리초>           외부 엔진의 한 수 시간 제한 (기본 5000)
+//!   --seed <수>                     오프닝과 AI 난수 시드 (기본 0)
+//!   --out <파일>                    기보 파일 (확장자가 `.sgf`이면 SGF, 아니면 좌표 표기)
+//! ```
+//!
+//! 대국이 끝날 때마다 결과를 표준 오류로, 모든 대국이 끝나면 순위표를 표준 출력으로 내보냅니다.
+
+use std::fmt::Write as _;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use bevy_examples::the_devils_plan::blind_gomoku::external::EngineLog;
+use bevy_examples::the_devils_plan::blind_gomoku::record::{self, Record};
+use bevy_examples::the_devils_plan::blind_gomoku::rng;
+use bevy_examples::the_devils_plan::blind_gomoku::tournament::{
+    self, Format, PlayerSpec, Settings,
+};
+
+/// 명령행 인자
+struct Args {
+    format: Format,           // 대진 방식
+    games: usize,             // 짝마다 둘 대국 수
+    settings: Settings,       // 대국 설정
+    seed: u64,                // 난수 시드
+    out: Option<String>,      // 기보 파일
+    players: Vec<PlayerSpec>, // 참가자
+}
+
+impl Args {
+    /// 명령행 인자를 읽음
+    fn parse() -> Result<Self, String> {
+        let mut args = Args {
+            format: Format::default(),
+            games: 2,
+            settings: Settings::default(),
+            seed: 0,
+            out: None,
+            players: Vec::new(),
+        };
+        let mut iter = std::env::args().skip(1);
+        while let Some(arg) = iter.next() {
+            let mut value = |name: &str| iter.next().ok_or(format!("missing value for {name}"));
+            match arg.as_str() {
+                "--format" => args.format = value("--format")?.parse()?,
+                "--games" => {
+                    args.games = value("--games")?.parse().map_err(|_| "invalid --games")?;
+                }
+                "--size" => {
+                    let width = value("--size")?;
+                    let height = value("--size")?;
+                    args.settings.width = width.parse().map_err(|_| "invalid --size width")?;
+                    args.settings.height = height.parse().map_err(|_| "invalid --size height")?;
+                }
+                "--rule" => args.settings.rule_set = value("--rule")?.parse()?,
+                "--opening" => args.settings.opening = value("--opening")?.parse()?,
+                "--blind" => args.settings.blind = true,
+                "--collision" => args.settings.collision = value("--collision")?.parse()?,
+                "--timeout-ms" => {
+                    let millis = value("--timeout-ms")?
+                        .parse()
+                        .map_err(|_| "invalid --timeout-ms")?;
+                    args.settings.engine_timeout = Duration::from_millis(millis);
+                }
+                "--seed" => args.seed = value("--seed")?.parse().map_err(|_| "invalid --seed")?,
+                "--out" => args.out = Some(value("--out")?),
+                _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+                _ => args.players.push(arg.parse()?),
+            }
+        }
+        if args.players.len() < 2 {
+            return Err("at least two players are required".to_string());
+        }
+        Ok(args)
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let settings = &args.settings;
+    let log = EngineLog::default();
+    let names: Vec<String> = args.players.iter().map(ToString::to_string).collect();
+
+    let sgf = args.out.as_ref().is_some_and(|path| path.ends_with(".sgf"));
+    let mut text = if sgf {
+        String::new()
+    } else {
+        format!(
+            "# tournament: {}, {}, opening {}, {}x{}, blind {}, seed {}\n",
+            args.format,
+            settings.rule_set,
+            settings.opening,
+            settings.width,
+            settings.height,
+            if settings.blind { "on" } else { "off" },
+            args.seed
+        )
+    };
+    let pairings = tournament::schedule(args.format, args.players.len(), args.games);
+    let mut records = Vec::new();
+    for (number, &(first, second)) in pairings.iter().enumerate() {
+        let seed = rng::mix(args.seed ^ number as u64);
+        let started = record::now();
+        let record = tournament::play_game(&args.players, first, second, settings, seed, &log);
+        let summary = format!(
+            "game {}/{}: {} (black) vs {} (white): {} ({}, {} moves)",
+            number + 1,
+            pairings.len(),
+            names[record.black],
+            names[record.white],
+            record::format_result(record.result),
+            record.reason,
+            record.moves.len()
+        );
+        eprintln!("{summary}");
+
+        let game = Record {
+            black: names[record.black].clone(),
+            white: names[record.white].clone(),
+            moves: record.moves.clone(),
+            result: record.result,
+            started: Some(started),
+            finished: Some(record::now()),
+            ..Record::new(settings.width, settings.height, settings.rule_set)
+        };
+        if sgf {
+            text += &game.to_sgf();
+        } else {
+            let _ = write!(text, "\n# {summary}\n{}", game.to_text());
+        }
+        records.push(record);
+    }
+
+    if let Some(path) = &args.out {
+        std::fs::write(path, text).map_err(|err| format!("{path}: {err}"))?;
+    }
+
+    // 순위표 (Elo 순)
+    let standings = tournament::standings(args.players.len(), &records);
+    let mut order: Vec<usize> = (0..standings.len()).collect();
+    order.sort_by(|&a, &b| standings[b].elo.total_cmp(&standings[a].elo));
+    let width = names.iter().map(String::len).max().unwrap_or(0).max(6);
+    println!(
+        "{:>3}  {:<width$}  {:>5}  {:>4}  {:>5}  {:>6}  {:>6}  {:>5}",
+        "#", "player", "games", "wins", "draws", "losses", "score", "elo"
+    );
+    for (rank, &player) in order.iter().enumerate() {
+        let standing = &standings[player];
+        println!(
+            "{:>3}  {:<width$}  {:>5}  {:>4}  {:>5}  {:>6}  {:>5.1}%  {:>+5.0}",
+            rank + 1,
+            names[player],
+            standing.games,
+            standing.wins,
+            standing.draws,
+            standing.losses,
+            standing.score_rate() * 100.0,
+            standing.elo.round() + 0.0 // -0 대신 0으로 표시
+        );
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse().and_then(|args| run(&args));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("tournament: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,902 @@
+//! 오목 AI (알파-베타 탐색)
+//!
+//! 패턴 기반 평가 함수와 반복 심화 알파-베타 탐색으로 다음 수를 고릅니다.
+//! Bevy에 의존하지 않으며, 탐색은 호출한 스레드에서 그대로 실행되므로
+//! 화면이 멈추지 않게 하려면 별도의 작업 스레드에서 호출해야 합니다.
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use super::engine::{Board, StoneColor, DIRECTIONS};
+use super::rng::{self, Rng};
+use super::rules::RuleSet;
+use super::zobrist::{self, TranspositionTable};
+
+// ==================== 탐색 설정 ====================
+/// 탐색 한도
+///
+/// 기본적으로 시간 한도로 탐색을 멈추므로 컴퓨터의 속도나 부하에 따라 결과가 달라질 수 있습니다.
+/// `max_nodes`를 정하면 시간 대신 노드 수로 멈추므로 같은 시드에서 항상 같은 수를 고릅니다.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SearchLimits {
+    pub max_depth: u32,         // 반복 심화의 최대 깊이
+    pub time_limit: Duration,   // 한 수에 쓸 수 있는 최대 시간
+    pub max_candidates: usize,  // 한 노드에서 살펴볼 최대 후보 수
+    pub max_nodes: Option<u64>, // 방문할 최대 노드 수 (정하면 시간 한도는 쓰지 않음)
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 10,
+            time_limit: Duration::from_millis(1000),
+            max_candidates: 12,
+            max_nodes: None,
+        }
+    }
+}
+
+impl SearchLimits {
+    /// 시간 한도 대신 `max_nodes`개의 노드까지만 탐색하는 한도 (재현이 필요한 테스트나 대국용)
+    pub fn with_node_budget(self, max_nodes: u64) -> Self {
+        Self {
+            max_nodes: Some(max_nodes),
+            ..self
+        }
+    }
+}
+
+// ==================== 난이도 ====================
+/// AI 난이도 프리셋
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Difficulty {
+    /// 입문: 바로 앞만 보고 실수가 잦음
+    Beginner,
+    /// 쉬움
+    Easy,
+    /// 보통
+    #[default]
+    Normal,
+    /// 어려움: 실수 없이 깊게 탐색
+    Hard,
+    /// 전문가: 가장 깊고 오래 탐색
+    Expert,
+}
+
+impl Difficulty {
+    /// 쉬운 것부터 어려운 것 순서의 모든 난이도
+    pub const ALL: [Difficulty; 5] = [
+        Difficulty::Beginner,
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Expert,
+    ];
+
+    /// 난이도에 해당하는 AI 설정
+    ///
+    /// 모든 프리셋은 노드 수 한도로 멈추므로 컴퓨터 속도와 관계없이 같은 시드에서 같은 수를 둡니다.
+    /// 노드 수 한도는 보통 속도의 컴퓨터에서 시간 한도와 비슷하게 걸리도록 정했으며, 시간 한도는
+    /// 노드 수 한도를 지운 호출자(예: 외부 엔진 프로토콜)를 위해 남겨 둡니다.
+    pub fn profile(self) -> AiProfile {
+        let (max_depth, millis, max_nodes, max_candidates, eval_noise, blunder_rate) = match self {
+            Difficulty::Beginner => (1, 200, 3_000, 8, 2_000, 30),
+            Difficulty::Easy => (2, 300, 5_000, 10, 500, 15),
+            Difficulty::Normal => (4, 500, 8_000, 12, 100, 5),
+            Difficulty::Hard => (6, 1_000, 16_000, 12, 0, 0),
+            Difficulty::Expert => (10, 2_000, 32_000, 15, 0, 0),
+        };
+        AiProfile {
+            limits: SearchLimits {
+                max_depth,
+                time_limit: Duration::from_millis(millis),
+                max_candidates,
+                max_nodes: Some(max_nodes),
+            },
+            eval_noise,
+            blunder_rate,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difficulty::Beginner => write!(f, "Beginner"),
+            Difficulty::Easy => write!(f, "Easy"),
+            Difficulty::Normal => write!(f, "Normal"),
+            Difficulty::Hard => write!(f, "Hard"),
+            Difficulty::Expert => write!(f, "Expert"),
+        }
+    }
+}
+
+impl std::str::FromStr for Difficulty {
+    type Err = String;
+
+    /// 표시 이름을 대소문자 구분 없이 읽음 (예: `hard`)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string().eq_ignore_ascii_case(text))
+            .ok_or_else(|| format!("unknown difficulty \"{text}\""))
+    }
+}
+
+/// 난이도를 결정하는 AI 설정
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AiProfile {
+    pub limits: SearchLimits, // 탐색 깊이와 시간 한도
+    pub eval_noise: i32,      // 평가 점수에 더하는 잡음의 최대 크기
+    pub blunder_rate: u32,    // 일부러 최선이 아닌 수를 둘 확률 (%)
+}
+
+/// 탐색 결과
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SearchResult {
+    pub x: usize,   // 고른 수의 x 좌표
+    pub y: usize,   // 고른 수의 y 좌표
+    pub score: i32, // 탐색한 플레이어 기준 평가 점수
+    pub depth: u32, // 끝까지 탐색한 깊이
+    pub nodes: u64, // 방문한 노드 수
+}
+
+// ==================== 평가 함수 ====================
+/// 승리가 확정된 국면의 점수 (빨리 이길수록 높음)
+pub const WIN_SCORE: i32 = 10_000_000;
+
+/// 이 값보다 절댓값이 큰 점수는 승패가 확정된 점수로 봄 (수순 길이만큼 `WIN_SCORE`에서 빠짐)
+const DECIDED_SCORE: i32 = WIN_SCORE - 1_000;
+
+/// 한 줄에서 찾는 모양과 점수 (1: 내 돌, 0: 빈칸)
+///
+/// 모양은 겹쳐서 셀 수 있으므로 열린 모양일수록 점수가 더 커집니다.
+const SHAPES: &[(&[u8], i32)] = &[
+    // 5목
+    (&[1, 1, 1, 1, 1], 1_000_000),
+    // 열린 4
+    (&[0, 1, 1, 1, 1, 0], 100_000),
+    // 막힌 4, 끊어진 4
+    (&[1, 1, 1, 1, 0], 10_000),
+    (&[0, 1, 1, 1, 1], 10_000),
+    (&[1, 0, 1, 1, 1], 10_000),
+    (&[1, 1, 0, 1, 1], 10_000),
+    (&[1, 1, 1, 0, 1], 10_000),
+    // 열린 3, 끊어진 열린 3
+    (&[0, 1, 1, 1, 0, 0], 1_000),
+    (&[0, 0, 1, 1, 1, 0], 1_000),
+    (&[0, 1, 0, 1, 1, 0], 1_000),
+    (&[0, 1, 1, 0, 1, 0], 1_000),
+    // 막힌 3
+    (&[1, 1, 1, 0, 0], 100),
+    (&[0, 0, 1, 1, 1], 100),
+    (&[1, 0, 1, 1, 0], 100),
+    (&[0, 1, 1, 0, 1], 100),
+    (&[1, 1, 0, 1, 0], 100),
+    (&[0, 1, 0, 1, 1], 100),
+    (&[1, 0, 1, 0, 1], 100),
+    // 열린 2
+    (&[0, 0, 1, 1, 0, 0], 50),
+    (&[0, 1, 0, 1, 0, 0], 30),
+    (&[0, 0, 1, 0, 1, 0], 30),
+    // 막힌 2
+    (&[1, 1, 0, 0, 0], 10),
+    (&[0, 0, 0, 1, 1], 10),
+];
+
+/// 한 칸의 상태 (빈칸 0, 내 돌 1, 상대 돌이나 판 밖 2)를 2비트씩 담은 6칸 창의 점수표
+///
+/// 창의 첫 칸에서 시작하는 모양의 점수만 더하므로, 줄을 한 칸씩 밀면서
+/// 모든 창의 점수를 더하면 각 모양을 정확히 한 번씩 세게 됩니다.
+fn shape_table() -> &'static [i32] {
+    static TABLE: OnceLock<Vec<i32>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        (0..1usize << 12)
+            .map(|code| {
+                let cells: Vec<u8> = (0..6).map(|i| ((code >> (2 * i)) & 3) as u8).collect();
+                SHAPES
+                    .iter()
+                    .filter(|(shape, _)| cells.starts_with(shape))
+                    .map(|&(_, score)| score)
+                    .sum()
+            })
+            .collect()
+    })
+}
+
+/// (x, y)를 지나는 한 방향의 줄 전체에서 `color`가 만든 모양의 점수
+fn line_score(board: &Board, x: usize, y: usize, dx: i32, dy: i32, color: StoneColor) -> i32 {
+    // 줄의 시작점으로 이동
+    let (mut sx, mut sy) = (x as i32, y as i32);
+    while board.in_bounds(sx - dx, sy - dy) {
+        sx -= dx;
+        sy -= dy;
+    }
+
+    let cell = |k: i32| -> usize {
+        let (cx, cy) = (sx + dx * k, sy + dy * k);
+        if !board.in_bounds(cx, cy) {
+            return 2;
+        }
+        match board.get(cx as usize, cy as usize) {
+            None => 0,
+            Some(stone) if stone == color => 1,
+            Some(_) => 2,
+        }
+    };
+
+    // 판 밖 한 칸(-1)부터 6칸 창을 한 칸씩 밀면서 점수 합산
+    let table = shape_table();
+    let mut code = (0..6).fold(0, |code, i| code | cell(i - 1) << (2 * i));
+    let mut score = 0;
+    let mut k = -1;
+    while k == -1 || board.in_bounds(sx + dx * k, sy + dy * k) {
+        score += table[code];
+        code = (code >> 2) | cell(k + 6) << 10;
+        k += 1;
+    }
+    score
+}
+
+/// (x, y)를 지나는 네 방향의 줄에서 흑의 점수에서 백의 점수를 뺀 값
+fn lines_around(board: &Board, x: usize, y: usize) -> i32 {
+    DIRECTIONS
+        .iter()
+        .map(|&(dx, dy)| {
+            line_score(board, x, y, dx, dy, StoneColor::Black)
+                - line_score(board, x, y, dx, dy, StoneColor::White)
+        })
+        .sum()
+}
+
+/// 판 전체를 평가 (흑 기준, 양수면 흑이 유리)
+pub fn evaluate(board: &Board) -> i32 {
+    let mut score = 0;
+    for &(dx, dy) in &DIRECTIONS {
+        // 각 줄의 시작점에서 한 번씩만 계산
+        for (x, y) in board.points() {
+            if !board.in_bounds(x as i32 - dx, y as i32 - dy) {
+                score += line_score(board, x, y, dx, dy, StoneColor::Black)
+                    - line_score(board, x, y, dx, dy, StoneColor::White);
+            }
+        }
+    }
+    score
+}
+
+// ==================== 탐색 ====================
+/// 난이도 설정에 따라 `color`가 둘 수를 고름
+///
+/// 같은 `seed`에서는 항상 같은 수를 고릅니다. 난이도 프리셋은 노드 수 한도를 쓰므로 그대로
+/// 재현되지만, 노드 수 한도를 지우고 시간 한도로만 탐색하면 중단 시점에 따라 결과가 달라질 수 있습니다.
+pub fn choose_move(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    profile: &AiProfile,
+    seed: u64,
+) -> Option<SearchResult> {
+    let mut rng = Rng::new(seed);
+
+    // 일부러 실수: 최선의 후보를 뺀 나머지 후보 중 하나를 무작위로 고름
+    if !board.is_empty() && rng.chance(profile.blunder_rate) {
+        let mut searcher = Searcher::new(board, rule_set, &profile.limits, Noise::default());
+        let moves = searcher.candidates(color);
+        if moves.len() > 1 {
+            let (x, y) = moves[1 + rng.below(moves.len() - 1)];
+            return Some(SearchResult {
+                x,
+                y,
+                score: 0,
+                depth: 0,
+                nodes: 0,
+            });
+        }
+    }
+
+    let noise = Noise {
+        amount: profile.eval_noise,
+        seed: rng.next_u64(),
+    };
+    search_with_noise(board, rule_set, color, &profile.limits, noise)
+}
+
+/// `color`가 둘 차례인 `board`에서 가장 좋은 수를 찾음
+///
+/// 돌이 놓인 자리 근처(두 칸 이내)의 빈 자리만 후보로 보며, 둘 곳이 없으면 `None`을 반환합니다.
+pub fn search(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    limits: &SearchLimits,
+) -> Option<SearchResult> {
+    search_with_noise(board, rule_set, color, limits, Noise::default())
+}
+
+/// `color`가 둘 차례인 `board`에서 유망한 후보 수를 좋은 순서로 최대 `max_candidates`개 반환
+///
+/// 탐색과 같은 기준(돌 근처의 빈 자리, 금수 제외)으로 고릅니다.
+pub fn candidates(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    max_candidates: usize,
+) -> Vec<(usize, usize)> {
+    let limits = SearchLimits {
+        max_candidates,
+        ..SearchLimits::default()
+    };
+    Searcher::new(board, rule_set, &limits, Noise::default()).candidates(color)
+}
+
+/// 여러 후보 수를 함께 평가한 국면 분석 결과
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Analysis {
+    pub moves: Vec<SearchResult>, // 좋은 순서의 후보 수 (점수는 둘 차례인 쪽 기준)
+    pub score: i32,               // 국면의 평가 점수 (흑 기준, 양수면 흑이 유리)
+}
+
+/// `color`가 둘 차례인 `board`에서 가장 좋은 후보 수를 최대 `count`개 골라 점수와 함께 반환
+///
+/// 최선의 수 하나만 찾는 `search`와 달리 모든 루트 후보를 좁히지 않은 창으로 탐색하므로
+/// 후보마다 정확한 점수를 얻는 대신 같은 시간에 덜 깊게 탐색합니다.
+pub fn analyze(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    limits: &SearchLimits,
+    count: usize,
+) -> Analysis {
+    let sign = match color {
+        StoneColor::Black => 1,
+        StoneColor::White => -1,
+    };
+    if board.is_empty() {
+        let best = search(board, rule_set, color, limits);
+        return Analysis {
+            moves: best.into_iter().take(count).collect(),
+            score: 0,
+        };
+    }
+
+    let mut searcher = Searcher::new(board, rule_set, limits, Noise::default());
+    searcher.table = TranspositionTable::new(TABLE_CAPACITY);
+    let moves = searcher.candidates(color);
+    let mut results: Vec<SearchResult> = Vec::new();
+    for depth in 1..=limits.max_depth.max(1) {
+        // 첫 깊이는 시간과 관계없이 끝까지 탐색
+        searcher.stopped = false;
+        let mut scored = Vec::with_capacity(moves.len());
+        for &(x, y) in &moves {
+            let score = searcher.score_move(
+                x,
+                y,
+                color,
+                depth,
+                -WIN_SCORE - 1,
+                WIN_SCORE + 1,
+                1,
+                depth > 1,
+            );
+            if searcher.stopped {
+                break;
+            }
+            scored.push(SearchResult {
+                x,
+                y,
+                score,
+                depth,
+                nodes: searcher.nodes,
+            });
+        }
+        if searcher.stopped {
+            break; // 시간 초과 (이전 깊이의 결과 사용)
+        }
+        scored.sort_by_key(|result| Reverse(result.score));
+        results = scored;
+
+        // 최선의 수로 승부가 확정되었으면 더 깊이 볼 필요 없음
+        if results
+            .first()
+            .is_some_and(|best| best.score.abs() >= WIN_SCORE - depth as i32)
+        {
+            break;
+        }
+    }
+
+    // 둘 곳이 없으면 판의 평가 점수를 그대로 사용
+    let score = results
+        .first()
+        .map_or(searcher.score, |best| sign * best.score);
+    results.truncate(count);
+    Analysis {
+        moves: results,
+        score,
+    }
+}
+
+/// 평가 점수를 흑이 유리한 정도(0.0~1.0, 0.5면 균형)로 변환 (평가 막대 표시용)
+///
+/// 승패가 확정된 점수는 0.0 또는 1.0이 되며, 나머지는 로지스틱 곡선을 따릅니다.
+pub fn advantage(score: i32) -> f32 {
+    if score >= DECIDED_SCORE {
+        return 1.0;
+    }
+    if score <= -DECIDED_SCORE {
+        return 0.0;
+    }
+    1.0 / (1.0 + (-score as f32 / ADVANTAGE_SCALE).exp())
+}
+
+/// `advantage`의 로지스틱 곡선 폭 (이 점수 차이에서 약 0.73)
+const ADVANTAGE_SCALE: f32 = 2_000.0;
+
+/// 평가 점수에 잡음을 섞어 탐색
+fn search_with_noise(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    limits: &SearchLimits,
+    noise: Noise,
+) -> Option<SearchResult> {
+    // 빈 판이면 정중앙
+    if board.is_empty() {
+        return Some(SearchResult {
+            x: board.width() / 2,
+            y: board.height() / 2,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        });
+    }
+
+    let mut searcher = Searcher::new(board, rule_set, limits, noise);
+    searcher.table = TranspositionTable::new(TABLE_CAPACITY);
+    let mut moves = searcher.candidates(color);
+    let mut best: Option<SearchResult> = None;
+    for depth in 1..=limits.max_depth.max(1) {
+        let Some((index, score)) = searcher.search_root(&moves, depth, color) else {
+            break; // 시간 초과 (이전 깊이의 결과 사용)
+        };
+
+        // 다음 깊이에서는 이번 최선의 수를 가장 먼저 탐색
+        let (x, y) = moves.remove(index);
+        moves.insert(0, (x, y));
+        best = Some(SearchResult {
+            x,
+            y,
+            score,
+            depth,
+            nodes: searcher.nodes,
+        });
+
+        // 승부가 확정되었으면 더 깊이 볼 필요 없음
+        if score.abs() >= WIN_SCORE - depth as i32 {
+            break;
+        }
+    }
+    best
+}
+
+/// 평가 점수에 섞는 잡음 설정
+///
+/// 잡음은 판 상태와 시드만으로 정해지므로 탐색 순서와 관계없이 같은 국면에서는 항상 같습니다.
+#[derive(Clone, Copy, Default)]
+struct Noise {
+    amount: i32, // 잡음의 최대 크기 (0이면 잡음 없음)
+    seed: u64,   // 잡음 시드
+}
+
+/// 한 번의 탐색에서 사용하는 치환표의 크기
+const TABLE_CAPACITY: usize = 1 << 16;
+
+/// 치환표에 저장된 점수의 종류
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    Exact, // 정확한 점수
+    Lower, // 베타 컷으로 끝나 실제 점수는 이보다 크거나 같음
+    Upper, // 알파를 넘지 못해 실제 점수는 이보다 작거나 같음
+}
+
+/// 치환표에 저장하는 탐색 결과
+#[derive(Clone, Copy)]
+struct TableEntry {
+    depth: u32,                   // 이 결과를 얻을 때 남아 있던 깊이
+    score: i32,                   // 둘 차례인 쪽 기준 점수
+    bound: Bound,                 // 점수의 종류
+    best: Option<(usize, usize)>, // 가장 좋았던 수 (다음 탐색에서 먼저 살펴봄)
+}
+
+impl TableEntry {
+    /// 남은 깊이 `depth`, 창 (`alpha`, `beta`)에서 이 결과를 쓸 수 있는지 확인
+    ///
+    /// 탐색을 끝낼 수 있으면 그 점수를, 아니면 이 결과로 좁힌 창을 반환합니다.
+    /// 승패가 확정된 점수는 수순 길이에 따라 달라지므로 쓰지 않습니다.
+    fn probe(&self, depth: u32, alpha: i32, beta: i32) -> Result<i32, (i32, i32)> {
+        if self.depth < depth || self.score.abs() >= DECIDED_SCORE {
+            return Err((alpha, beta));
+        }
+        let (alpha, beta) = match self.bound {
+            Bound::Exact => return Ok(self.score),
+            Bound::Lower => (alpha.max(self.score), beta),
+            Bound::Upper => (alpha, beta.min(self.score)),
+        };
+        if alpha >= beta {
+            Ok(self.score)
+        } else {
+            Err((alpha, beta))
+        }
+    }
+}
+
+/// 알파-베타 탐색 상태
+struct Searcher {
+    board: Board,                          // 탐색 중인 판 (수를 두고 물리며 사용)
+    rule_set: RuleSet,                     // 승리 조건과 금수 규칙
+    score: i32,                            // 현재 판의 평가 점수 (흑 기준, 착수마다 갱신)
+    noise: Noise,                          // 평가 잡음 설정
+    table: TranspositionTable<TableEntry>, // 이미 탐색한 국면의 결과
+    max_candidates: usize,                 // 한 노드에서 살펴볼 최대 후보 수
+    deadline: Instant,                     // 탐색을 멈출 시각
+    max_nodes: Option<u64>,                // 방문할 최대 노드 수 (있으면 시각 대신 사용)
+    nodes: u64,                            // 방문한 노드 수
+    stopped: bool,                         // 시간 초과로 탐색이 중단되었는지 여부
+}
+
+impl Searcher {
+    /// `board`에서 시작하는 탐색 상태 생성
+    fn new(board: &Board, rule_set: RuleSet, limits: &SearchLimits, noise: Noise) -> Self {
+        let mut searcher = Self {
+            board: Board::with_size(board.width(), board.height()),
+            rule_set,
+            score: 0,
+            noise,
+            table: TranspositionTable::default(),
+            max_candidates: limits.max_candidates,
+            deadline: Instant::now() + limits.time_limit,
+            max_nodes: limits.max_nodes,
+            nodes: 0,
+            stopped: false,
+        };
+        for (x, y, color) in board.stones() {
+            searcher.board.set(x, y, Some(color));
+        }
+        searcher.score = evaluate(board);
+        searcher
+    }
+
+    /// 루트에서 한 깊이만큼 탐색하여 최선의 후보 번호와 점수를 반환
+    ///
+    /// 깊이 2 이상에서 시간이 초과되면 `None`을 반환합니다.
+    fn search_root(
+        &mut self,
+        moves: &[(usize, usize)],
+        depth: u32,
+        color: StoneColor,
+    ) -> Option<(usize, i32)> {
+        // 첫 깊이는 시간과 관계없이 끝까지 탐색
+        self.stopped = false;
+        let can_stop = depth > 1;
+
+        let mut alpha = -WIN_SCORE - 1;
+        let mut best = None;
+        for (index, &(x, y)) in moves.iter().enumerate() {
+            let score = self.score_move(x, y, color, depth, alpha, WIN_SCORE + 1, 1, can_stop);
+            if self.stopped {
+                return None;
+            }
+            if best.is_none() || score > alpha {
+                alpha = score;
+                best = Some((index, score));
+            }
+        }
+        best
+    }
+
+    /// `color`가 (x, y)에 두었을 때의 점수 (`color` 기준)
+    #[allow(clippy::too_many_arguments)]
+    fn score_move(
+        &mut self,
+        x: usize,
+        y: usize,
+        color: StoneColor,
+        depth: u32,
+        alpha: i32,
+        beta: i32,
+        ply: i32,
+        can_stop: bool,
+    ) -> i32 {
+        self.place(x, y, Some(color));
+        let score = if self.rule_set.is_win(&self.board, x, y, color) {
+            WIN_SCORE - ply
+        } else {
+            -self.negamax(
+                depth - 1,
+                -beta,
+                -alpha,
+                color.opposite(),
+                ply + 1,
+                can_stop,
+            )
+        };
+        self.place(x, y, None);
+        score
+    }
+
+    /// 네가맥스 형태의 알파-베타 탐색 (`color` 기준 점수)
+    fn negamax(
+        &mut self,
+        depth: u32,
+        mut alpha: i32,
+        mut beta: i32,
+        color: StoneColor,
+        ply: i32,
+        can_stop: bool,
+    ) -> i32 {
+        self.nodes += 1;
+        let out_of_budget = match self.max_nodes {
+            Some(max_nodes) => self.nodes > max_nodes,
+            None => self.nodes & 1023 == 0 && Instant::now() >= self.deadline,
+        };
+        if can_stop && out_of_budget {
+            self.stopped = true;
+        }
+        if self.stopped {
+            return 0;
+        }
+
+        if depth == 0 {
+            let score = self.score + self.noise_value();
+            return match color {
+                StoneColor::Black => score,
+                StoneColor::White => -score,
+            };
+        }
+
+        // 같은 국면을 이미 충분히 깊게 탐색했으면 그 결과를 사용
+        // (승패가 확정된 점수는 수순 길이에 따라 달라지므로 수 순서에만 사용)
+        let key = self.board.hash() ^ zobrist::side_key(color);
+        let original_alpha = alpha;
+        let mut hint = None;
+        if let Some(entry) = self.table.get(key) {
+            hint = entry.best;
+            match entry.probe(depth, alpha, beta) {
+                Ok(score) => return score,
+                Err(window) => (alpha, beta) = window,
+            }
+        }
+
+        let mut moves = self.candidates(color);
+        if moves.is_empty() {
+            return 0; // 둘 곳이 없으면 무승부
+        }
+        if let Some(index) = hint.and_then(|pos| moves.iter().position(|&m| m == pos)) {
+            let pos = moves.remove(index);
+            moves.insert(0, pos);
+        }
+
+        let mut best = -WIN_SCORE - 1;
+        let mut best_move = None;
+        for (x, y) in moves {
+            let score = self.score_move(x, y, color, depth, alpha, beta, ply, can_stop);
+            if self.stopped {
+                return 0;
+            }
+            if score > best {
+                best = score;
+                best_move = Some((x, y));
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table.insert(
+            key,
+            TableEntry {
+                depth,
+                score: best,
+                bound,
+                best: best_move,
+            },
+        );
+        best
+    }
+
+    /// 판의 (x, y)를 바꾸고 평가 점수를 갱신 (판의 해시는 `Board`가 갱신)
+    fn place(&mut self, x: usize, y: usize, stone: Option<StoneColor>) {
+        let before = lines_around(&self.board, x, y);
+        self.board.set(x, y, stone);
+        self.score += lines_around(&self.board, x, y) - before;
+    }
+
+    /// 현재 판에 대한 평가 잡음 (`-amount..=amount`)
+    fn noise_value(&self) -> i32 {
+        if self.noise.amount <= 0 {
+            return 0;
+        }
+        let span = 2 * self.noise.amount as u64 + 1;
+        (rng::mix(self.noise.seed ^ self.board.hash()) % span) as i32 - self.noise.amount
+    }
+
+    /// `color`가 (x, y)에 두었을 때 네 방향에서 늘어나는 `color`의 모양 점수
+    fn shape_gain(&mut self, x: usize, y: usize, color: StoneColor) -> i32 {
+        let gain = |board: &Board| -> i32 {
+            DIRECTIONS
+                .iter()
+                .map(|&(dx, dy)| line_score(board, x, y, dx, dy, color))
+                .sum()
+        };
+        let before = gain(&self.board);
+        self.board.set(x, y, Some(color));
+        let after = gain(&self.board);
+        self.board.set(x, y, None);
+        after - before
+    }
+
+    /// 돌 근처의 빈 자리를 유망한 순서로 정렬한 후보 목록
+    ///
+    /// 내 모양을 키우는 정도와 상대 모양을 막는 정도를 더해 정렬하고,
+    /// 금수를 제외한 상위 `max_candidates`개만 남깁니다.
+    fn candidates(&mut self, color: StoneColor) -> Vec<(usize, usize)> {
+        let mut scored: Vec<((usize, usize), i32)> = self
+            .board
+            .points()
+            .filter(|&(x, y)| self.board.get(x, y).is_none() && self.near_stone(x, y))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(x, y)| {
+                let priority =
+                    self.shape_gain(x, y, color) + self.shape_gain(x, y, color.opposite());
+                ((x, y), priority)
+            })
+            .collect();
+        scored.sort_by_key(|&(_, priority)| Reverse(priority));
+
+        scored
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .filter(|&(x, y)| self.rule_set.forbidden(&self.board, x, y, color).is_none())
+            .take(self.max_candidates)
+            .collect()
+    }
+
+    /// (x, y)에서 두 칸 이내에 돌이 있는지 확인
+    fn near_stone(&self, x: usize, y: usize) -> bool {
+        (-2..=2).any(|dy: i32| {
+            (-2..=2).any(|dx: i32| {
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                self.board.in_bounds(nx, ny) && self.board.get(nx as usize, ny as usize).is_some()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 중반쯤의 국면 (흑 차례)
+    fn middle_game() -> Board {
+        Board::from_rows(&[
+            "...........",
+            "...........",
+            "...........",
+            "....O......",
+            "....XX.....",
+            "...OXO.....",
+            "....X......",
+            "...........",
+            "...........",
+            "...........",
+            "...........",
+        ])
+    }
+
+    #[test]
+    fn the_same_seed_chooses_the_same_move_under_a_node_budget() {
+        let board = middle_game();
+        for difficulty in Difficulty::ALL {
+            let mut profile = difficulty.profile();
+            profile.limits = profile.limits.with_node_budget(3_000);
+            for seed in [0, 7, 12_345] {
+                let first = choose_move(&board, RuleSet::Renju, StoneColor::Black, &profile, seed);
+                let second = choose_move(&board, RuleSet::Renju, StoneColor::Black, &profile, seed);
+                assert!(first.is_some());
+                assert_eq!(first, second, "{difficulty} with seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn a_node_budget_stops_the_search() {
+        let limits = SearchLimits {
+            max_depth: 20,
+            time_limit: Duration::from_secs(3_600),
+            ..SearchLimits::default()
+        }
+        .with_node_budget(2_000);
+        let result = search(
+            &middle_game(),
+            RuleSet::Freestyle,
+            StoneColor::Black,
+            &limits,
+        )
+        .expect("a move");
+        assert!(result.depth < limits.max_depth);
+        assert_eq!(
+            search(
+                &middle_game(),
+                RuleSet::Freestyle,
+                StoneColor::Black,
+                &limits
+            ),
+            Some(result)
+        );
+    }
+
+    #[test]
+    fn search_completes_an_open_four() {
+        let board = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            ".OOO.....",
+            "..XXXX...",
+            ".........",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let limits = SearchLimits::default().with_node_budget(10_000);
+        let result = search(&board, RuleSet::Freestyle, StoneColor::Black, &limits).unwrap();
+        assert!(matches!((result.x, result.y), (1, 4) | (6, 4)));
+        assert!(result.score >= DECIDED_SCORE);
+    }
+
+    #[test]
+    fn table_entries_are_used_only_when_deep_enough() {
+        let entry = |depth, score, bound| TableEntry {
+            depth,
+            score,
+            bound,
+            best: None,
+        };
+
+        // 정확한 점수는 남은 깊이 이상으로 탐색한 결과일 때만 그대로 사용
+        assert_eq!(entry(4, 30, Bound::Exact).probe(4, -100, 100), Ok(30));
+        assert_eq!(entry(4, 30, Bound::Exact).probe(2, -100, 100), Ok(30));
+        assert_eq!(
+            entry(3, 30, Bound::Exact).probe(4, -100, 100),
+            Err((-100, 100))
+        );
+
+        // 하한과 상한은 창을 좁히고, 창이 닫히면 탐색을 끝냄
+        assert_eq!(
+            entry(4, 30, Bound::Lower).probe(4, -100, 100),
+            Err((30, 100))
+        );
+        assert_eq!(entry(4, 30, Bound::Lower).probe(4, -100, 20), Ok(30));
+        assert_eq!(
+            entry(4, 30, Bound::Upper).probe(4, -100, 100),
+            Err((-100, 30))
+        );
+        assert_eq!(entry(4, 30, Bound::Upper).probe(4, 40, 100), Ok(30));
+
+        // 승패가 확정된 점수는 쓰지 않음
+        assert_eq!(
+            entry(9, DECIDED_SCORE, Bound::Exact).probe(1, -100, 100),
+            Err((-100, 100))
+        );
+    }
+}
@@ -0,0 +1,663 @@
+//! 블라인드 오목 AI (믿음 상태와 결정화 몬테카를로 탐색)
+//!
+//! 일반 오목 AI는 실제 판을 보고 수를 고르므로 블라인드 규칙에서는 반칙이 됩니다.
+//! 이 AI는 자신의 시야(자신의 돌과 공개된 돌)와 자신이 받은 피드백만 사용하며,
+//! 보이지 않는 상대 돌의 위치를 확률적으로 추정한 판(결정화)을 여러 번 뽑아
+//! 각 판에서 짧은 모의 대국을 두어 보고 평균적으로 가장 좋은 수를 고릅니다.
+//! Bevy에 의존하지 않습니다.
+
+use std::time::Instant;
+
+use super::ai::{self, AiProfile, SearchResult};
+use super::blind::Feedback;
+use super::engine::{Board, StoneColor};
+use super::rng::Rng;
+use super::rules::RuleSet;
+
+// ==================== 탐색 상수 ====================
+/// 한 수를 고를 때 두어 보는 최대 모의 대국 수 (시간 한도와 함께 적용)
+const MAX_ITERATIONS: u32 = 20_000;
+
+/// 모의 대국 한 번을 탐색 노드 수로 환산한 값
+///
+/// 모의 대국 한 번에 알파-베타 탐색 노드 약 16개만큼의 시간이 걸리므로, 노드 수 한도를
+/// 이 값으로 나눈 만큼 모의 대국을 두면 같은 난이도에서 두 방식이 비슷한 시간을 씁니다.
+const NODES_PER_ITERATION: u64 = 16;
+
+/// 평가 잡음(`AiProfile::eval_noise`)을 승률(천분율) 잡음으로 바꿀 때 나누는 값
+const NOISE_SCALE: i32 = 10;
+
+/// 모의 대국에서 두어 보는 최대 수 (양쪽 합계)
+const ROLLOUT_PLIES: u32 = 6;
+
+/// 모의 대국의 각 수에서 무작위로 고를 후보 수
+const ROLLOUT_CANDIDATES: usize = 3;
+
+/// 후보를 모을 때 미리 뽑아 보는 결정화 판의 수
+const CANDIDATE_SAMPLES: usize = 8;
+
+/// UCB 탐험 계수
+const EXPLORATION: f64 = 0.7;
+
+/// 평가 점수를 승률로 바꿀 때 쓰는 척도 (이 점수 차이면 승률 약 73%)
+const EVAL_SCALE: f64 = 2_000.0;
+
+/// 빈 자리에 상대 돌이 있을 기본 가중치
+const BASE_WEIGHT: u64 = 1;
+
+/// 판 중앙 근처의 빈 자리에 더하는 가중치 (첫 수는 대개 중앙 근처에 둠)
+const CENTER_INFLUENCE: u64 = 6;
+
+/// 상대 돌 근처의 빈 자리에 더하는 가중치 (상대는 자신의 돌을 이어 가려 함)
+const OPPONENT_INFLUENCE: u64 = 12;
+
+/// 내 돌 근처의 빈 자리에 더하는 가중치 (상대는 내 돌을 막으려 함)
+const OWN_INFLUENCE: u64 = 3;
+
+// ==================== 믿음 상태 ====================
+/// AI가 보이지 않는 상대 돌에 대해 알고 있는 정보
+///
+/// 시야에 기록되지 않는 정보(색이 공개되지 않은 충돌, 보이지 않는 돌 때문에 생긴 금수)를
+/// 피드백에서 모아 두고, 이를 바탕으로 상대 돌이 놓였을 법한 판을 뽑습니다.
+/// 내 돌은 모두 시야에 있으므로 색이 공개되지 않은 충돌 자리에는 반드시 상대 돌이 있습니다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Belief {
+    occupied: Vec<(usize, usize)>, // 충돌로 상대 돌이 있다고 확인된 자리
+    avoid: Vec<(usize, usize)>,    // 두지 않을 자리 (보이지 않는 돌 때문에 생긴 금수 자리)
+}
+
+impl Belief {
+    /// 아무 정보도 없는 믿음 상태 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 충돌로 확인된 자리와 두지 않을 자리로 믿음 상태 생성 (저장한 게임을 불러올 때 사용)
+    pub fn from_points(occupied: Vec<(usize, usize)>, avoided: Vec<(usize, usize)>) -> Self {
+        Self {
+            occupied,
+            avoid: avoided,
+        }
+    }
+
+    /// 이 플레이어가 받은 피드백을 믿음 상태에 반영
+    pub fn observe(&mut self, feedback: &Feedback) {
+        match *feedback {
+            Feedback::TurnLost { pos, .. }
+            | Feedback::Strike { pos, .. }
+            | Feedback::Forfeit { pos, .. }
+                if !self.occupied.contains(&pos) =>
+            {
+                self.occupied.push(pos);
+            }
+            Feedback::Forbidden { pos, .. } if !self.avoid.contains(&pos) => {
+                self.avoid.push(pos);
+            }
+            _ => {}
+        }
+    }
+
+    /// 충돌로 상대 돌이 있다고 확인된 자리
+    pub fn occupied(&self) -> &[(usize, usize)] {
+        &self.occupied
+    }
+
+    /// 두지 않을 자리
+    pub fn avoided(&self) -> &[(usize, usize)] {
+        &self.avoid
+    }
+
+    /// 시야에 충돌로 확인된 상대 돌을 더한 판
+    pub fn known_board(&self, view: &Board, color: StoneColor) -> Board {
+        let mut board = view.clone();
+        for &(x, y) in &self.occupied {
+            if board.get(x, y).is_none() {
+                board.set(x, y, Some(color.opposite()));
+            }
+        }
+        board
+    }
+
+    /// 확인된 상대 돌에 더해 다시 두지 않을 자리도 상대 돌이 있는 것으로 가정한 판
+    ///
+    /// 보이지 않는 돌을 추정하지 않는 탐색이나 외부 엔진에 넘길 판으로 사용합니다.
+    pub fn assumed_board(&self, view: &Board, color: StoneColor) -> Board {
+        let mut board = self.known_board(view, color);
+        for &(x, y) in &self.avoid {
+            if board.get(x, y).is_none() {
+                board.set(x, y, Some(color.opposite()));
+            }
+        }
+        board
+    }
+
+    /// 탐색이 수를 찾지 못했을 때 둘 자리 (판 가운데에 가까운 순, 둘 자리가 없으면 `None`)
+    ///
+    /// 확인된 상대 돌과 두지 않을 자리를 피하고, 금수는 블라인드 규칙대로 자신의 시야로 판정합니다.
+    pub fn fallback_move(
+        &self,
+        view: &Board,
+        color: StoneColor,
+        rule_set: RuleSet,
+    ) -> Option<(usize, usize)> {
+        let board = self.assumed_board(view, color);
+        let (cx, cy) = (board.width() / 2, board.height() / 2);
+        board
+            .points()
+            .filter(|&(x, y)| {
+                board.get(x, y).is_none() && rule_set.forbidden(view, x, y, color).is_none()
+            })
+            .min_by_key(|&(x, y)| x.abs_diff(cx).pow(2) + y.abs_diff(cy).pow(2))
+    }
+
+    /// 보이지 않는 상대 돌 `hidden`개를 그럴듯한 위치에 놓은 판을 하나 뽑음
+    ///
+    /// 판 중앙, 상대 돌, 내 돌 근처일수록 상대 돌이 있을 가능성이 높다고 보고,
+    /// 돌을 하나씩 뽑을 때마다 그 주변의 가능성을 높입니다.
+    /// 게임이 끝나지 않았으므로 상대의 5목이 생기는 자리는 뽑지 않습니다.
+    pub fn sample(
+        &self,
+        view: &Board,
+        color: StoneColor,
+        hidden: usize,
+        rule_set: RuleSet,
+        rng: &mut Rng,
+    ) -> Board {
+        let opponent = color.opposite();
+        let mut board = self.known_board(view, color);
+        let width = board.width();
+
+        // 빈 자리마다 상대 돌이 있을 가중치
+        let mut weights: Vec<u64> = board
+            .points()
+            .map(|(x, y)| {
+                if board.get(x, y).is_none() {
+                    BASE_WEIGHT
+                } else {
+                    0
+                }
+            })
+            .collect();
+        add_influence(
+            &mut weights,
+            &board,
+            board.width() / 2,
+            board.height() / 2,
+            CENTER_INFLUENCE,
+        );
+        for (x, y, stone) in board.stones() {
+            let influence = if stone == opponent {
+                OPPONENT_INFLUENCE
+            } else {
+                OWN_INFLUENCE
+            };
+            add_influence(&mut weights, &board, x, y, influence);
+        }
+
+        // 확인된 돌은 이미 놓였으므로 나머지만 뽑음
+        let confirmed = self
+            .occupied
+            .iter()
+            .filter(|&&(x, y)| view.get(x, y).is_none())
+            .count();
+        let mut remaining = hidden.saturating_sub(confirmed);
+        while remaining > 0 {
+            let total: u64 = weights.iter().sum();
+            if total == 0 {
+                break; // 더 놓을 자리가 없음
+            }
+
+            // 가중치에 비례하여 한 자리를 고름
+            let mut pick = rng.next_u64() % total;
+            let index = weights
+                .iter()
+                .position(|&weight| {
+                    if pick < weight {
+                        true
+                    } else {
+                        pick -= weight;
+                        false
+                    }
+                })
+                .unwrap_or(0);
+            let (x, y) = (index % width, index / width);
+            weights[index] = 0;
+
+            board.set(x, y, Some(opponent));
+            if rule_set.is_win(&board, x, y, opponent) {
+                board.set(x, y, None);
+                continue;
+            }
+            add_influence(&mut weights, &board, x, y, OPPONENT_INFLUENCE);
+            remaining -= 1;
+        }
+        board
+    }
+}
+
+/// (x, y) 주변 두 칸 이내의 빈 자리에 가중치를 더함 (가까울수록 큼)
+///
+/// 이미 가중치가 0인 자리(돌이 있거나 제외된 자리)는 그대로 둡니다.
+fn add_influence(weights: &mut [u64], board: &Board, x: usize, y: usize, influence: u64) {
+    for dy in -2..=2i32 {
+        for dx in -2..=2i32 {
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            if !board.in_bounds(nx, ny) {
+                continue;
+            }
+            let weight = &mut weights[ny as usize * board.width() + nx as usize];
+            if *weight > 0 {
+                *weight += influence / dx.abs().max(dy.abs()).max(1) as u64;
+            }
+        }
+    }
+}
+
+// ==================== 결정화 몬테카를로 탐색 ====================
+/// 후보 수 하나의 통계
+#[derive(Clone, Copy, Default)]
+struct Arm {
+    visits: u32, // 이 수를 골라 본 횟수
+    reward: f64, // 모의 대국 결과의 합 (이기면 1, 지면 0)
+}
+
+/// 자신의 시야와 믿음 상태만으로 `color`가 둘 수를 고름
+///
+/// 매번 믿음 상태에서 판을 하나 뽑아 UCB로 후보 하나를 고르고, 그 판에서 짧은 모의 대국을
+/// 두어 본 결과를 후보의 통계에 더합니다. 뽑은 판에서 상대 돌이 있는 자리를 고르면
+/// 충돌로 보고 차례를 잃은 것으로 처리합니다. 가장 많이 골라 본 후보를 반환합니다.
+///
+/// 난이도 설정은 탐색과 같은 뜻으로 씁니다. `blunder_rate`% 확률로 가장 유망한 후보를 뺀 나머지 중
+/// 하나를 무작위로 고르고, `eval_noise`가 있으면 후보마다 예상 승률에 잡음을 더해 가장 높은 후보를
+/// 고릅니다. 탐색 한도 중에서는 시간 한도, 노드 수 한도, 후보 수만 사용하며, 노드 수 한도를 정하면
+/// 시간 대신 모의 대국 수(`NODES_PER_ITERATION`으로 환산)로 멈추므로 같은 `seed`에서 항상 같은 수를
+/// 고릅니다.
+/// 반환값의 점수는 예상 승률(0~1000), 노드 수는 모의 대국 수입니다.
+pub fn choose_move(
+    view: &Board,
+    belief: &Belief,
+    color: StoneColor,
+    hidden: usize,
+    rule_set: RuleSet,
+    profile: &AiProfile,
+    seed: u64,
+) -> Option<SearchResult> {
+    let limits = &profile.limits;
+    let mut rng = Rng::new(seed);
+    let known = belief.known_board(view, color);
+
+    // 아무 돌도 없으면 정중앙
+    if known.is_empty() && hidden == 0 {
+        return Some(SearchResult {
+            x: view.width() / 2,
+            y: view.height() / 2,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        });
+    }
+
+    // 아는 판과 미리 뽑아 본 판들의 후보를 모음
+    let mut moves = ai::candidates(&known, rule_set, color, limits.max_candidates);
+    for _ in 0..CANDIDATE_SAMPLES {
+        let board = belief.sample(view, color, hidden, rule_set, &mut rng);
+        for pos in ai::candidates(&board, rule_set, color, limits.max_candidates) {
+            if known.get(pos.0, pos.1).is_none() && !moves.contains(&pos) {
+                moves.push(pos);
+            }
+        }
+    }
+    moves.retain(|pos| !belief.avoided().contains(pos));
+    if moves.is_empty() {
+        return None;
+    }
+
+    // 일부러 실수: 아는 판에서 가장 유망한 후보를 뺀 나머지 중 하나를 무작위로 고름
+    if rng.chance(profile.blunder_rate) && moves.len() > 1 {
+        let (x, y) = moves[1 + rng.below(moves.len() - 1)];
+        return Some(SearchResult {
+            x,
+            y,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        });
+    }
+
+    let deadline = Instant::now() + limits.time_limit;
+    let max_iterations = limits.max_nodes.map_or(MAX_ITERATIONS, |nodes| {
+        (nodes / NODES_PER_ITERATION).clamp(1, MAX_ITERATIONS as u64) as u32
+    });
+    let in_budget = |iterations: u32| match limits.max_nodes {
+        Some(_) => true,
+        None => iterations < 1 || Instant::now() < deadline,
+    };
+    let mut arms = vec![Arm::default(); moves.len()];
+    let mut iterations = 0;
+    while iterations < max_iterations && in_budget(iterations) {
+        iterations += 1;
+        let mut board = belief.sample(view, color, hidden, rule_set, &mut rng);
+
+        // UCB로 후보 선택 (아직 골라 보지 않은 후보가 먼저)
+        let total = iterations as f64;
+        let index = (0..arms.len())
+            .max_by(|&a, &b| ucb(&arms[a], total).total_cmp(&ucb(&arms[b], total)))
+            .unwrap_or(0);
+        let (x, y) = moves[index];
+
+        let reward = if board.get(x, y).is_some() {
+            // 충돌: 차례를 잃고 상대가 둠
+            rollout(&mut board, rule_set, color, color.opposite(), &mut rng)
+        } else if rule_set.forbidden(view, x, y, color).is_some() {
+            // 금수는 자신의 시야로 판정되므로 다시 두어야 함: 가치 없음
+            0.0
+        } else {
+            board.set(x, y, Some(color));
+            if rule_set.is_win(&board, x, y, color) {
+                1.0
+            } else {
+                rollout(&mut board, rule_set, color, color.opposite(), &mut rng)
+            }
+        };
+
+        let arm = &mut arms[index];
+        arm.visits += 1;
+        arm.reward += reward;
+    }
+
+    // 잡음이 없으면 가장 많이 골라 본 후보, 있으면 예상 승률에 잡음을 더해 가장 높은 후보
+    let win_rate = |arm: &Arm| (arm.reward / arm.visits.max(1) as f64 * 1000.0) as i32;
+    let noise = profile.eval_noise / NOISE_SCALE;
+    let (index, arm) = if noise > 0 {
+        arms.iter()
+            .enumerate()
+            .filter(|(_, arm)| arm.visits > 0)
+            .max_by_key(|(_, arm)| {
+                win_rate(arm) + rng.below(2 * noise as usize + 1) as i32 - noise
+            })?
+    } else {
+        arms.iter().enumerate().max_by_key(|(_, arm)| arm.visits)?
+    };
+    let (x, y) = moves[index];
+    Some(SearchResult {
+        x,
+        y,
+        score: win_rate(arm),
+        depth: 0,
+        nodes: iterations as u64,
+    })
+}
+
+/// 후보의 UCB 값 (골라 본 적이 없으면 무한대)
+fn ucb(arm: &Arm, total: f64) -> f64 {
+    if arm.visits == 0 {
+        return f64::INFINITY;
+    }
+    let visits = arm.visits as f64;
+    arm.reward / visits + EXPLORATION * (total.ln() / visits).sqrt()
+}
+
+/// `to_move`부터 양쪽이 유망한 후보 중 하나를 무작위로 두는 짧은 모의 대국
+///
+/// 중간에 승부가 나면 그 결과를, 아니면 마지막 판의 평가 점수를 `color` 기준 승률로 바꿔 반환합니다.
+fn rollout(
+    board: &mut Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    mut to_move: StoneColor,
+    rng: &mut Rng,
+) -> f64 {
+    for _ in 0..ROLLOUT_PLIES {
+        let moves = ai::candidates(board, rule_set, to_move, ROLLOUT_CANDIDATES);
+        if moves.is_empty() {
+            return 0.5; // 둘 곳이 없으면 무승부
+        }
+        let (x, y) = moves[rng.below(moves.len())];
+        board.set(x, y, Some(to_move));
+        if rule_set.is_win(board, x, y, to_move) {
+            return if to_move == color { 1.0 } else { 0.0 };
+        }
+        to_move = to_move.opposite();
+    }
+
+    let score = match color {
+        StoneColor::Black => ai::evaluate(board),
+        StoneColor::White => -ai::evaluate(board),
+    };
+    1.0 / (1.0 + (-(score as f64) / EVAL_SCALE).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ai::{Difficulty, SearchLimits};
+    use super::super::rules::Forbidden;
+    use super::*;
+
+    #[test]
+    fn the_same_seed_chooses_the_same_move_under_a_node_budget() {
+        // 흑은 자신의 돌만 알고, 백 돌 두 개의 위치는 모름
+        let view = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            "....X....",
+            "...X.....",
+            ".........",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let profile = AiProfile {
+            limits: SearchLimits::default().with_node_budget(300 * NODES_PER_ITERATION),
+            eval_noise: 0,
+            blunder_rate: 0,
+        };
+        let belief = Belief::new();
+        for seed in [0, 7, 12_345] {
+            let choose = || {
+                choose_move(
+                    &view,
+                    &belief,
+                    StoneColor::Black,
+                    2,
+                    RuleSet::Renju,
+                    &profile,
+                    seed,
+                )
+            };
+            let first = choose().expect("a move");
+            assert_eq!(first.nodes, 300);
+            assert_eq!(Some(first), choose(), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn every_difficulty_is_reproducible_with_the_same_seed() {
+        let view = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            "....X....",
+            "...X.....",
+            ".........",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let belief = Belief::new();
+        for difficulty in Difficulty::ALL {
+            // 잡음과 실수 확률은 프리셋 그대로, 모의 대국 수만 줄임
+            let mut profile = difficulty.profile();
+            profile.limits = profile.limits.with_node_budget(50 * NODES_PER_ITERATION);
+            for seed in [3, 99] {
+                let choose = || {
+                    choose_move(
+                        &view,
+                        &belief,
+                        StoneColor::Black,
+                        2,
+                        RuleSet::Renju,
+                        &profile,
+                        seed,
+                    )
+                };
+                let first = choose().expect("a move");
+                assert_eq!(Some(first), choose(), "{difficulty:?}, seed {seed}");
+            }
+        }
+    }
+
+    #[test]
+    fn fallback_moves_avoid_known_and_forbidden_points() {
+        // 흑이 (4, 4)에 두면 3-3
+        let view = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            ".........",
+            "..XX.....",
+            "....X....",
+            "....X....",
+            ".........",
+            ".........",
+        ]);
+        let belief = Belief::from_points(vec![(3, 5)], vec![(5, 4)]);
+        let fallback =
+            |belief: &Belief, rule_set| belief.fallback_move(&view, StoneColor::Black, rule_set);
+        assert_eq!(fallback(&Belief::new(), RuleSet::Freestyle), Some((4, 4)));
+        let pos = fallback(&belief, RuleSet::Renju).unwrap();
+        assert!(![(4, 4), (3, 5), (5, 4)].contains(&pos));
+        assert_eq!(view.get(pos.0, pos.1), None);
+
+        // 둘 자리가 없으면 없음
+        let full = Board::from_rows(&["XO", "OX"]);
+        assert_eq!(
+            Belief::new().fallback_move(&full, StoneColor::Black, RuleSet::Freestyle),
+            None
+        );
+    }
+
+    #[test]
+    fn observe_records_collisions_and_forbidden_points() {
+        let player = StoneColor::Black;
+        let mut belief = Belief::new();
+
+        // 이미 시야에 있는 돌과 정상 착수는 새로 알려 주는 것이 없음
+        belief.observe(&Feedback::Occupied {
+            player,
+            pos: (1, 1),
+        });
+        belief.observe(&Feedback::Placed {
+            player,
+            pos: (2, 2),
+        });
+        assert_eq!(belief, Belief::new());
+
+        belief.observe(&Feedback::TurnLost {
+            player,
+            pos: (3, 3),
+        });
+        belief.observe(&Feedback::TurnLost {
+            player,
+            pos: (3, 3),
+        });
+        belief.observe(&Feedback::Strike {
+            player,
+            pos: (4, 4),
+            strikes: 1,
+            limit: 3,
+        });
+        belief.observe(&Feedback::Forbidden {
+            player,
+            pos: (5, 5),
+            kind: Forbidden::DoubleThree,
+        });
+        belief.observe(&Feedback::Forbidden {
+            player,
+            pos: (5, 5),
+            kind: Forbidden::DoubleThree,
+        });
+        assert_eq!(belief.occupied(), &[(3, 3), (4, 4)]);
+        assert_eq!(belief.avoided(), &[(5, 5)]);
+    }
+
+    #[test]
+    fn chosen_moves_avoid_known_and_avoided_points() {
+        // 흑의 세 줄을 잇는 양 끝은 상대 돌과 금수 자리로 막혀 있음
+        let view = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            "...X.....",
+            "...X.....",
+            "...X.....",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let belief = Belief::from_points(vec![(3, 2), (4, 4)], vec![(3, 6), (2, 4)]);
+        let limits = SearchLimits::default().with_node_budget(30 * NODES_PER_ITERATION);
+        let profiles = [
+            AiProfile {
+                limits,
+                eval_noise: 0,
+                blunder_rate: 0,
+            },
+            AiProfile {
+                limits,
+                eval_noise: 2_000,
+                blunder_rate: 100,
+            },
+        ];
+        for profile in &profiles {
+            for seed in 0..8 {
+                let result = choose_move(
+                    &view,
+                    &belief,
+                    StoneColor::Black,
+                    3,
+                    RuleSet::Renju,
+                    profile,
+                    seed,
+                )
+                .expect("a move");
+                let pos = (result.x, result.y);
+                assert_eq!(view.get(pos.0, pos.1), None, "seed {seed}");
+                assert!(!belief.occupied().contains(&pos), "seed {seed}: {pos:?}");
+                assert!(!belief.avoided().contains(&pos), "seed {seed}: {pos:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn samples_place_exactly_the_hidden_opponent_stones() {
+        // 백 (5, 5)는 공개된 돌, (2, 6)은 충돌로 확인된 돌
+        let view = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            ".....O...",
+            "....X....",
+            "...X.....",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let belief = Belief::from_points(vec![(2, 6)], Vec::new());
+        let mut rng = Rng::new(1);
+        for hidden in [0, 1, 4] {
+            for _ in 0..20 {
+                let board =
+                    belief.sample(&view, StoneColor::Black, hidden, RuleSet::Renju, &mut rng);
+                let count = |color| board.stones().filter(|&(_, _, c)| c == color).count();
+                // 확인된 돌은 숨은 돌로 세므로 숨은 돌이 없어도 판에 놓임
+                assert_eq!(count(StoneColor::White), 1 + hidden.max(1));
+                assert_eq!(count(StoneColor::Black), 2);
+                assert_eq!(board.get(2, 6), Some(StoneColor::White));
+                for (x, y, color) in view.stones() {
+                    assert_eq!(board.get(x, y), Some(color));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,661 @@
+//! 블라인드 오목 규칙
+//!
+//! 실제 판은 심판(`Game`)만 알고 있으며, 각 플레이어는 자신의 돌과
+//! 피드백으로 공개된 돌만 알 수 있습니다. Bevy에 의존하지 않습니다.
+
+use std::fmt;
+
+use super::engine::{Board, Game, Move, MoveError, MoveOutcome, StoneColor};
+use super::rules::Forbidden;
+
+// ==================== 충돌 규칙 ====================
+/// 자신이 모르는 돌이 있는 자리에 놓으려 했을 때의 처리 규칙
+///
+/// 자신이 이미 알고 있는 돌 위를 클릭한 경우에는 벌칙 없이 무시됩니다.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CollisionRule {
+    /// 턴을 잃음 (점유 여부 외에는 아무것도 알려주지 않음)
+    LoseTurn,
+    /// 점유한 돌의 색을 놓으려 한 플레이어에게만 공개하고 다시 놓게 함
+    #[default]
+    RevealToPlayer,
+    /// 점유한 돌을 양쪽 플레이어 모두에게 공개하고 다시 놓게 함
+    RevealToBoth,
+    /// 경고를 누적하고 다시 놓게 함, `limit`회에 도달하면 기권패
+    Strikes { limit: u32 },
+}
+
+impl CollisionRule {
+    /// 기본 경고 한도
+    pub const DEFAULT_STRIKE_LIMIT: u32 = 3;
+
+    /// 다음 규칙 반환 (규칙 선택 키에서 사용)
+    pub fn next(self) -> Self {
+        match self {
+            CollisionRule::LoseTurn => CollisionRule::RevealToPlayer,
+            CollisionRule::RevealToPlayer => CollisionRule::RevealToBoth,
+            CollisionRule::RevealToBoth => CollisionRule::Strikes {
+                limit: Self::DEFAULT_STRIKE_LIMIT,
+            },
+            CollisionRule::Strikes { .. } => CollisionRule::LoseTurn,
+        }
+    }
+
+    /// 규칙을 화면에 표시할 문자열로 변환
+    pub fn description(self) -> String {
+        match self {
+            CollisionRule::LoseTurn => "Collision: lose the turn".to_string(),
+            CollisionRule::RevealToPlayer => "Collision: color revealed to you".to_string(),
+            CollisionRule::RevealToBoth => "Collision: stone revealed to both".to_string(),
+            CollisionRule::Strikes { limit } => {
+                format!("Collision: strike ({limit} strikes forfeit)")
+            }
+        }
+    }
+}
+
+impl fmt::Display for CollisionRule {
+    /// 명령행과 저장 파일에서 쓰는 이름 (예: `reveal`, `strikes-3`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollisionRule::LoseTurn => write!(f, "lose-turn"),
+            CollisionRule::RevealToPlayer => write!(f, "reveal"),
+            CollisionRule::RevealToBoth => write!(f, "reveal-both"),
+            CollisionRule::Strikes { limit } => write!(f, "strikes-{limit}"),
+        }
+    }
+}
+
+impl std::str::FromStr for CollisionRule {
+    type Err = String;
+
+    /// 표시 이름을 읽음 (`strikes`만 적으면 기본 경고 한도)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "lose-turn" => Ok(CollisionRule::LoseTurn),
+            "reveal" => Ok(CollisionRule::RevealToPlayer),
+            "reveal-both" => Ok(CollisionRule::RevealToBoth),
+            "strikes" => Ok(CollisionRule::Strikes {
+                limit: Self::DEFAULT_STRIKE_LIMIT,
+            }),
+            _ => text
+                .strip_prefix("strikes-")
+                .and_then(|limit| limit.parse().ok())
+                .filter(|&limit| limit > 0)
+                .map(|limit| CollisionRule::Strikes { limit })
+                .ok_or_else(|| format!("unknown collision rule \"{text}\"")),
+        }
+    }
+}
+
+// ==================== 플레이어 시야 ====================
+/// 한 플레이어가 지금까지 알게 된 돌의 정보
+///
+/// 자신의 돌은 놓는 즉시 알게 되고, 상대 돌은 피드백을 통해서만 알 수 있습니다.
+///
+/// 알고 있는 돌을 실제 판과 같은 크기의 `Board`에 기록합니다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct PlayerView {
+    known: Board, // 이 플레이어가 알고 있는 돌
+}
+
+impl PlayerView {
+    /// 아무것도 모르는 상태의 시야 생성
+    fn with_size(width: usize, height: usize) -> Self {
+        Self {
+            known: Board::with_size(width, height),
+        }
+    }
+
+    /// 해당 위치의 돌을 알고 있는지 확인
+    pub fn knows(&self, x: usize, y: usize) -> bool {
+        self.known.get(x, y).is_some()
+    }
+
+    /// 해당 위치에 있다고 알고 있는 돌 반환
+    pub fn get(&self, x: usize, y: usize) -> Option<StoneColor> {
+        self.known.get(x, y)
+    }
+
+    /// 알고 있는 돌만 놓인 판
+    pub fn board(&self) -> &Board {
+        &self.known
+    }
+
+    /// 해당 위치의 돌 정보를 기록
+    fn reveal(&mut self, x: usize, y: usize, color: StoneColor) {
+        self.known.set(x, y, Some(color));
+    }
+
+    /// 해당 위치의 돌 정보를 지움 (무르기로 돌이 사라졌을 때)
+    fn forget(&mut self, x: usize, y: usize) {
+        self.known.set(x, y, None);
+    }
+}
+
+// ==================== 피드백 ====================
+/// 플레이어에게 전달되는 피드백
+///
+/// 블라인드 규칙에서 플레이어는 판을 직접 볼 수 없으므로
+/// 이 피드백을 통해서만 돌에 대한 정보를 얻습니다.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Feedback {
+    /// 돌을 정상적으로 놓음
+    Placed {
+        player: StoneColor,
+        pos: (usize, usize),
+    },
+    /// 자신이 이미 알고 있는 돌 위에 놓으려 함 (벌칙 없이 다시 놓음)
+    Occupied {
+        player: StoneColor,
+        pos: (usize, usize),
+    },
+    /// 보이지 않는 돌 위에 놓으려 하여 턴을 잃음
+    TurnLost {
+        player: StoneColor,
+        pos: (usize, usize),
+    },
+    /// 보이지 않는 돌 위에 놓으려 하여 그 돌의 색이 공개됨
+    Revealed {
+        player: StoneColor,
+        pos: (usize, usize),
+        color: StoneColor,
+        to_both: bool, // 상대 플레이어에게도 공개되었는지 여부
+    },
+    /// 보이지 않는 돌 위에 놓으려 하여 경고를 받음
+    Strike {
+        player: StoneColor,
+        pos: (usize, usize),
+        strikes: u32,
+        limit: u32,
+    },
+    /// 경고 누적으로 기권패
+    Forfeit {
+        player: StoneColor,
+        pos: (usize, usize),
+    },
+    /// 플레이어가 알고 있는 돌만으로 판정한 금수 (다시 놓아야 함)
+    Forbidden {
+        player: StoneColor,
+        pos: (usize, usize),
+        kind: Forbidden,
+    },
+}
+
+impl Feedback {
+    /// 피드백을 일으킨 플레이어
+    pub fn player(&self) -> StoneColor {
+        match *self {
+            Feedback::Placed { player, .. }
+            | Feedback::Occupied { player, .. }
+            | Feedback::TurnLost { player, .. }
+            | Feedback::Revealed { player, .. }
+            | Feedback::Strike { player, .. }
+            | Feedback::Forfeit { player, .. }
+            | Feedback::Forbidden { player, .. } => player,
+        }
+    }
+
+    /// `viewer`에게 보여 줄 문자열
+    ///
+    /// 피드백을 일으킨 플레이어에게는 `message`를 그대로 보여 주고, 상대에게는 좌표 없이
+    /// 무슨 일이 있었는지만 알립니다. 양쪽 모두에게 공개된 돌만 좌표까지 보여 줍니다.
+    pub fn message_for(&self, viewer: StoneColor) -> String {
+        if viewer == self.player() {
+            return self.message();
+        }
+        match *self {
+            Feedback::Placed { player, .. } => format!("{player} has moved"),
+            Feedback::Occupied { player, .. } | Feedback::Forbidden { player, .. } => {
+                format!("{player} is choosing again")
+            }
+            Feedback::TurnLost { player, .. } => format!("{player} lost the turn"),
+            Feedback::Revealed { to_both: true, .. } => self.message(),
+            Feedback::Revealed { player, .. } => {
+                format!("{player} hit a hidden stone and is choosing again")
+            }
+            Feedback::Strike {
+                player,
+                strikes,
+                limit,
+                ..
+            } => format!("{player} hit a hidden stone. Strike {strikes}/{limit}"),
+            Feedback::Forfeit { player, .. } => {
+                format!("{player} hit a hidden stone. Too many strikes, forfeit")
+            }
+        }
+    }
+
+    /// 피드백을 화면에 표시할 문자열로 변환 (피드백을 일으킨 플레이어의 시점)
+    pub fn message(&self) -> String {
+        match self {
+            Feedback::Placed { player, pos } => {
+                format!("{} placed a stone at ({}, {})", player, pos.0, pos.1)
+            }
+            Feedback::Occupied { player, pos } => {
+                format!(
+                    "{}: ({}, {}) is already occupied. Try again",
+                    player, pos.0, pos.1
+                )
+            }
+            Feedback::TurnLost { player, pos } => {
+                format!("{}: ({}, {}) was occupied. Turn lost", player, pos.0, pos.1)
+            }
+            Feedback::Revealed {
+                player,
+                pos,
+                color,
+                to_both,
+            } => {
+                format!(
+                    "{}: ({}, {}) holds a {} stone{}. Try again",
+                    player,
+                    pos.0,
+                    pos.1,
+                    color,
+                    if *to_both { " (revealed to both)" } else { "" }
+                )
+            }
+            Feedback::Strike {
+                player,
+                pos,
+                strikes,
+                limit,
+            } => {
+                format!(
+                    "{}: ({}, {}) was occupied. Strike {}/{}",
+                    player, pos.0, pos.1, strikes, limit
+                )
+            }
+            Feedback::Forfeit { player, pos } => {
+                format!(
+                    "{}: ({}, {}) was occupied. Too many strikes, forfeit",
+                    player, pos.0, pos.1
+                )
+            }
+            Feedback::Forbidden { player, pos, kind } => {
+                format!(
+                    "{}: ({}, {}) is forbidden ({}). Try again",
+                    player, pos.0, pos.1, kind
+                )
+            }
+        }
+    }
+}
+
+// ==================== 블라인드 게임 ====================
+/// 실제 게임과 플레이어별 공개 정보를 함께 관리하는 블라인드 오목 게임
+#[derive(Clone, Debug, Default)]
+pub struct BlindGame {
+    game: Game,             // 실제 게임 (심판만 알고 있음)
+    views: [PlayerView; 2], // 플레이어별 공개 정보 (흑, 백 순서)
+    strikes: [u32; 2],      // 플레이어별 충돌 경고 횟수
+}
+
+impl BlindGame {
+    /// 15x15 판에서 새 블라인드 게임 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지정한 크기의 판에서 새 블라인드 게임 생성
+    pub fn with_size(width: usize, height: usize) -> Self {
+        Self::with_game(Game::with_size(width, height))
+    }
+
+    /// 아직 돌이 없는 게임(판 크기, 규칙 설정 완료)으로 블라인드 게임 생성
+    pub fn with_game(game: Game) -> Self {
+        let (width, height) = (game.board().width(), game.board().height());
+        Self {
+            game,
+            views: [
+                PlayerView::with_size(width, height),
+                PlayerView::with_size(width, height),
+            ],
+            strikes: [0; 2],
+        }
+    }
+
+    /// 이미 돌이 놓인 게임과 플레이어별로 알고 있는 돌(흑, 백 순서)로 블라인드 게임 생성
+    ///
+    /// 자신의 돌은 항상 알고 있는 것으로 보며, `known`에서 실제 판과 다른 돌은 무시합니다.
+    pub fn with_known(game: Game, known: [&Board; 2]) -> Self {
+        let mut blind = Self::with_game(game);
+        for (x, y, color) in blind.game.board().stones() {
+            for player in [StoneColor::Black, StoneColor::White] {
+                if player == color || known[player.index()].get(x, y) == Some(color) {
+                    blind.views[player.index()].reveal(x, y, color);
+                }
+            }
+        }
+        blind
+    }
+
+    /// 플레이어별 충돌 경고 횟수(흑, 백 순서) 설정 (게임 생성 직후에 사용)
+    pub fn with_strikes(mut self, strikes: [u32; 2]) -> Self {
+        self.strikes = strikes;
+        self
+    }
+
+    /// 실제 게임 상태 (심판 시점)
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// 해당 플레이어에게 공개된 정보 반환
+    pub fn view(&self, player: StoneColor) -> &PlayerView {
+        &self.views[player.index()]
+    }
+
+    /// 해당 플레이어의 충돌 경고 횟수
+    pub fn strikes(&self, player: StoneColor) -> u32 {
+        self.strikes[player.index()]
+    }
+
+    /// 해당 플레이어가 아직 위치를 모르는 상대 돌의 수
+    ///
+    /// 상대가 돌을 놓을 때마다 차례가 넘어가므로 돌의 개수는 양쪽 모두 알 수 있는 정보입니다.
+    pub fn hidden_stones(&self, player: StoneColor) -> usize {
+        let opponent = player.opposite();
+        let placed = self
+            .game
+            .board()
+            .stones()
+            .filter(|&(_, _, color)| color == opponent)
+            .count();
+        let known = self
+            .view(player)
+            .board()
+            .stones()
+            .filter(|&(_, _, color)| color == opponent)
+            .count();
+        placed - known
+    }
+
+    /// `player`의 기권패로 게임을 끝냄 (외부 엔진의 시간 초과나 비정상 종료 등)
+    pub fn resign(&mut self, player: StoneColor) {
+        self.game.resign(player);
+    }
+
+    /// 양쪽 플레이어 모두에게 공개되는 방식으로 실제 게임을 조작
+    ///
+    /// 오프닝처럼 모든 돌이 공개되는 단계에서 사용하며,
+    /// `f`가 끝난 뒤 판 위의 모든 돌을 양쪽 플레이어에게 공개합니다.
+    pub fn play_public<R>(&mut self, f: impl FnOnce(&mut Game) -> R) -> R {
+        let result = f(&mut self.game);
+        for (x, y, color) in self.game.board().stones() {
+            for view in &mut self.views {
+                view.reveal(x, y, color);
+            }
+        }
+        result
+    }
+
+    /// 마지막 수를 무름
+    ///
+    /// 치운 돌은 양쪽 플레이어의 시야에서도 지워집니다.
+    /// 충돌로 받은 경고는 되돌리지 않습니다.
+    pub fn undo(&mut self) -> Option<Move> {
+        let undone = self.game.undo()?;
+        for view in &mut self.views {
+            view.forget(undone.x, undone.y);
+        }
+        Some(undone)
+    }
+
+    /// 가장 최근에 무른 수를 다시 둠 (돌을 둔 플레이어만 그 돌을 알게 됨)
+    pub fn redo(&mut self) -> Option<(Move, MoveOutcome)> {
+        let (redone, outcome) = self.game.redo()?;
+        self.views[redone.color.index()].reveal(redone.x, redone.y, redone.color);
+        Some((redone, outcome))
+    }
+
+    /// 현재 플레이어가 해당 위치에 돌을 놓으려 시도
+    ///
+    /// 빈 자리면 돌을 놓고, 이미 돌이 있으면 `rule`에 따라 처리합니다.
+    /// 게임이 끝났거나 판 밖의 좌표이면 오류를 반환합니다.
+    ///
+    /// 금수는 실제 판이 아니라 플레이어가 알고 있는 돌만으로 판정합니다.
+    /// 실제 판으로 판정하면 거부된 이유로 보이지 않는 돌의 위치가 드러나기 때문이며,
+    /// 그래서 플레이어의 시야에서 금수가 아닌 자리는 실제 판에서 금수 모양이 되더라도 둘 수 있습니다.
+    pub fn play(&mut self, x: usize, y: usize, rule: CollisionRule) -> Result<Feedback, MoveError> {
+        let player = self.game.current_player();
+        match self.game.check_placement(x, y) {
+            Ok(()) | Err(MoveError::Occupied(_)) => {}
+            Err(err) => return Err(err),
+        }
+        let known = self.view(player).board();
+        if let Some(kind) = self.game.rule_set().forbidden(known, x, y, player) {
+            return Ok(Feedback::Forbidden {
+                player,
+                pos: (x, y),
+                kind,
+            });
+        }
+
+        match self.game.play_ignoring_forbidden(x, y) {
+            Ok(_) => {
+                // 놓은 플레이어는 자신의 돌을 알게 됨
+                self.views[player.index()].reveal(x, y, player);
+                Ok(Feedback::Placed {
+                    player,
+                    pos: (x, y),
+                })
+            }
+            Err(MoveError::Occupied(occupant)) => {
+                Ok(self.resolve_collision(rule, (x, y), occupant))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 현재 플레이어가 이미 돌이 있는 자리에 놓으려 할 때 규칙에 따라 상태를 갱신
+    ///
+    /// # 매개변수
+    /// * `rule` - 적용할 충돌 규칙
+    /// * `pos` - 놓으려 한 위치
+    /// * `occupant` - 그 자리에 실제로 있는 돌의 색상
+    ///
+    /// # 반환값
+    /// * `Feedback` - 플레이어에게 전달할 피드백
+    fn resolve_collision(
+        &mut self,
+        rule: CollisionRule,
+        pos: (usize, usize),
+        occupant: StoneColor,
+    ) -> Feedback {
+        let player = self.game.current_player();
+        let (x, y) = pos;
+
+        // 이미 알고 있는 돌이면 단순한 실수이므로 벌칙 없음
+        if self.view(player).knows(x, y) {
+            return Feedback::Occupied { player, pos };
+        }
+
+        match rule {
+            CollisionRule::LoseTurn => {
+                // 진행 중인 게임이므로 실패하지 않음
+                let _ = self.game.pass();
+                Feedback::TurnLost { player, pos }
+            }
+            CollisionRule::RevealToPlayer => {
+                self.views[player.index()].reveal(x, y, occupant);
+                Feedback::Revealed {
+                    player,
+                    pos,
+                    color: occupant,
+                    to_both: false,
+                }
+            }
+            CollisionRule::RevealToBoth => {
+                self.views[player.index()].reveal(x, y, occupant);
+                self.views[player.opposite().index()].reveal(x, y, occupant);
+                Feedback::Revealed {
+                    player,
+                    pos,
+                    color: occupant,
+                    to_both: true,
+                }
+            }
+            CollisionRule::Strikes { limit } => {
+                let strikes = &mut self.strikes[player.index()];
+                *strikes += 1;
+                let strikes = *strikes;
+                if strikes >= limit {
+                    self.game.resign(player);
+                    Feedback::Forfeit { player, pos }
+                } else {
+                    Feedback::Strike {
+                        player,
+                        pos,
+                        strikes,
+                        limit,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::the_devils_plan::blind_gomoku::rules::RuleSet;
+
+    /// 렌주룰로 흑 차례인 블라인드 게임 (각 플레이어는 자신의 돌만 앎)
+    fn renju_game(board: Board) -> BlindGame {
+        let game = Game::from_position(board, RuleSet::Renju, StoneColor::Black);
+        let empty = Board::with_size(game.board().width(), game.board().height());
+        BlindGame::with_known(game, [&empty, &empty])
+    }
+
+    // 흑이 (4, 3)에 두면 3-3이지만, 흑이 모르는 백 돌이 가로 3을 막고 있음
+    const HIDDEN_BLOCKER: [&str; 9] = [
+        ".........",
+        ".........",
+        ".........",
+        "....X....",
+        "....X....",
+        "..XX.O...",
+        ".........",
+        ".........",
+        ".........",
+    ];
+
+    #[test]
+    fn forbidden_moves_are_judged_on_the_players_view() {
+        let mut blind = renju_game(Board::from_rows(&HIDDEN_BLOCKER));
+        assert!(blind.game().is_legal(4, 3));
+
+        let feedback = blind.play(4, 3, CollisionRule::RevealToPlayer);
+        assert_eq!(
+            feedback,
+            Ok(Feedback::Forbidden {
+                player: StoneColor::Black,
+                pos: (4, 3),
+                kind: Forbidden::DoubleThree,
+            })
+        );
+        assert_eq!(blind.game().board().get(4, 3), None);
+        assert_eq!(blind.game().current_player(), StoneColor::Black);
+        assert_eq!(blind.view(StoneColor::Black).get(5, 3), None);
+    }
+
+    #[test]
+    fn moves_legal_in_the_players_view_are_placed() {
+        let mut blind = renju_game(Board::from_rows(&HIDDEN_BLOCKER));
+        // 백이 막은 자리를 알게 되면 가로 3이 막혀 있으므로 둘 수 있음
+        assert_eq!(
+            blind.play(5, 3, CollisionRule::RevealToPlayer),
+            Ok(Feedback::Revealed {
+                player: StoneColor::Black,
+                pos: (5, 3),
+                color: StoneColor::White,
+                to_both: false,
+            })
+        );
+        assert_eq!(
+            blind.play(4, 3, CollisionRule::RevealToPlayer),
+            Ok(Feedback::Placed {
+                player: StoneColor::Black,
+                pos: (4, 3),
+            })
+        );
+        assert_eq!(blind.game().current_player(), StoneColor::White);
+    }
+
+    #[test]
+    fn collisions_follow_the_collision_rule() {
+        let board = Board::from_rows(&["O....", "....."]);
+        let mut blind = renju_game(board.clone());
+        assert_eq!(
+            blind.play(0, 1, CollisionRule::LoseTurn),
+            Ok(Feedback::TurnLost {
+                player: StoneColor::Black,
+                pos: (0, 1),
+            })
+        );
+        assert_eq!(blind.game().current_player(), StoneColor::White);
+        assert!(!blind.view(StoneColor::Black).knows(0, 1));
+
+        let mut blind = renju_game(board);
+        let strikes = CollisionRule::Strikes { limit: 2 };
+        assert!(matches!(
+            blind.play(0, 1, strikes),
+            Ok(Feedback::Strike { strikes: 1, .. })
+        ));
+        assert!(matches!(
+            blind.play(0, 1, strikes),
+            Ok(Feedback::Forfeit { .. })
+        ));
+        assert_eq!(blind.game().winner(), Some(StoneColor::White));
+    }
+
+    #[test]
+    fn opponents_never_see_hidden_points() {
+        let mut blind = BlindGame::with_size(9, 9);
+        let mut feedbacks = Vec::new();
+        for (x, y, rule) in [
+            (4, 4, CollisionRule::RevealToPlayer),
+            (4, 4, CollisionRule::RevealToPlayer),
+            (3, 3, CollisionRule::RevealToPlayer),
+            (3, 3, CollisionRule::Strikes { limit: 3 }),
+            (5, 5, CollisionRule::LoseTurn),
+            (5, 5, CollisionRule::LoseTurn),
+        ] {
+            feedbacks.push(blind.play(x, y, rule).unwrap());
+        }
+        assert!(matches!(
+            feedbacks[1],
+            Feedback::Revealed { to_both: false, .. }
+        ));
+        assert!(matches!(feedbacks[3], Feedback::Strike { .. }));
+        assert!(matches!(feedbacks[5], Feedback::TurnLost { .. }));
+
+        let forbidden = Feedback::Forbidden {
+            player: StoneColor::Black,
+            pos: (4, 3),
+            kind: Forbidden::DoubleThree,
+        };
+        for feedback in feedbacks.into_iter().chain([forbidden]) {
+            let player = feedback.player();
+            assert_eq!(feedback.message_for(player), feedback.message());
+            let shown = feedback.message_for(player.opposite());
+            // 좌표는 항상 `(x, y)`로 적으므로 괄호가 없으면 좌표도 없음
+            assert!(!shown.contains('('), "{shown}");
+            assert!(shown.starts_with(&player.to_string()), "{shown}");
+        }
+    }
+
+    #[test]
+    fn stones_revealed_to_both_are_shown_to_both() {
+        let mut blind = BlindGame::with_size(9, 9);
+        blind.play(4, 4, CollisionRule::RevealToBoth).unwrap();
+        let feedback = blind.play(4, 4, CollisionRule::RevealToBoth).unwrap();
+        assert_eq!(
+            feedback.message_for(StoneColor::Black),
+            feedback.message_for(StoneColor::White)
+        );
+        assert!(feedback.message_for(StoneColor::Black).contains("(4, 4)"));
+    }
+}
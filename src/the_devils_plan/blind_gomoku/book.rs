@@ -0,0 +1,534 @@
+//! 오프닝 정석 (오프닝 북)
+//!
+//! 국면마다 둘 만한 수와 가중치를 모아 둔 표입니다. 국면은 대칭 변환으로 정규화하여 저장하므로
+//! 회전하거나 뒤집은 국면에서도 같은 항목을 찾습니다. Bevy에 의존하지 않습니다.
+//!
+//! # 파일 형식 (버전 1)
+//!
+//! UTF-8 텍스트이며 `#`부터 줄 끝까지는 주석이고 빈 줄은 무시합니다.
+//!
+//! ```text
+//! gomoku-book 1
+//! size 15 15
+//! black | - | - | h8:10
+//! white | h8 | - | i9:6 h9:4
+//! black | h8 | i9 | j10:3 i8:2
+//! ```
+//!
+//! - 첫 줄은 형식 이름과 버전, 둘째 줄은 판 크기(가로, 세로)입니다.
+//! - 나머지 줄은 한 국면씩이며 `|`로 나눈 네 칸으로 이루어집니다:
+//!   둘 차례(`black` 또는 `white`), 흑 돌 목록, 백 돌 목록, `좌표:가중치` 후보 목록.
+//!   목록은 공백으로 구분하고, 비어 있으면 `-`를 씁니다.
+//! - 좌표는 열 문자(`a`부터, x = 0)와 행 번호(`1`부터, y = 0)를 이어 씁니다. 예: `h8` = (7, 7)
+//! - 국면은 어느 방향으로 적어도 되며, 읽을 때 정규화되어 같은 국면의 후보는 가중치가 합쳐집니다.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::engine::{Board, Move, StoneColor};
+use super::record::{format_point, parse_point};
+use super::rng::Rng;
+use super::zobrist::{self, Symmetry};
+
+/// 파일 형식 이름
+const BOOK_MAGIC: &str = "gomoku-book";
+
+/// 현재 파일 형식 버전
+pub const BOOK_VERSION: u32 = 1;
+
+// ==================== 정석 수 ====================
+/// 정석에 있는 후보 수 하나
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BookMove {
+    pub x: usize,    // x 좌표
+    pub y: usize,    // y 좌표
+    pub weight: u32, // 가중치 (클수록 자주 고름)
+}
+
+/// 정규화된 국면 하나와 그 국면의 후보 수
+#[derive(Clone, Debug)]
+struct BookEntry {
+    board: Board,         // 정규화된 국면
+    to_move: StoneColor,  // 둘 차례
+    moves: Vec<BookMove>, // 정규화된 좌표의 후보 수
+}
+
+// ==================== 오류 ====================
+/// 정석 파일을 읽을 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum BookError {
+    /// 첫 줄이 `gomoku-book <버전>`이 아님
+    MissingHeader,
+    /// 지원하지 않는 형식 버전
+    UnsupportedVersion(u32),
+    /// 판 크기 줄이 없거나 지원하지 않는 크기
+    InvalidSize,
+    /// 형식이 잘못된 줄 (줄 번호)
+    InvalidLine(usize),
+    /// 판 밖이거나 읽을 수 없는 좌표 (줄 번호, 좌표)
+    InvalidPoint(usize, String),
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BookError::MissingHeader => write!(f, "missing \"{BOOK_MAGIC} <version>\" header"),
+            BookError::UnsupportedVersion(version) => {
+                write!(f, "unsupported book version {version}")
+            }
+            BookError::InvalidSize => {
+                write!(f, "missing or invalid \"size <width> <height>\" line")
+            }
+            BookError::InvalidLine(line) => write!(f, "line {line}: malformed entry"),
+            BookError::InvalidPoint(line, point) => {
+                write!(f, "line {line}: invalid point \"{point}\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {}
+
+// ==================== 오프닝 정석 ====================
+/// 정규화된 국면에서 후보 수와 가중치를 찾는 오프닝 정석
+///
+/// 한 가지 판 크기에만 쓸 수 있으며, 크기가 다른 판에서는 아무것도 찾지 않습니다.
+#[derive(Clone, Debug)]
+pub struct OpeningBook {
+    width: usize,                     // 판 가로 칸 수
+    height: usize,                    // 판 세로 칸 수
+    entries: HashMap<u64, BookEntry>, // 정규화된 국면 해시(둘 차례 포함) → 항목
+}
+
+impl OpeningBook {
+    /// `width`x`height` 판에 쓸 빈 정석 생성
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// 판 가로 칸 수
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// 판 세로 칸 수
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 저장된 국면 수
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 저장된 국면이 없는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// `board`에서 `to_move`가 (x, y)에 두는 수의 가중치를 `weight`만큼 늘림
+    pub fn add(&mut self, board: &Board, to_move: StoneColor, x: usize, y: usize, weight: u32) {
+        if !self.fits(board) {
+            return;
+        }
+        let (key, symmetries) = canonical_key(board, to_move);
+        let (width, height) = (board.width(), board.height());
+
+        // 국면을 바꾸지 않는 변환끼리 같은 수로 모이도록 가장 앞선 좌표를 대표로 사용
+        let Some((cx, cy)) = symmetries
+            .iter()
+            .map(|symmetry| symmetry.apply(x, y, width, height))
+            .min_by_key(|&(x, y)| (y, x))
+        else {
+            return;
+        };
+
+        let entry = self.entries.entry(key).or_insert_with(|| BookEntry {
+            board: symmetries[0].transform(board),
+            to_move,
+            moves: Vec::new(),
+        });
+        match entry.moves.iter_mut().find(|m| (m.x, m.y) == (cx, cy)) {
+            Some(existing) => existing.weight = existing.weight.saturating_add(weight),
+            None => entry.moves.push(BookMove {
+                x: cx,
+                y: cy,
+                weight,
+            }),
+        }
+    }
+
+    /// 기록된 대국의 처음 `max_plies`수를 정석에 더함 (나온 수마다 가중치 1)
+    ///
+    /// 빈 판에서 시작하여 차례대로 두며, 판 밖이거나 이미 돌이 있는 수가 나오면 멈춥니다.
+    pub fn add_game(&mut self, moves: &[Move], max_plies: usize) {
+        let mut board = Board::with_size(self.width, self.height);
+        for stone in moves.iter().take(max_plies) {
+            if stone.x >= self.width
+                || stone.y >= self.height
+                || board.get(stone.x, stone.y).is_some()
+            {
+                break;
+            }
+            self.add(&board, stone.color, stone.x, stone.y, 1);
+            board.set(stone.x, stone.y, Some(stone.color));
+        }
+    }
+
+    /// `board`에서 `to_move`가 둘 정석 후보 (`board`의 방향으로 변환한 좌표)
+    pub fn lookup(&self, board: &Board, to_move: StoneColor) -> Vec<BookMove> {
+        if !self.fits(board) {
+            return Vec::new();
+        }
+        let (key, symmetries) = canonical_key(board, to_move);
+        let Some(entry) = self.entries.get(&key) else {
+            return Vec::new();
+        };
+
+        let inverse = symmetries[0].inverse();
+        let (width, height) = (entry.board.width(), entry.board.height());
+        entry
+            .moves
+            .iter()
+            .map(|m| {
+                let (x, y) = inverse.apply(m.x, m.y, width, height);
+                BookMove {
+                    x,
+                    y,
+                    weight: m.weight,
+                }
+            })
+            .filter(|m| board.get(m.x, m.y).is_none())
+            .collect()
+    }
+
+    /// 가중치에 비례하여 정석 후보 하나를 무작위로 고름 (정석에 없는 국면이면 `None`)
+    pub fn choose(
+        &self,
+        board: &Board,
+        to_move: StoneColor,
+        rng: &mut Rng,
+    ) -> Option<(usize, usize)> {
+        let moves = self.lookup(board, to_move);
+        let total: u64 = moves.iter().map(|m| m.weight as u64).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut pick = rng.next_u64() % total;
+        moves.iter().find_map(|m| {
+            if pick < m.weight as u64 {
+                Some((m.x, m.y))
+            } else {
+                pick -= m.weight as u64;
+                None
+            }
+        })
+    }
+
+    /// 판 크기가 이 정석과 같은지 확인
+    fn fits(&self, board: &Board) -> bool {
+        (board.width(), board.height()) == (self.width, self.height)
+    }
+
+    /// 정석 파일 내용을 읽음
+    pub fn parse(text: &str) -> Result<Self, BookError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.split('#').next().unwrap_or("").trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        // 헤더: 형식 이름과 버전
+        let (_, header) = lines.next().ok_or(BookError::MissingHeader)?;
+        let version = match header.split_whitespace().collect::<Vec<_>>()[..] {
+            [BOOK_MAGIC, version] => version.parse().map_err(|_| BookError::MissingHeader)?,
+            _ => return Err(BookError::MissingHeader),
+        };
+        if version != BOOK_VERSION {
+            return Err(BookError::UnsupportedVersion(version));
+        }
+
+        // 판 크기
+        let (_, size) = lines.next().ok_or(BookError::InvalidSize)?;
+        let (width, height) = match size.split_whitespace().collect::<Vec<_>>()[..] {
+            ["size", width, height] => (
+                width.parse().map_err(|_| BookError::InvalidSize)?,
+                height.parse().map_err(|_| BookError::InvalidSize)?,
+            ),
+            _ => return Err(BookError::InvalidSize),
+        };
+        if !(1..=super::engine::MAX_BOARD_SIZE).contains(&width)
+            || !(1..=super::engine::MAX_BOARD_SIZE).contains(&height)
+        {
+            return Err(BookError::InvalidSize);
+        }
+
+        let mut book = Self::new(width, height);
+        for (number, line) in lines {
+            book.parse_entry(number, line)?;
+        }
+        Ok(book)
+    }
+
+    /// 국면 한 줄을 읽어 정석에 더함
+    fn parse_entry(&mut self, number: usize, line: &str) -> Result<(), BookError> {
+        let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+        let [to_move, black, white, moves] = fields[..] else {
+            return Err(BookError::InvalidLine(number));
+        };
+        let to_move = match to_move {
+            "black" => StoneColor::Black,
+            "white" => StoneColor::White,
+            _ => return Err(BookError::InvalidLine(number)),
+        };
+
+        let mut board = Board::with_size(self.width, self.height);
+        for (list, color) in [(black, StoneColor::Black), (white, StoneColor::White)] {
+            for token in list_items(list) {
+                let (x, y) = self.point(number, token)?;
+                board.set(x, y, Some(color));
+            }
+        }
+
+        for token in list_items(moves) {
+            let (point, weight) = token
+                .split_once(':')
+                .ok_or(BookError::InvalidLine(number))?;
+            let weight = weight.parse().map_err(|_| BookError::InvalidLine(number))?;
+            let (x, y) = self.point(number, point)?;
+            if board.get(x, y).is_some() {
+                return Err(BookError::InvalidPoint(number, point.to_string()));
+            }
+            self.add(&board, to_move, x, y, weight);
+        }
+        Ok(())
+    }
+
+    /// 이 정석의 판 안에 있는 좌표를 읽음
+    fn point(&self, number: usize, token: &str) -> Result<(usize, usize), BookError> {
+        parse_point(token)
+            .filter(|&(x, y)| x < self.width && y < self.height)
+            .ok_or_else(|| BookError::InvalidPoint(number, token.to_string()))
+    }
+
+    /// 정석 파일 형식의 문자열로 변환 (돌이 적은 국면부터 항상 같은 순서로)
+    pub fn to_text(&self) -> String {
+        let mut entries: Vec<(&u64, &BookEntry)> = self.entries.iter().collect();
+        entries.sort_by_key(|&(key, entry)| (entry.board.stones().count(), *key));
+
+        let mut text = format!(
+            "{BOOK_MAGIC} {BOOK_VERSION}\nsize {} {}\n",
+            self.width, self.height
+        );
+        for (_, entry) in entries {
+            let stones = |color: StoneColor| {
+                let points: Vec<String> = entry
+                    .board
+                    .stones()
+                    .filter(|&(_, _, stone)| stone == color)
+                    .map(|(x, y, _)| format_point(x, y))
+                    .collect();
+                if points.is_empty() {
+                    "-".to_string()
+                } else {
+                    points.join(" ")
+                }
+            };
+            let mut moves = entry.moves.clone();
+            moves.sort_by_key(|m| (std::cmp::Reverse(m.weight), m.y, m.x));
+            let moves: Vec<String> = moves
+                .iter()
+                .map(|m| format!("{}:{}", format_point(m.x, m.y), m.weight))
+                .collect();
+
+            text += &format!(
+                "{} | {} | {} | {}\n",
+                match entry.to_move {
+                    StoneColor::Black => "black",
+                    StoneColor::White => "white",
+                },
+                stones(StoneColor::Black),
+                stones(StoneColor::White),
+                moves.join(" ")
+            );
+        }
+        text
+    }
+}
+
+/// 정규화된 국면 키(둘 차례 포함)와 원래 판을 대표 국면으로 옮기는 변환들
+///
+/// 국면 자체가 대칭이면 같은 대표 국면으로 옮기는 변환이 여럿일 수 있습니다.
+fn canonical_key(board: &Board, to_move: StoneColor) -> (u64, Vec<Symmetry>) {
+    let (hash, _) = zobrist::canonical(board);
+    let symmetries = Symmetry::ALL
+        .into_iter()
+        .filter(|symmetry| {
+            symmetry.fits(board.width(), board.height()) && symmetry.hash(board) == hash
+        })
+        .collect();
+    (hash ^ zobrist::side_key(to_move), symmetries)
+}
+
+/// `-`이면 빈 목록, 아니면 공백으로 나눈 항목
+fn list_items(list: &str) -> impl Iterator<Item = &str> {
+    list.split_whitespace().filter(|&item| item != "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 저장소에 들어 있는 오프닝 정석
+    const OPENING_BOOK: &str = include_str!("../../../assets/books/opening.book");
+
+    /// `width`x`height` 판에 흑, 백 돌을 놓은 국면
+    fn board(width: usize, height: usize, black: &[&str], white: &[&str]) -> Board {
+        let mut board = Board::with_size(width, height);
+        for (points, color) in [(black, StoneColor::Black), (white, StoneColor::White)] {
+            for point in points {
+                let (x, y) = parse_point(point).unwrap();
+                board.set(x, y, Some(color));
+            }
+        }
+        board
+    }
+
+    /// 후보 수를 (x, y, 가중치)로 정렬한 목록
+    fn sorted(moves: Vec<BookMove>) -> Vec<(usize, usize, u32)> {
+        let mut moves: Vec<_> = moves.into_iter().map(|m| (m.x, m.y, m.weight)).collect();
+        moves.sort();
+        moves
+    }
+
+    #[test]
+    fn finds_moves_in_every_orientation() {
+        // 대칭이 없는 국면이므로 변환마다 후보 좌표가 그대로 따라 움직여야 함
+        let original = board(15, 15, &["h8", "j8"], &["i9"]);
+        let mut book = OpeningBook::new(15, 15);
+        book.add(&original, StoneColor::White, 8, 7, 3);
+        book.add(&original, StoneColor::White, 6, 9, 1);
+        assert_eq!(book.len(), 1);
+
+        for symmetry in Symmetry::ALL {
+            let transformed = symmetry.transform(&original);
+            let (sx, sy) = symmetry.apply(8, 7, 15, 15);
+            let (tx, ty) = symmetry.apply(6, 9, 15, 15);
+            assert_eq!(
+                sorted(book.lookup(&transformed, StoneColor::White)),
+                sorted(vec![
+                    BookMove {
+                        x: sx,
+                        y: sy,
+                        weight: 3
+                    },
+                    BookMove {
+                        x: tx,
+                        y: ty,
+                        weight: 1
+                    },
+                ]),
+                "{symmetry:?}"
+            );
+            assert!(book.lookup(&transformed, StoneColor::Black).is_empty());
+
+            // 같은 국면을 돌려서 더한 수는 한 항목에 합쳐짐
+            let mut turned = book.clone();
+            turned.add(&transformed, StoneColor::White, sx, sy, 2);
+            assert_eq!(turned.len(), 1);
+            let weights: Vec<u32> = turned
+                .lookup(&original, StoneColor::White)
+                .iter()
+                .filter(|m| (m.x, m.y) == (8, 7))
+                .map(|m| m.weight)
+                .collect();
+            assert_eq!(weights, [5], "{symmetry:?}");
+        }
+    }
+
+    #[test]
+    fn merges_equivalent_moves_in_symmetric_positions() {
+        // 천원 하나뿐인 국면에서는 대각 네 자리가 같은 수
+        let center = board(15, 15, &["h8"], &[]);
+        let mut book = OpeningBook::new(15, 15);
+        for point in ["i9", "g9", "g7", "i7"] {
+            let (x, y) = parse_point(point).unwrap();
+            book.add(&center, StoneColor::White, x, y, 1);
+        }
+        for symmetry in Symmetry::ALL {
+            let moves = book.lookup(&symmetry.transform(&center), StoneColor::White);
+            let &[only] = moves.as_slice() else {
+                panic!("{symmetry:?}: {moves:?}");
+            };
+            assert_eq!(only.weight, 4);
+            assert_eq!((only.x.abs_diff(7), only.y.abs_diff(7)), (1, 1));
+        }
+    }
+
+    #[test]
+    fn keeps_axes_on_rectangular_boards() {
+        let original = board(15, 11, &["c3"], &[]);
+        let mut book = OpeningBook::new(15, 11);
+        book.add(&original, StoneColor::White, 3, 3, 1);
+        for symmetry in Symmetry::ALL.into_iter().filter(|s| s.fits(15, 11)) {
+            let (x, y) = symmetry.apply(3, 3, 15, 11);
+            assert_eq!(
+                book.lookup(&symmetry.transform(&original), StoneColor::White),
+                [BookMove { x, y, weight: 1 }],
+                "{symmetry:?}"
+            );
+        }
+        assert!(book
+            .lookup(&Symmetry::Transpose.transform(&original), StoneColor::White)
+            .is_empty());
+    }
+
+    #[test]
+    fn round_trips_the_opening_book() {
+        let book = OpeningBook::parse(OPENING_BOOK).unwrap();
+        assert_eq!((book.width(), book.height()), (15, 15));
+        assert!(!book.is_empty());
+
+        let text = book.to_text();
+        let reparsed = OpeningBook::parse(&text).unwrap();
+        assert_eq!(reparsed.len(), book.len());
+        assert_eq!(reparsed.to_text(), text);
+
+        let opening = board(15, 15, &["h8"], &["i9"]);
+        for symmetry in Symmetry::ALL {
+            let position = symmetry.transform(&opening);
+            assert_eq!(
+                sorted(reparsed.lookup(&position, StoneColor::Black)),
+                sorted(book.lookup(&position, StoneColor::Black))
+            );
+        }
+        assert_eq!(
+            sorted(book.lookup(&Board::with_size(15, 15), StoneColor::Black)),
+            [(7, 7, 1)]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_books() {
+        let parse = |text: &str| OpeningBook::parse(text).unwrap_err();
+        assert_eq!(parse(""), BookError::MissingHeader);
+        assert_eq!(parse("gomoku-book 2\n"), BookError::UnsupportedVersion(2));
+        assert_eq!(parse("gomoku-book 1\nsize 0 15\n"), BookError::InvalidSize);
+        assert_eq!(
+            parse("gomoku-book 1\nsize 9 9\nblack | - | -\n"),
+            BookError::InvalidLine(3)
+        );
+        assert_eq!(
+            parse("gomoku-book 1\nsize 9 9\n\nwhite | e5 | - | j5:1\n"),
+            BookError::InvalidPoint(4, "j5".to_string())
+        );
+        assert_eq!(
+            parse("gomoku-book 1\nsize 9 9\nwhite | e5 | - | e5:1\n"),
+            BookError::InvalidPoint(3, "e5".to_string())
+        );
+    }
+}
@@ -0,0 +1,783 @@
+//! Bevy에 의존하지 않는 오목 규칙 엔진
+//!
+//! 판 상태, 착수 검사, 기보, 승부 판정을 담당합니다.
+//! Bevy 시스템은 이 엔진의 결과를 화면에 그리기만 하며,
+//! 같은 엔진을 테스트, AI, 서버 등에서 창 없이 그대로 사용할 수 있습니다.
+
+use std::fmt;
+
+use super::rules::{Forbidden, RuleSet};
+use super::zobrist;
+
+// ==================== 엔진 상수 정의 ====================
+/// 기본 오목판 크기 (15x15)
+pub const DEFAULT_BOARD_SIZE: usize = 15;
+
+/// 엔진이 지원하는 최대 오목판 크기 (가로, 세로 각각)
+pub const MAX_BOARD_SIZE: usize = 26;
+
+/// 승리에 필요한 연속 돌 개수
+pub const WIN_LENGTH: usize = 5;
+
+/// 확인할 4가지 방향: 가로, 세로, 대각선 2개
+pub const DIRECTIONS: [(i32, i32); 4] = [
+    (1, 0),  // 가로 (→)
+    (0, 1),  // 세로 (↑)
+    (1, 1),  // 대각선 (↗)
+    (1, -1), // 대각선 (↘)
+];
+
+// ==================== 돌 색깔 열거형 ====================
+/// 오목돌의 색깔을 나타내는 열거형
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StoneColor {
+    Black, // 흑돌
+    White, // 백돌
+}
+
+impl StoneColor {
+    /// 상대방 돌 색깔 반환
+    pub fn opposite(self) -> Self {
+        match self {
+            StoneColor::Black => StoneColor::White,
+            StoneColor::White => StoneColor::Black,
+        }
+    }
+
+    /// 플레이어별 배열에서 사용할 인덱스 반환
+    pub fn index(self) -> usize {
+        match self {
+            StoneColor::Black => 0,
+            StoneColor::White => 1,
+        }
+    }
+}
+
+impl fmt::Display for StoneColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoneColor::Black => write!(f, "Black"),
+            StoneColor::White => write!(f, "White"),
+        }
+    }
+}
+
+// ==================== 오목판 ====================
+/// 오목판의 돌 배치
+///
+/// 가로(`width`)와 세로(`height`)가 다른 판도 지원합니다.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Board {
+    width: usize,                   // 가로 칸 수
+    height: usize,                  // 세로 칸 수
+    cells: Vec<Option<StoneColor>>, // cells[y * width + x]
+    hash: u64,                      // 놓인 돌의 조브리스트 해시 (돌을 놓거나 치울 때마다 갱신)
+}
+
+impl Default for Board {
+    /// 15x15 빈 판
+    fn default() -> Self {
+        Self::with_size(DEFAULT_BOARD_SIZE, DEFAULT_BOARD_SIZE)
+    }
+}
+
+impl Board {
+    /// 15x15 빈 오목판 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지정한 크기의 빈 오목판 생성
+    ///
+    /// # Panics
+    /// 가로나 세로가 0이거나 `MAX_BOARD_SIZE`보다 크면 패닉이 발생합니다.
+    pub fn with_size(width: usize, height: usize) -> Self {
+        assert!(
+            (1..=MAX_BOARD_SIZE).contains(&width) && (1..=MAX_BOARD_SIZE).contains(&height),
+            "board size {width}x{height} is not supported"
+        );
+        Self {
+            width,
+            height,
+            cells: vec![None; width * height],
+            hash: 0,
+        }
+    }
+
+    /// 가로 칸 수
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// 세로 칸 수
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 좌표가 판 안에 있는지 확인 (음수 좌표도 허용)
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32
+    }
+
+    /// 해당 위치의 돌 반환
+    pub fn get(&self, x: usize, y: usize) -> Option<StoneColor> {
+        self.cells[y * self.width + x]
+    }
+
+    /// 해당 위치에 돌을 놓거나(`Some`) 치움(`None`)
+    ///
+    /// 규칙 검사 없이 판만 바꾸므로 탐색이나 복기에 사용합니다.
+    pub fn set(&mut self, x: usize, y: usize, stone: Option<StoneColor>) {
+        let cell = &mut self.cells[y * self.width + x];
+        if let Some(previous) = *cell {
+            self.hash ^= zobrist::stone_key(x, y, previous);
+        }
+        if let Some(color) = stone {
+            self.hash ^= zobrist::stone_key(x, y, color);
+        }
+        *cell = stone;
+    }
+
+    /// 놓인 돌의 조브리스트 해시
+    ///
+    /// 같은 돌 배치면 항상 같은 값이며, 돌을 놓을 때마다 바로 갱신되므로 계산 비용이 없습니다.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// 판이 비어 있는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(Option::is_none)
+    }
+
+    /// 빈 자리가 하나도 없는지 확인
+    pub fn is_full(&self) -> bool {
+        self.cells.iter().all(Option::is_some)
+    }
+
+    /// 판의 모든 좌표를 `(x, y)` 형태로 반환
+    pub fn points(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// 놓인 모든 돌을 `(x, y, 색상)` 형태로 반환
+    pub fn stones(&self) -> impl Iterator<Item = (usize, usize, StoneColor)> + '_ {
+        self.points()
+            .filter_map(move |(x, y)| self.get(x, y).map(|color| (x, y, color)))
+    }
+
+    /// 한 방향으로 같은 색 돌이 몇 개 연속되는지 세기 (시작 위치 제외)
+    pub fn count_direction(
+        &self,
+        x: usize,
+        y: usize,
+        dx: i32,
+        dy: i32,
+        color: StoneColor,
+    ) -> usize {
+        let mut count = 0;
+        let mut nx = x as i32 + dx;
+        let mut ny = y as i32 + dy;
+        while self.in_bounds(nx, ny) && self.get(nx as usize, ny as usize) == Some(color) {
+            count += 1;
+            nx += dx; // 다음 위치로 이동
+            ny += dy;
+        }
+        count
+    }
+
+    /// `color`가 앞으로 5목을 만들 수 있는 자리가 남아 있는지 확인
+    ///
+    /// 상대 돌이 하나도 없는 연속된 5칸이 있으면 가능성이 있다고 봅니다.
+    /// 장목이나 금수로 실제로는 불가능한 경우도 가능하다고 보므로,
+    /// 이 함수가 `false`를 반환하면 그 색은 확실히 5목을 만들 수 없습니다.
+    pub fn can_make_five(&self, color: StoneColor) -> bool {
+        let blocker = Some(color.opposite());
+        self.points().any(|(x, y)| {
+            DIRECTIONS.iter().any(|&(dx, dy)| {
+                (0..WIN_LENGTH as i32).all(|k| {
+                    let (nx, ny) = (x as i32 + dx * k, y as i32 + dy * k);
+                    self.in_bounds(nx, ny) && self.get(nx as usize, ny as usize) != blocker
+                })
+            })
+        })
+    }
+}
+
+// ==================== 착수 결과 ====================
+/// 기보에 기록되는 한 수
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Move {
+    pub color: StoneColor, // 돌 색상
+    pub x: usize,          // x 좌표
+    pub y: usize,          // y 좌표
+}
+
+/// 착수에 성공했을 때의 결과
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MoveOutcome {
+    /// 게임이 계속됨 (턴이 상대에게 넘어감)
+    Continue,
+    /// 해당 색이 5목을 완성하여 승리
+    Win(StoneColor),
+    /// 더 이상 승부가 날 수 없어 무승부
+    Draw,
+}
+
+/// 끝난 게임의 결과
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GameResult {
+    /// 해당 색의 승리 (5목 완성 또는 상대의 기권)
+    Win(StoneColor),
+    /// 무승부
+    Draw,
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::Win(color) => write!(f, "{color} wins"),
+            GameResult::Draw => write!(f, "Draw"),
+        }
+    }
+}
+
+/// 착수할 수 없는 이유
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MoveError {
+    /// 게임이 이미 끝남
+    GameOver,
+    /// 판 밖의 좌표
+    OutOfBounds,
+    /// 이미 돌이 있는 자리
+    Occupied(StoneColor),
+    /// 현재 규칙에서 금지된 수 (렌주룰 흑 금수)
+    Forbidden(Forbidden),
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::GameOver => write!(f, "the game is already over"),
+            MoveError::OutOfBounds => write!(f, "the point is outside the board"),
+            MoveError::Occupied(color) => write!(f, "the point is occupied by {color}"),
+            MoveError::Forbidden(kind) => write!(f, "the move is forbidden ({kind})"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+// ==================== 게임 ====================
+/// 한 판의 오목 게임 (판, 규칙, 턴, 기보, 결과)
+#[derive(Clone, Debug)]
+pub struct Game {
+    board: Board,                      // 현재 판 상태
+    rule_set: RuleSet,                 // 적용할 규칙
+    early_draw: bool,                  // 양쪽 모두 5목이 불가능해지면 바로 무승부 처리할지 여부
+    current_player: StoneColor,        // 현재 턴의 플레이어
+    history: Vec<Move>,                // 지금까지 둔 수
+    undone: Vec<Move>,                 // 무르기로 취소된 수 (가장 최근에 취소된 수가 마지막)
+    result: Option<GameResult>,        // 게임 결과 (끝났을 경우)
+    winning_line: Vec<(usize, usize)>, // 승리를 만든 돌의 좌표 (5목으로 이겼을 경우)
+}
+
+impl Default for Game {
+    /// 흑이 선공인 15x15 빈 판으로 시작
+    fn default() -> Self {
+        Self::with_board(Board::new())
+    }
+}
+
+impl Game {
+    /// 15x15 판에서 새 게임 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지정한 크기의 판에서 새 게임 생성
+    pub fn with_size(width: usize, height: usize) -> Self {
+        Self::with_board(Board::with_size(width, height))
+    }
+
+    /// 빈 판에서 흑이 선공으로 시작하는 게임 생성
+    fn with_board(board: Board) -> Self {
+        Self {
+            board,
+            rule_set: RuleSet::default(),
+            early_draw: false,
+            current_player: StoneColor::Black,
+            history: Vec::new(),
+            undone: Vec::new(),
+            result: None,
+            winning_line: Vec::new(),
+        }
+    }
+
+    /// 이미 돌이 놓인 판에서 `to_move`의 차례로 시작하는 게임 생성 (기보는 비어 있음)
+    ///
+    /// 판에 이미 5목이 있으면 그 색의 승리로, 판이 가득 찼으면 무승부로 끝난 게임이 됩니다.
+    pub fn from_position(board: Board, rule_set: RuleSet, to_move: StoneColor) -> Self {
+        let mut game = Self {
+            rule_set,
+            current_player: to_move,
+            ..Self::with_board(board)
+        };
+        let win = game.board.stones().find_map(|(x, y, color)| {
+            rule_set
+                .winning_line(&game.board, x, y, color)
+                .map(|line| (color, line))
+        });
+        if let Some((color, line)) = win {
+            game.result = Some(GameResult::Win(color));
+            game.winning_line = line;
+        } else if game.board.is_full() {
+            game.result = Some(GameResult::Draw);
+        }
+        game
+    }
+
+    /// 적용할 규칙 설정 (게임 생성 직후에 사용)
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    /// 조기 무승부 판정 사용 여부 설정 (게임 생성 직후에 사용)
+    ///
+    /// 켜면 판이 다 차기 전이라도 양쪽 모두 5목을 만들 자리가 없어지는 즉시 무승부가 됩니다.
+    pub fn with_early_draw(mut self, early_draw: bool) -> Self {
+        self.early_draw = early_draw;
+        self
+    }
+
+    /// 현재 판 상태
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// 적용 중인 규칙
+    pub fn rule_set(&self) -> RuleSet {
+        self.rule_set
+    }
+
+    /// 현재 턴의 플레이어
+    pub fn current_player(&self) -> StoneColor {
+        self.current_player
+    }
+
+    /// 지금까지 둔 수 (먼저 둔 수부터)
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+
+    /// 마지막으로 둔 수
+    pub fn last_move(&self) -> Option<Move> {
+        self.history.last().copied()
+    }
+
+    /// 무르기로 취소되어 다시 둘 수 있는 수 (다음에 다시 둘 수부터)
+    pub fn undone(&self) -> impl Iterator<Item = &Move> {
+        self.undone.iter().rev()
+    }
+
+    /// 조기 무승부 판정 사용 여부
+    pub fn early_draw(&self) -> bool {
+        self.early_draw
+    }
+
+    /// 게임 결과 (끝났을 경우)
+    pub fn result(&self) -> Option<GameResult> {
+        self.result
+    }
+
+    /// 승자 (있을 경우, 무승부이면 `None`)
+    pub fn winner(&self) -> Option<StoneColor> {
+        match self.result {
+            Some(GameResult::Win(color)) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// 승리를 만든 돌의 좌표 (한쪽 끝부터 순서대로)
+    ///
+    /// 5목으로 승부가 나지 않았으면 (진행 중, 무승부, 기권) 비어 있습니다.
+    pub fn winning_line(&self) -> &[(usize, usize)] {
+        &self.winning_line
+    }
+
+    /// 게임 종료 여부
+    pub fn is_over(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// 더 이상 승부가 날 수 없는지 확인
+    ///
+    /// 판이 가득 찼거나, 조기 무승부 판정을 켠 경우 양쪽 모두 5목을 만들 자리가 없으면 참입니다.
+    pub fn is_drawn(&self) -> bool {
+        self.board.is_full()
+            || (self.early_draw
+                && !self.board.can_make_five(StoneColor::Black)
+                && !self.board.can_make_five(StoneColor::White))
+    }
+
+    /// 현재 플레이어가 해당 위치에 둘 수 있는지 검사
+    pub fn check_move(&self, x: usize, y: usize) -> Result<(), MoveError> {
+        self.check_placement(x, y)?;
+        match self
+            .rule_set
+            .forbidden(&self.board, x, y, self.current_player)
+        {
+            Some(kind) => Err(MoveError::Forbidden(kind)),
+            None => Ok(()),
+        }
+    }
+
+    /// 금수를 빼고 해당 위치에 돌을 놓을 수 있는지 검사 (게임 진행 중, 판 안, 빈 자리)
+    pub fn check_placement(&self, x: usize, y: usize) -> Result<(), MoveError> {
+        if self.is_over() {
+            return Err(MoveError::GameOver);
+        }
+        if x >= self.board.width() || y >= self.board.height() {
+            return Err(MoveError::OutOfBounds);
+        }
+        if let Some(color) = self.board.get(x, y) {
+            return Err(MoveError::Occupied(color));
+        }
+        Ok(())
+    }
+
+    /// 현재 플레이어가 해당 위치에 둘 수 있는지 확인
+    pub fn is_legal(&self, x: usize, y: usize) -> bool {
+        self.check_move(x, y).is_ok()
+    }
+
+    /// 현재 플레이어가 둘 수 있는 모든 위치
+    pub fn legal_moves(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.board.points().filter(|&(x, y)| self.is_legal(x, y))
+    }
+
+    /// 현재 플레이어의 돌을 놓고 승부를 판정
+    ///
+    /// 승부가 나지 않으면 무승부인지 확인한 뒤 턴이 상대에게 넘어갑니다.
+    /// 새로 수를 두면 무르기로 취소된 수는 더 이상 다시 둘 수 없습니다.
+    pub fn play(&mut self, x: usize, y: usize) -> Result<MoveOutcome, MoveError> {
+        self.check_move(x, y)?;
+        let outcome = self.place(x, y);
+        self.undone.clear();
+        Ok(outcome)
+    }
+
+    /// 금수 검사 없이 현재 플레이어의 돌을 놓고 승부를 판정
+    ///
+    /// 블라인드 게임처럼 금수를 실제 판이 아닌 다른 판으로 판정할 때 사용하며,
+    /// 나머지는 `play`와 같습니다.
+    pub fn play_ignoring_forbidden(
+        &mut self,
+        x: usize,
+        y: usize,
+    ) -> Result<MoveOutcome, MoveError> {
+        self.check_placement(x, y)?;
+        let outcome = self.place(x, y);
+        self.undone.clear();
+        Ok(outcome)
+    }
+
+    /// 마지막 수를 무름
+    ///
+    /// 판에서 돌을 치우고, 그 수를 둔 플레이어의 턴으로 되돌리며, 게임 결과를 지웁니다.
+    /// 무를 수가 없으면 `None`을 반환합니다.
+    pub fn undo(&mut self) -> Option<Move> {
+        let last = self.history.pop()?;
+        self.board.set(last.x, last.y, None);
+        self.current_player = last.color;
+        self.result = None;
+        self.winning_line.clear();
+        self.undone.push(last);
+        Some(last)
+    }
+
+    /// 가장 최근에 무른 수를 다시 둠
+    ///
+    /// 무른 뒤에는 새 수를 두지 않는 한 판이 그대로이므로, 처음 둘 때 허용된 수는 금수를
+    /// 다시 검사하지 않고 둡니다. 다시 둘 수가 없으면 `None`을 반환합니다.
+    pub fn redo(&mut self) -> Option<(Move, MoveOutcome)> {
+        let next = *self.undone.last()?;
+        let previous = std::mem::replace(&mut self.current_player, next.color);
+        if self.check_placement(next.x, next.y).is_err() {
+            // 무른 뒤 판이 바뀌지 않았다면 일어나지 않음
+            self.current_player = previous;
+            return None;
+        }
+        self.undone.pop();
+        Some((next, self.place(next.x, next.y)))
+    }
+
+    /// 검사를 마친 자리에 현재 플레이어의 돌을 놓고 승부를 판정 (다시 두기 목록은 그대로 둠)
+    fn place(&mut self, x: usize, y: usize) -> MoveOutcome {
+        let color = self.current_player;
+        self.board.set(x, y, Some(color));
+        self.history.push(Move { color, x, y });
+
+        if let Some(line) = self.rule_set.winning_line(&self.board, x, y, color) {
+            self.result = Some(GameResult::Win(color));
+            self.winning_line = line;
+            MoveOutcome::Win(color)
+        } else if self.is_drawn() {
+            self.result = Some(GameResult::Draw);
+            MoveOutcome::Draw
+        } else {
+            self.current_player = color.opposite();
+            MoveOutcome::Continue
+        }
+    }
+
+    /// 돌을 놓지 않고 턴을 넘김
+    pub fn pass(&mut self) -> Result<(), MoveError> {
+        if self.is_over() {
+            return Err(MoveError::GameOver);
+        }
+        self.current_player = self.current_player.opposite();
+        Ok(())
+    }
+
+    /// 해당 색의 플레이어가 기권 (상대가 승리)
+    pub fn resign(&mut self, color: StoneColor) {
+        if !self.is_over() {
+            self.result = Some(GameResult::Win(color.opposite()));
+        }
+    }
+}
+
+#[cfg(test)]
+impl Board {
+    /// 그림으로 판 생성 (테스트용)
+    ///
+    /// 첫 줄이 맨 위(가장 큰 y)이며 `X`는 흑, `O`는 백, 나머지 글자는 빈 칸입니다.
+    pub(crate) fn from_rows(rows: &[&str]) -> Self {
+        let mut board = Self::with_size(rows[0].len(), rows.len());
+        for (index, row) in rows.iter().enumerate() {
+            let y = rows.len() - 1 - index;
+            for (x, c) in row.chars().enumerate() {
+                match c {
+                    'X' => board.set(x, y, Some(StoneColor::Black)),
+                    'O' => board.set(x, y, Some(StoneColor::White)),
+                    _ => {}
+                }
+            }
+        }
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 기보에 기록될 한 수
+    fn stone(color: StoneColor, x: usize, y: usize) -> Move {
+        Move { color, x, y }
+    }
+
+    /// 흑이 `black`을, 백이 `white`를 번갈아 둠 (흑부터, 마지막 흑 수의 결과 반환)
+    fn play_all(
+        game: &mut Game,
+        black: &[(usize, usize)],
+        white: &[(usize, usize)],
+    ) -> MoveOutcome {
+        let mut outcome = MoveOutcome::Continue;
+        for (index, &(x, y)) in black.iter().enumerate() {
+            outcome = game.play(x, y).unwrap();
+            if let Some(&(x, y)) = white.get(index) {
+                if outcome == MoveOutcome::Continue {
+                    game.play(x, y).unwrap();
+                }
+            }
+        }
+        outcome
+    }
+
+    #[test]
+    fn play_alternates_turns_and_records_history() {
+        let mut game = Game::new();
+        assert_eq!(game.play(7, 7), Ok(MoveOutcome::Continue));
+        assert_eq!(game.current_player(), StoneColor::White);
+        assert_eq!(game.play(8, 8), Ok(MoveOutcome::Continue));
+        assert_eq!(game.current_player(), StoneColor::Black);
+
+        assert_eq!(game.board().get(7, 7), Some(StoneColor::Black));
+        assert_eq!(game.board().get(8, 8), Some(StoneColor::White));
+        assert_eq!(
+            game.history(),
+            [
+                stone(StoneColor::Black, 7, 7),
+                stone(StoneColor::White, 8, 8),
+            ]
+        );
+        assert_eq!(game.last_move(), Some(stone(StoneColor::White, 8, 8)));
+    }
+
+    #[test]
+    fn rejected_moves_leave_the_game_unchanged() {
+        let mut game = Game::with_size(9, 9);
+        game.play(4, 4).unwrap();
+
+        assert_eq!(game.play(4, 4), Err(MoveError::Occupied(StoneColor::Black)));
+        assert_eq!(game.play(9, 0), Err(MoveError::OutOfBounds));
+        assert_eq!(game.play(0, 9), Err(MoveError::OutOfBounds));
+        assert_eq!(game.current_player(), StoneColor::White);
+        assert_eq!(game.history().len(), 1);
+
+        game.resign(StoneColor::White);
+        assert_eq!(game.result(), Some(GameResult::Win(StoneColor::Black)));
+        assert_eq!(game.play(0, 0), Err(MoveError::GameOver));
+        assert_eq!(game.pass(), Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn renju_rejects_black_double_three() {
+        let board = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            "....X....",
+            "....X....",
+            "..XX.....",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        let game = Game::from_position(board, RuleSet::Renju, StoneColor::Black);
+        assert_eq!(
+            game.check_move(4, 3),
+            Err(MoveError::Forbidden(Forbidden::DoubleThree))
+        );
+        assert!(!game.legal_moves().any(|pos| pos == (4, 3)));
+    }
+
+    #[test]
+    fn undo_and_redo_restore_board_turn_and_result() {
+        let mut game = Game::with_size(9, 9);
+        let outcome = play_all(
+            &mut game,
+            &[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)],
+            &[(0, 8), (1, 8), (2, 8), (3, 8)],
+        );
+        assert_eq!(outcome, MoveOutcome::Win(StoneColor::Black));
+        assert!(game.is_over());
+
+        let undone = game.undo().unwrap();
+        assert_eq!(undone, stone(StoneColor::Black, 4, 0));
+        assert_eq!(game.board().get(4, 0), None);
+        assert_eq!(game.current_player(), StoneColor::Black);
+        assert_eq!(game.result(), None);
+        assert!(game.winning_line().is_empty());
+
+        game.undo().unwrap();
+        assert_eq!(game.current_player(), StoneColor::White);
+        assert_eq!(game.undone().count(), 2);
+
+        assert_eq!(
+            game.redo(),
+            Some((stone(StoneColor::White, 3, 8), MoveOutcome::Continue))
+        );
+        assert_eq!(
+            game.redo(),
+            Some((
+                stone(StoneColor::Black, 4, 0),
+                MoveOutcome::Win(StoneColor::Black)
+            ))
+        );
+        assert_eq!(game.redo(), None);
+        assert_eq!(game.winner(), Some(StoneColor::Black));
+    }
+
+    #[test]
+    fn a_new_move_discards_the_redo_list() {
+        let mut game = Game::new();
+        game.play(7, 7).unwrap();
+        game.play(8, 8).unwrap();
+        game.undo().unwrap();
+        assert_eq!(game.undone().count(), 1);
+
+        game.play(6, 6).unwrap();
+        assert_eq!(game.undone().count(), 0);
+        assert_eq!(game.redo(), None);
+        assert_eq!(game.undo(), Some(stone(StoneColor::White, 6, 6)));
+    }
+
+    #[test]
+    fn five_in_a_row_wins_in_every_direction() {
+        for (dx, dy) in DIRECTIONS {
+            let mut game = Game::new();
+            // 흑은 (5, 7)부터 한 방향으로, 백은 맨 아래 줄에 둠
+            let black: Vec<(usize, usize)> = (0..WIN_LENGTH as i32)
+                .map(|k| ((5 + dx * k) as usize, (7 + dy * k) as usize))
+                .collect();
+            let white: Vec<(usize, usize)> = (0..4).map(|x| (x * 3, 0)).collect();
+
+            let outcome = play_all(&mut game, &black, &white);
+            assert_eq!(
+                outcome,
+                MoveOutcome::Win(StoneColor::Black),
+                "direction ({dx}, {dy})"
+            );
+            assert_eq!(game.winning_line(), black, "direction ({dx}, {dy})");
+            assert_eq!(game.current_player(), StoneColor::Black);
+        }
+    }
+
+    #[test]
+    fn winning_line_runs_from_one_end_when_the_gap_is_filled_last() {
+        let mut game = Game::new();
+        let outcome = play_all(
+            &mut game,
+            &[(3, 3), (4, 4), (6, 6), (7, 7), (5, 5)],
+            &[(0, 14), (1, 14), (2, 14), (3, 14)],
+        );
+        assert_eq!(outcome, MoveOutcome::Win(StoneColor::Black));
+        assert_eq!(
+            game.winning_line(),
+            [(3, 3), (4, 4), (5, 5), (6, 6), (7, 7)]
+        );
+    }
+
+    #[test]
+    fn filling_the_board_is_a_draw() {
+        let mut game = Game::with_size(2, 1);
+        assert_eq!(game.play(0, 0), Ok(MoveOutcome::Continue));
+        assert_eq!(game.play(1, 0), Ok(MoveOutcome::Draw));
+        assert_eq!(game.result(), Some(GameResult::Draw));
+        assert_eq!(game.winner(), None);
+    }
+
+    #[test]
+    fn from_position_finishes_a_game_that_is_already_won() {
+        let board = Board::from_rows(&[".......", "OOOOO..", ".......", "XXXX..."]);
+        let game = Game::from_position(board, RuleSet::Freestyle, StoneColor::Black);
+        assert_eq!(game.result(), Some(GameResult::Win(StoneColor::White)));
+        assert_eq!(
+            game.winning_line(),
+            [(0, 2), (1, 2), (2, 2), (3, 2), (4, 2)]
+        );
+        assert!(game.history().is_empty());
+    }
+
+    #[test]
+    fn board_hash_depends_only_on_the_stones() {
+        let mut board = Board::new();
+        let empty = board.hash();
+        board.set(3, 4, Some(StoneColor::Black));
+        board.set(5, 6, Some(StoneColor::White));
+        let mut other = Board::new();
+        other.set(5, 6, Some(StoneColor::White));
+        other.set(3, 4, Some(StoneColor::Black));
+        assert_eq!(board.hash(), other.hash());
+        assert_ne!(board.hash(), empty);
+
+        board.set(3, 4, None);
+        board.set(5, 6, None);
+        assert_eq!(board.hash(), empty);
+    }
+}
@@ -0,0 +1,302 @@
+//! 외부 엔진 관리 (Gomocup 관리 프로그램 쪽)
+//!
+//! Gomocup (Piskvork) 프로토콜을 쓰는 엔진 실행 파일을 자식 프로세스로 띄우고
+//! 표준 입출력으로 명령을 주고받습니다. 엔진의 출력은 별도 스레드가 한 줄씩 읽어 채널로 넘기므로
+//! 정해진 시간 안에 응답이 없으면 시간 초과로, 출력이 끊기면 비정상 종료로 판단할 수 있습니다.
+//! 주고받은 명령과 엔진의 MESSAGE 등은 `EngineLog`에 남습니다. Bevy에 의존하지 않으며,
+//! 응답을 기다리는 동안 호출한 스레드가 멈추므로 화면에서는 작업 스레드에서 호출해야 합니다.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::engine::{Board, StoneColor};
+use super::protocol::{self, format_point};
+use super::rules::RuleSet;
+
+/// START 명령에 응답을 기다리는 시간
+pub const START_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 한 수의 시간 제한 외에 프로세스 통신 지연을 감안해 더 기다리는 시간
+pub const TIMEOUT_GRACE: Duration = Duration::from_millis(500);
+
+/// 로그에 남기는 최대 줄 수 (넘으면 오래된 줄부터 지움)
+const LOG_CAPACITY: usize = 200;
+
+/// 종료할 때 END 명령 후 엔진이 스스로 끝나기를 기다리는 시간
+const EXIT_TIMEOUT: Duration = Duration::from_millis(200);
+
+// ==================== 오류 ====================
+/// 외부 엔진과 통신할 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EngineError {
+    /// 실행 파일을 실행하지 못함 (경로, 이유)
+    Spawn(PathBuf, String),
+    /// 엔진이 응답 없이 종료됨 (종료 상태)
+    Crashed(String),
+    /// 제한 시간 안에 응답하지 않음
+    TimedOut(Duration),
+    /// 엔진이 ERROR 또는 UNKNOWN으로 응답함 (응답 줄)
+    Rejected(String),
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineError::Spawn(path, reason) => {
+                write!(f, "failed to start {}: {reason}", path.display())
+            }
+            EngineError::Crashed(status) => write!(f, "engine exited ({status})"),
+            EngineError::TimedOut(limit) => {
+                write!(f, "engine did not answer within {} ms", limit.as_millis())
+            }
+            EngineError::Rejected(reply) => write!(f, "engine replied \"{reply}\""),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
+
+// ==================== 통신 기록 ====================
+/// 엔진과 주고받은 줄의 기록 (여러 스레드에서 함께 사용)
+///
+/// 보낸 줄은 `>`, 받은 줄은 `<`, 관리 프로그램의 알림은 `!`로 시작합니다.
+#[derive(Clone, Debug, Default)]
+pub struct EngineLog(Arc<Mutex<VecDeque<String>>>);
+
+impl EngineLog {
+    /// 한 줄 추가
+    pub fn push(&self, line: impl Into<String>) {
+        let Ok(mut lines) = self.0.lock() else {
+            return;
+        };
+        if lines.len() == LOG_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line.into());
+    }
+
+    /// 가장 최근의 줄 `count`개 (오래된 줄부터)
+    pub fn recent(&self, count: usize) -> Vec<String> {
+        let Ok(lines) = self.0.lock() else {
+            return Vec::new();
+        };
+        lines
+            .iter()
+            .skip(lines.len().saturating_sub(count))
+            .cloned()
+            .collect()
+    }
+
+    /// 모든 줄 삭제
+    pub fn clear(&self) {
+        if let Ok(mut lines) = self.0.lock() {
+            lines.clear();
+        }
+    }
+}
+
+// ==================== 외부 엔진 ====================
+/// 실행 중인 외부 엔진 프로세스
+///
+/// 값이 사라지면 엔진에 END를 보내고, 끝나지 않으면 프로세스를 강제로 종료합니다.
+#[derive(Debug)]
+pub struct ExternalEngine {
+    child: Child,                   // 엔진 프로세스
+    stdin: ChildStdin,              // 엔진의 표준 입력
+    lines: Mutex<Receiver<String>>, // 엔진의 표준 출력 줄 (프로세스가 끝나면 채널이 닫힘)
+    timeout_turn: Duration,         // 한 수의 시간 제한
+    log: EngineLog,                 // 통신 기록
+}
+
+impl ExternalEngine {
+    /// 엔진을 실행하고 `width`x`height` 판과 규칙, 한 수의 시간 제한을 알림
+    pub fn spawn(
+        program: &Path,
+        args: &[String],
+        width: usize,
+        height: usize,
+        rule_set: RuleSet,
+        timeout_turn: Duration,
+        log: EngineLog,
+    ) -> Result<Self, EngineError> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|err| EngineError::Spawn(program.to_path_buf(), err.to_string()))?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+            return Err(EngineError::Spawn(
+                program.to_path_buf(),
+                "no stdio pipes".to_string(),
+            ));
+        };
+
+        // 엔진 출력을 한 줄씩 채널로 넘기는 스레드 (출력이 끝나면 채널이 닫힘)
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        log.push(format!("! started {}", program.display()));
+        let mut engine = Self {
+            child,
+            stdin,
+            lines: Mutex::new(receiver),
+            timeout_turn,
+            log,
+        };
+
+        let start = if width == height {
+            format!("START {width}")
+        } else {
+            format!("RECTSTART {width},{height}")
+        };
+        engine.send(&start)?;
+        engine.expect_ok(START_TIMEOUT)?;
+        engine.send(&format!("INFO timeout_turn {}", timeout_turn.as_millis()))?;
+        engine.send(&format!(
+            "INFO rule {}",
+            protocol::rule_set_to_info(rule_set)
+        ))?;
+        Ok(engine)
+    }
+
+    /// 통신 기록
+    pub fn log(&self) -> &EngineLog {
+        &self.log
+    }
+
+    /// 빈 판에서 먼저 두게 하고 엔진의 수를 받음
+    pub fn begin(&mut self) -> Result<(usize, usize), EngineError> {
+        self.send("BEGIN")?;
+        self.expect_move()
+    }
+
+    /// 상대의 수를 알리고 엔진의 수를 받음
+    pub fn turn(&mut self, x: usize, y: usize) -> Result<(usize, usize), EngineError> {
+        self.send(&format!("TURN {}", format_point(x, y)))?;
+        self.expect_move()
+    }
+
+    /// 국면 전체를 알리고 `own` 색을 맡은 엔진의 수를 받음
+    ///
+    /// 돌 목록은 기록에 남기지 않습니다.
+    pub fn board(&mut self, board: &Board, own: StoneColor) -> Result<(usize, usize), EngineError> {
+        let mut command = String::from("BOARD\n");
+        for (x, y, color) in board.stones() {
+            let field = if color == own { 1 } else { 2 };
+            command += &format!("{},{field}\n", format_point(x, y));
+        }
+        command += "DONE";
+        self.write(&command)?;
+        self.log
+            .push(format!("> BOARD ({} stones) DONE", board.stones().count()));
+        self.expect_move()
+    }
+
+    /// 엔진에 한 줄을 보내고 기록에 남김
+    fn send(&mut self, line: &str) -> Result<(), EngineError> {
+        self.write(line)?;
+        self.log.push(format!("> {line}"));
+        Ok(())
+    }
+
+    /// 엔진의 표준 입력에 씀 (실패하면 엔진이 종료된 것으로 봄)
+    fn write(&mut self, text: &str) -> Result<(), EngineError> {
+        let written = writeln!(self.stdin, "{text}").and_then(|()| self.stdin.flush());
+        written.map_err(|_| self.crashed())
+    }
+
+    /// OK 응답을 기다림
+    fn expect_ok(&mut self, timeout: Duration) -> Result<(), EngineError> {
+        self.expect(timeout, |line| (line == "OK").then_some(()))
+    }
+
+    /// 한 수의 시간 제한 안에 `x,y` 응답을 기다림
+    fn expect_move(&mut self) -> Result<(usize, usize), EngineError> {
+        self.expect(self.timeout_turn + TIMEOUT_GRACE, protocol::parse_point)
+    }
+
+    /// `accept`가 받아들이는 줄이 올 때까지 기다림
+    ///
+    /// MESSAGE, DEBUG 등 다른 줄은 기록에 남기고 계속 기다리며, ERROR나 UNKNOWN은 거절로 처리합니다.
+    fn expect<T>(
+        &mut self,
+        timeout: Duration,
+        accept: impl Fn(&str) -> Option<T>,
+    ) -> Result<T, EngineError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let received = match self.lines.lock() {
+                Ok(lines) => lines.recv_timeout(remaining),
+                Err(_) => Err(RecvTimeoutError::Disconnected),
+            };
+            let line = match received {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => {
+                    self.log
+                        .push(format!("! no answer within {} ms", timeout.as_millis()));
+                    return Err(EngineError::TimedOut(timeout));
+                }
+                Err(RecvTimeoutError::Disconnected) => return Err(self.crashed()),
+            };
+
+            let line = line.trim();
+            self.log.push(format!("< {line}"));
+            if let Some(value) = accept(line) {
+                return Ok(value);
+            }
+            if line.starts_with("ERROR") || line.starts_with("UNKNOWN") {
+                return Err(EngineError::Rejected(line.to_string()));
+            }
+        }
+    }
+
+    /// 종료된 엔진의 상태로 오류를 만들고 기록에 남김
+    fn crashed(&mut self) -> EngineError {
+        // 출력만 닫고 살아 있는 엔진은 더 쓸 수 없으므로 종료
+        let status = match self.child.try_wait() {
+            Ok(Some(status)) => status.to_string(),
+            Ok(None) => {
+                let _ = self.child.kill();
+                "closed its output".to_string()
+            }
+            Err(err) => err.to_string(),
+        };
+        self.log.push(format!("! engine exited ({status})"));
+        EngineError::Crashed(status)
+    }
+}
+
+impl Drop for ExternalEngine {
+    fn drop(&mut self) {
+        // 정상 종료를 요청하고 잠시 기다린 뒤에도 살아 있으면 강제 종료
+        let _ = writeln!(self.stdin, "END").and_then(|()| self.stdin.flush());
+        let deadline = Instant::now() + EXIT_TIMEOUT;
+        while Instant::now() < deadline {
+            if !matches!(self.child.try_wait(), Ok(None)) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
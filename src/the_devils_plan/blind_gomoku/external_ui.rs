@@ -0,0 +1,230 @@
+//! 외부 엔진 대국 시스템
+//!
+//! 엔진 프로세스와의 Gomocup 프로토콜 통신은 Bevy에 의존하지 않는 `external` 모듈이 맡고,
+//! 이 모듈은 AI 차례에 엔진에 판을 보내고 받은 수를 두는 시스템과 엔진 기록 창을 담습니다.
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::engine::{Board, StoneColor};
+use super::external::{EngineError, EngineLog, ExternalEngine};
+use super::rules::RuleSet;
+use super::{AiPlayer, AiStrategy, GameState, MovePlayer, OpeningState, PendingMoveFilter};
+
+// ==================== 엔진 상수 정의 ====================
+const ENGINE_TIMEOUT_TURN: Duration = Duration::from_secs(5); // 외부 엔진의 한 수 시간 제한
+const ENGINE_LOG_LINES: usize = 12; // 외부 엔진 기록 창에 보여 줄 줄 수
+const STAND_IN_ENGINE: &str = "stand_in_engine"; // 기본으로 실행할 시험용 엔진 이름 (실행 파일과 같은 폴더)
+
+// ==================== 엔진 설정 리소스 ====================
+/// 외부 엔진의 실행 설정과 실행 중인 프로세스를 관리하는 리소스
+///
+/// 엔진은 작업 스레드에서 응답을 기다리므로 프로세스는 `Mutex`로 감싸 작업과 함께 사용합니다.
+/// `--engine <경로>`로 실행할 파일을, `--engine-arg <인자>`(여러 번 가능)로 인자를 정하며
+/// 지정하지 않으면 함께 빌드되는 시험용 엔진(`stand_in_engine`)을 실행합니다.
+#[derive(Resource)]
+pub(super) struct EngineManager {
+    program: PathBuf,                           // 엔진 실행 파일
+    args: Vec<String>,                          // 엔진 실행 인자
+    timeout_turn: Duration,                     // 한 수의 시간 제한
+    engine: Arc<Mutex<Option<ExternalEngine>>>, // 실행 중인 엔진 (대국마다 새로 실행)
+    log: EngineLog,                             // 엔진과 주고받은 줄의 기록
+}
+
+impl EngineManager {
+    /// 명령행 인자로 설정 생성 (모르는 인자는 무시)
+    pub(super) fn from_args(args: impl IntoIterator<Item = String>) -> Self {
+        let mut manager = Self {
+            program: default_engine_path(),
+            args: Vec::new(),
+            timeout_turn: ENGINE_TIMEOUT_TURN,
+            engine: Arc::default(),
+            log: EngineLog::default(),
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--engine" => manager.program = args.next().map(PathBuf::from).unwrap_or_default(),
+                "--engine-arg" => manager.args.extend(args.next()),
+                _ => {}
+            }
+        }
+        manager
+    }
+}
+
+/// 실행 파일과 같은 폴더에 있는 시험용 엔진의 경로
+fn default_engine_path() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .unwrap_or_default()
+        .join(format!("{STAND_IN_ENGINE}{}", std::env::consts::EXE_SUFFIX))
+}
+
+// ==================== 컴포넌트 정의 ====================
+/// 외부 엔진 기록 창을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct EngineLogDisplay;
+
+/// 외부 엔진의 응답을 기다리는 작업을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct EngineTask {
+    task: Task<Result<(usize, usize), EngineError>>, // 작업 스레드에서 엔진 응답을 기다리는 작업
+    view: Board,                                     // 요청할 때 AI에게 공개되어 있던 판
+    color: StoneColor,                               // 엔진이 둘 색
+}
+
+// ==================== 외부 엔진 시스템 ====================
+/// 작업 스레드에서 외부 엔진에 `board`를 보내고 `color`로 둘 수를 받는 작업
+///
+/// 엔진이 이번 대국에서 처음 두는 수이거나 실행 중인 엔진이 없으면 엔진을 새로 실행합니다.
+/// 통신에 실패한 엔진은 버리고 다음 요청 때 다시 실행합니다.
+fn request_engine_move(
+    manager: &EngineManager,
+    board: Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+) -> impl std::future::Future<Output = Result<(usize, usize), EngineError>> {
+    let (engine, log) = (manager.engine.clone(), manager.log.clone());
+    let (program, args, timeout_turn) = (
+        manager.program.clone(),
+        manager.args.clone(),
+        manager.timeout_turn,
+    );
+    let first_move = !board.stones().any(|(_, _, stone)| stone == color);
+
+    async move {
+        let Ok(mut slot) = engine.lock() else {
+            return Err(EngineError::Crashed("engine lock poisoned".to_string()));
+        };
+        if first_move || slot.is_none() {
+            *slot = None; // 이전 엔진을 먼저 종료
+            *slot = Some(ExternalEngine::spawn(
+                &program,
+                &args,
+                board.width(),
+                board.height(),
+                rule_set,
+                timeout_turn,
+                log,
+            )?);
+        }
+        let Some(engine) = slot.as_mut() else {
+            return Err(EngineError::Crashed("engine not running".to_string()));
+        };
+
+        let result = if board.is_empty() {
+            engine.begin()
+        } else {
+            engine.board(&board, color)
+        };
+        if result.is_err() {
+            *slot = None;
+        }
+        result
+    }
+}
+
+/// 외부 엔진을 쓸 때 AI 차례가 되면 작업 스레드에서 엔진에 판을 보내는 시스템
+///
+/// 엔진에도 AI에게 공개된 돌과 피드백으로 알게 된 돌만 보내며, 응답은 `EngineTask`로 기다립니다.
+pub(super) fn start_engine_move(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    ai: Res<AiPlayer>,
+    manager: Res<EngineManager>,
+    tasks: Query<(), PendingMoveFilter>,
+) {
+    let game = game_state.game();
+    let Some(color) = ai.color else {
+        return;
+    };
+    if ai.strategy != AiStrategy::External
+        || game.is_over()
+        || !opening.is_done()
+        || game.current_player() != color
+        || !tasks.is_empty()
+    {
+        return;
+    }
+
+    let view = game_state.view(color).board().clone();
+    let task = AsyncComputeTaskPool::get().spawn(request_engine_move(
+        &manager,
+        ai.belief.assumed_board(&view, color),
+        game.rule_set(),
+        color,
+    ));
+    commands.spawn(EngineTask { task, view, color });
+}
+
+/// 외부 엔진이 보낸 수를 판에 두는 시스템
+///
+/// 엔진이 시간 안에 응답하지 않거나, 비정상 종료하거나, 둘 수 없는 자리를 보내면 기권패로 처리합니다.
+pub(super) fn finish_engine_move(
+    mut tasks: Query<(Entity, &mut EngineTask)>,
+    mut player: MovePlayer,
+    mut ai: ResMut<AiPlayer>,
+    manager: Res<EngineManager>,
+) {
+    for (entity, mut engine_task) in &mut tasks {
+        let Some(result) = block_on(future::poll_once(&mut engine_task.task)) else {
+            continue; // 아직 응답을 기다리는 중
+        };
+        player.painter.commands.entity(entity).despawn();
+
+        // 기다리는 동안 게임이 바뀌었으면 결과를 버림
+        let game = player.game_state.game();
+        let color = engine_task.color;
+        if game.is_over()
+            || game.current_player() != color
+            || player.game_state.view(color).board() != &engine_task.view
+        {
+            continue;
+        }
+
+        let failure = match result {
+            Ok((x, y)) => match player.play((x, y)) {
+                Some(event) => {
+                    ai.belief.observe(&event);
+                    continue;
+                }
+                None => format!("illegal move {x},{y}"),
+            },
+            Err(err) => err.to_string(),
+        };
+
+        manager.log.push(format!("! {color} forfeits: {failure}"));
+        player.resign(
+            color,
+            format!("{}이 기권패했습니다! ({failure})", color.to_korean()),
+        );
+    }
+}
+
+/// 외부 엔진을 쓰는 동안 최근 통신 기록을 화면에 표시하는 시스템
+pub(super) fn update_engine_log(
+    ai: Res<AiPlayer>,
+    manager: Res<EngineManager>,
+    mut log_display: Query<&mut Text, With<EngineLogDisplay>>,
+) {
+    let Ok(mut text) = log_display.single_mut() else {
+        return;
+    };
+    let content = if ai.strategy == AiStrategy::External {
+        let mut lines = vec![format!("Engine: {}", manager.program.display())];
+        lines.extend(manager.log.recent(ENGINE_LOG_LINES));
+        lines.join("\n")
+    } else {
+        String::new()
+    };
+    // 내용이 같으면 변경 감지가 일어나지 않도록 그대로 둠
+    if **text != content {
+        **text = content;
+    }
+}
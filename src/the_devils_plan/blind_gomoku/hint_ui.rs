@@ -0,0 +1,176 @@
+//! 수 추천과 국면 평가 표시 시스템
+//!
+//! 후보 수와 국면 평가는 Bevy에 의존하지 않는 `ai` 모듈의 `analyze`가 구하고,
+//! 이 모듈은 분석을 작업 스레드에서 돌려 추천 수와 평가 막대를 화면에 보여 주는 시스템을 담습니다.
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+
+use super::ai::{self, Analysis, Difficulty, SearchResult};
+use super::engine::{Board, StoneColor};
+use super::{
+    BoardPainter, FeedbackDisplay, GameState, OpeningState, Viewer, BLACK_STONE_COLOR,
+    STONE_RADIUS, WHITE_STONE_COLOR,
+};
+
+// ==================== 추천 상수 정의 ====================
+const HINT_COUNT: usize = 3; // 추천할 후보 수의 개수
+const HINT_ALPHA: f32 = 0.35; // 추천 수 돌의 투명도
+const HINT_DIFFICULTY: Difficulty = Difficulty::Hard; // 추천 수를 찾을 때 쓰는 탐색 한도의 난이도
+
+// ==================== 컴포넌트 정의 ====================
+/// 진행 중인 수 추천 작업을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct HintTask {
+    task: Task<Analysis>, // 작업 스레드에서 실행 중인 분석
+    view: Board,          // 분석을 시작할 때 현재 플레이어에게 공개되어 있던 판
+    color: StoneColor,    // 추천을 받는 플레이어
+}
+
+/// 추천 수 표시(반투명 돌과 순위)를 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct HintMark;
+
+/// 평가 막대에서 흑이 유리한 정도를 나타내는 검은 부분의 컴포넌트
+#[derive(Component)]
+pub(super) struct EvalBarFill;
+
+// ==================== 수 추천 시스템 ====================
+/// H 키로 현재 플레이어에게 추천할 후보 수와 국면 평가를 작업 스레드에서 구하기 시작하는 시스템
+///
+/// 블라인드 규칙을 지켜 현재 플레이어에게 공개된 돌만 놓인 판에서 분석하며,
+/// 화면에 그 플레이어의 시야를 보여 주고 있을 때만 시작합니다.
+pub(super) fn start_hint(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    viewer: Res<Viewer>,
+    tasks: Query<(), With<HintTask>>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    let game = game_state.game();
+    if !keyboard.just_pressed(KeyCode::KeyH)
+        || game.is_over()
+        || !opening.is_done()
+        || viewer.color != Some(game.current_player())
+        || !tasks.is_empty()
+    {
+        return;
+    }
+
+    let color = game.current_player();
+    let view = game_state.view(color).board().clone();
+    let (board, rule_set) = (view.clone(), game.rule_set());
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let limits = HINT_DIFFICULTY.profile().limits;
+        ai::analyze(&board, rule_set, color, &limits, HINT_COUNT)
+    });
+    commands.spawn(HintTask { task, view, color });
+
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = format!("{color}: looking for hints...");
+    }
+}
+
+/// 분석이 끝나면 추천 수를 판 위에 순위와 함께 표시하고 평가 막대를 채우는 시스템
+pub(super) fn finish_hint(
+    mut painter: BoardPainter,
+    mut tasks: Query<(Entity, &mut HintTask)>,
+    game_state: Res<GameState>,
+    marks: Query<Entity, With<HintMark>>,
+    mut eval_bar: Query<&mut Node, With<EvalBarFill>>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    for (entity, mut hint_task) in &mut tasks {
+        let Some(analysis) = block_on(future::poll_once(&mut hint_task.task)) else {
+            continue; // 아직 분석 중
+        };
+        painter.commands.entity(entity).despawn();
+
+        // 분석하는 동안 판이 바뀌었으면 결과를 버림
+        let game = game_state.game();
+        let color = hint_task.color;
+        if game.current_player() != color || game_state.view(color).board() != &hint_task.view {
+            continue;
+        }
+
+        // 이전 추천 제거
+        for mark in &marks {
+            painter.commands.entity(mark).despawn();
+        }
+        for (rank, result) in analysis.moves.iter().enumerate() {
+            spawn_hint_mark(&mut painter, rank, result, color);
+        }
+
+        // 평가 막대 채우기 (흑이 유리한 만큼 검은 부분이 늘어남)
+        let advantage = ai::advantage(analysis.score);
+        if let Ok(mut node) = eval_bar.single_mut() {
+            node.width = Val::Percent(advantage * 100.0);
+        }
+        let leader = if analysis.score >= 0 {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        };
+        if let Ok(mut text) = feedback_display.single_mut() {
+            **text = format!(
+                "{color}: {} hints, {leader} {:.0}%",
+                analysis.moves.len(),
+                advantage.max(1.0 - advantage) * 100.0
+            );
+        }
+    }
+}
+
+/// 추천 수 하나를 반투명 돌과 순위 번호로 생성
+fn spawn_hint_mark(
+    painter: &mut BoardPainter,
+    rank: usize,
+    result: &SearchResult,
+    color: StoneColor,
+) {
+    let (stone_color, text_color) = match color {
+        StoneColor::Black => (BLACK_STONE_COLOR, WHITE_STONE_COLOR),
+        StoneColor::White => (WHITE_STONE_COLOR, BLACK_STONE_COLOR),
+    };
+    let position = painter.config.grid_to_world(result.x, result.y);
+    let mesh = painter.meshes.add(Circle::default());
+    let material = painter.materials.add(stone_color.with_alpha(HINT_ALPHA));
+
+    let commands = &mut painter.commands;
+    commands.spawn((
+        Mesh2d(mesh),
+        MeshMaterial2d(material),
+        Transform {
+            translation: position.extend(2.5), // 돌과 수 표시보다 위, 강제승 수순보다 아래
+            scale: Vec3::splat(STONE_RADIUS * 2.0),
+            ..default()
+        },
+        HintMark,
+    ));
+    commands.spawn((
+        Text2d::new((rank + 1).to_string()),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(text_color),
+        Transform::from_translation(position.extend(2.75)),
+        HintMark,
+    ));
+}
+
+/// 돌이 놓이면 (무르기나 새 게임으로 판이 바뀔 때도) 추천 수와 평가 막대를 지우는 시스템
+pub(super) fn clear_hints(
+    mut commands: Commands,
+    marks: Query<Entity, With<HintMark>>,
+    mut eval_bar: Query<&mut Node, With<EvalBarFill>>,
+) {
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+    if let Ok(mut node) = eval_bar.single_mut() {
+        node.width = Val::Percent(50.0);
+    }
+}
@@ -1,10 +1,66 @@
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub mod ai;
+pub mod belief;
+pub mod blind;
+pub mod book;
+pub mod engine;
+pub mod external;
+pub mod opening;
+pub mod position;
+pub mod protocol;
+pub mod record;
+pub mod renlib;
+pub mod rng;
+pub mod rules;
+pub mod save;
+pub mod solver;
+pub mod tournament;
+pub mod zobrist;
+
+mod external_ui;
+mod hint_ui;
+mod replay;
+mod save_ui;
+
+use ai::{Difficulty, SearchResult};
+use belief::Belief;
+use blind::{BlindGame, CollisionRule, Feedback};
+use book::OpeningBook;
+use engine::{Board, Game, GameResult, Move, StoneColor, DEFAULT_BOARD_SIZE};
+use external_ui::{
+    finish_engine_move, start_engine_move, update_engine_log, EngineLogDisplay, EngineManager,
+    EngineTask,
+};
+use hint_ui::{clear_hints, finish_hint, start_hint, EvalBarFill, HintTask};
+use opening::{Opening, OpeningError, OpeningRule, Phase, Player};
+use position::{Position, PositionError};
+use record::Record;
+use renlib::{Library, LIBRARY_SIZE};
+use replay::{
+    control_replay, not_replaying, start_replay, stop_replay, sync_replay_stones, ReplayState,
+};
+use rng::Rng;
+use rules::RuleSet;
+use save_ui::{
+    autosave, continue_game, dismiss_continue, format_clock, offer_continue, tick_clock,
+    ContinueDisplay,
+};
+use solver::{SolveMode, SolverLimits, Step};
 
 // ==================== 게임 상수 정의 ====================
-const BOARD_SIZE: usize = 15; // 오목판 크기 (15x15)
 const GRID_SIZE: f32 = 40.0; // 각 격자칸의 픽셀 크기
 const LINE_WIDTH: f32 = 2.0; // 격자선 두께
+const WIN_LINE_WIDTH: f32 = 8.0; // 승리한 줄 표시 두께
 const STONE_RADIUS: f32 = 16.0; // 돌의 반지름
+const BOARD_PADDING: f32 = 60.0; // 격자 바깥쪽 오목판 여백 (양쪽 합)
+const UI_MARGIN: Vec2 = Vec2::new(280.0, 80.0); // 오목판 바깥 UI 공간 (가로, 세로)
 
 // ==================== 색상 상수 정의 ====================
 const BACKGROUND_COLOR: Color = Color::srgb(0.9, 0.85, 0.7); // 전체 배경색 (연한 베이지)
@@ -14,61 +70,587 @@ const STAR_COLOR: Color = Color::BLACK; // 화점(별) 색상
 const BLACK_STONE_COLOR: Color = Color::srgb(0.1, 0.1, 0.1); // 흑돌 색상
 const WHITE_STONE_COLOR: Color = Color::srgb(0.95, 0.95, 0.95); // 백돌 색상
 const TEXT_COLOR: Color = Color::srgb(0.2, 0.2, 0.2); // 텍스트 색상
+const FEEDBACK_COLOR: Color = Color::srgb(0.2, 0.3, 0.6); // 피드백 메시지 색상
+const WIN_MESSAGE_COLOR: Color = Color::srgb(1.0, 0.2, 0.2); // 승리 메시지 색상
+const DRAW_MESSAGE_COLOR: Color = Color::srgb(0.3, 0.3, 0.8); // 무승부 메시지 색상
+const BUTTON_COLOR: Color = Color::srgb(0.35, 0.3, 0.25); // 버튼 배경색
+const SELECTED_BUTTON_COLOR: Color = Color::srgb(0.2, 0.45, 0.3); // 선택된 버튼 배경색
+const BUTTON_TEXT_COLOR: Color = Color::srgb(0.95, 0.95, 0.95); // 버튼 글자색
+const LAST_MOVE_COLOR: Color = Color::srgb(0.9, 0.15, 0.15); // 마지막 수 표시 색상
+const WIN_LINE_COLOR: Color = Color::srgba(0.95, 0.2, 0.2, 0.6); // 승리한 줄 표시 색상
+const DIALOG_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.85); // 게임 종료 대화상자 배경색
+const FORBIDDEN_COLOR: Color = Color::srgba(0.9, 0.1, 0.1, 0.8); // 금수 표시 색상
+const OPENING_AREA_COLOR: Color = Color::srgba(0.2, 0.5, 0.9, 0.15); // 오프닝 제한 구역 색상
+const CANDIDATE_ALPHA: f32 = 0.45; // 5수 후보 돌의 투명도
+const SOLUTION_ALPHA: f32 = 0.6; // 강제승 수순 돌의 투명도
+const SOLUTION_STEP_SECONDS: f32 = 0.6; // 강제승 수순을 한 수씩 보여 주는 간격
+const EVAL_BAR_SIZE: Vec2 = Vec2::new(110.0, 12.0); // 평가 막대 크기 (버튼과 같은 폭)
+const BOOK_MARK_COLOR: Color = Color::srgb(0.1, 0.45, 0.2); // 정석 후보 표시 색상
+const LIBRARY_MARK_COLOR: Color = Color::srgb(0.45, 0.15, 0.55); // 라이브러리 변화 표시 색상
+const LIBRARY_MARKED_COLOR: Color = Color::srgb(0.85, 0.1, 0.3); // 라이브러리에서 표시한 수의 색상
+const BOOK_PATH: &str = "books/opening.book"; // assets 폴더 안의 오프닝 정석 파일 경로
+const RECORD_DIR: &str = "games"; // 기보를 저장하고 불러오는 폴더 (실행한 위치 기준)
 
 /// 메인 함수 - 오목 게임 실행
+///
+/// `--position <국면 문자열>`을 주면 오프닝 없이 그 국면에서 시작합니다 (디버깅용, `position` 모듈 참고).
+/// `--seed <숫자>`를 주면 AI가 그 난수 시드로 두므로 같은 대국을 다시 재현할 수 있습니다.
 pub fn example() {
+    let mut board_config = BoardConfig::default();
+    let mut game_config = GameConfig::default();
+    match ai_seed(std::env::args().skip(1)) {
+        Ok(Some(seed)) => game_config.ai_seed = seed,
+        Ok(None) => {}
+        Err(err) => {
+            eprintln!("--seed: {err}");
+            return;
+        }
+    }
+    let start = match start_position(std::env::args().skip(1)) {
+        Ok(start) => start,
+        Err(err) => {
+            eprintln!("--position: {err}");
+            return;
+        }
+    };
+    let (game, opening) = match &start {
+        Some(position) => {
+            board_config = BoardConfig::new(position.board.width(), position.board.height());
+            game_config.rule_set = position.rule_set;
+            (
+                position.to_game(game_config.early_draw),
+                Opening::with_first_player(OpeningRule::None, Player::First),
+            )
+        }
+        None => (
+            game_config.new_game(&board_config),
+            game_config.new_opening(Player::First),
+        ),
+    };
+
     App::new()
         // 기본 플러그인 설정 (렌더링, 윈도우, 입력 등)
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "오목 (Omok)".to_string(),
-                resolution: (900.0, 700.0).into(),
+                resolution: board_config.window_resolution().into(), // 판 크기에 맞춘 창 크기
                 ..default()
             }),
             ..default()
         }))
         // 리소스 초기화
         .insert_resource(ClearColor(BACKGROUND_COLOR)) // 배경색 설정
-        .insert_resource(GameState::default()) // 게임 상태 초기화
+        .insert_resource(GameState(game)) // 게임 상태 초기화
+        .insert_resource(OpeningState(opening)) // 오프닝 상태 초기화
+        .init_resource::<MatchState>() // 연속 대국 상태 초기화
+        .init_resource::<SolutionPlayback>() // 강제승 수순 재생 상태 초기화
+        .init_resource::<LibraryState>() // RenLib 라이브러리 초기화
+        .init_resource::<ReplayState>() // 기보 재생 상태 초기화
+        .init_resource::<Viewer>() // 화면의 시야 초기화
+        .insert_resource(AiPlayer {
+            rng: Rng::new(game_config.ai_seed),
+            ..default()
+        }) // AI 설정 초기화
+        .insert_resource(board_config) // 오목판 크기 설정
+        .insert_resource(game_config) // 게임 규칙 설정
+        .insert_resource(BlindRules::default()) // 블라인드 규칙 설정
+        .insert_resource(EngineManager::from_args(std::env::args().skip(1))) // 외부 엔진 설정
+        // 이벤트 등록
+        .add_event::<BlindFeedback>() // 블라인드 피드백 이벤트
+        .add_event::<OpeningFeedback>() // 오프닝 피드백 이벤트
+        // 오프닝 정석 에셋 등록
+        .init_asset::<BookAsset>()
+        .init_asset_loader::<BookLoader>()
         // 시스템 등록
-        .add_systems(Startup, setup) // 게임 시작 시 실행될 setup 함수
-        .add_systems(Update, (handle_stone_placement, update_turn_display)) // 매 프레임 실행될 함수들
+        .add_systems(Startup, (setup, load_opening_book, offer_continue).chain()) // 게임 시작 시 실행될 함수들
+        .add_systems(
+            Update,
+            (
+                change_board_size.run_if(not_replaying),
+                rebuild_board.run_if(resource_changed::<BoardConfig>),
+                fit_camera_to_board,
+                handle_opening_keys.run_if(not_replaying),
+                handle_stone_placement.run_if(not_replaying),
+                finish_ai_move,
+                finish_engine_move,
+                start_ai_move.run_if(not_replaying),
+                start_engine_move.run_if(not_replaying),
+                (start_solve, finish_solve, start_hint, finish_hint).run_if(not_replaying),
+                handle_undo_redo.run_if(not_replaying),
+                restart_game.run_if(not_replaying),
+                // 기보와 국면
+                (
+                    export_record,
+                    import_record.run_if(not_replaying),
+                    load_library,
+                    copy_position,
+                ),
+                // 기보 재생
+                (
+                    start_replay.run_if(not_replaying),
+                    control_replay,
+                    stop_replay,
+                    sync_replay_stones.run_if(resource_changed::<ReplayState>),
+                ),
+                // 자동 저장과 이어하기
+                (
+                    tick_clock.run_if(not_replaying),
+                    continue_game.run_if(not_replaying),
+                    autosave.run_if(resource_changed::<GameState>.and(not_replaying)),
+                    dismiss_continue.run_if(any_match_filter::<Added<Stone>>),
+                ),
+                // 설정 변경
+                (
+                    cycle_collision_rule,
+                    cycle_rule_set,
+                    cycle_opening_rule,
+                    toggle_early_draw,
+                    toggle_undo,
+                    toggle_swap_colors,
+                    toggle_forbidden_marks,
+                    toggle_book_marks,
+                    cycle_ai_player,
+                    cycle_ai_strategy,
+                    select_difficulty,
+                )
+                    .run_if(not_replaying),
+                // 판 위 표시
+                (
+                    update_viewer,
+                    update_forbidden_marks.run_if(
+                        resource_changed::<GameState>
+                            .or(resource_changed::<BoardConfig>)
+                            .or(resource_changed::<GameConfig>)
+                            .or(resource_changed::<Viewer>),
+                    ),
+                    update_opening_marks.run_if(
+                        resource_changed::<OpeningState>.or(resource_changed::<BoardConfig>),
+                    ),
+                    update_move_marks.run_if(
+                        resource_changed::<GameState>
+                            .or(resource_changed::<BoardConfig>)
+                            .or(resource_changed::<Viewer>),
+                    ),
+                    update_book_marks.run_if(
+                        resource_changed::<GameState>
+                            .or(resource_changed::<BoardConfig>)
+                            .or(resource_changed::<GameConfig>)
+                            .or(resource_changed::<Assets<BookAsset>>)
+                            .or(resource_changed::<Viewer>),
+                    ),
+                    update_library_marks.run_if(
+                        resource_changed::<GameState>
+                            .or(resource_changed::<BoardConfig>)
+                            .or(resource_changed::<LibraryState>)
+                            .or(resource_changed::<Viewer>),
+                    ),
+                    update_stone_visibility,
+                    clear_solution_marks.run_if(resource_changed::<GameState>),
+                    clear_hints
+                        .run_if(any_match_filter::<Added<Stone>>.or(resource_changed::<GameState>)),
+                    animate_solution,
+                ),
+                // UI 표시
+                (
+                    update_history_buttons.run_if(resource_changed::<GameConfig>),
+                    update_difficulty_buttons.run_if(resource_changed::<GameConfig>),
+                    update_rules_display.run_if(
+                        resource_changed::<BlindRules>
+                            .or(resource_changed::<BoardConfig>)
+                            .or(resource_changed::<GameConfig>)
+                            .or(resource_changed::<MatchState>)
+                            .or(resource_changed::<AiPlayer>),
+                    ),
+                    update_turn_display,
+                    update_feedback_display,
+                    update_engine_log,
+                ),
+            )
+                .chain(),
+        ) // 매 프레임 실행될 함수들
         .run();
 }
 
 // ==================== 게임 상태 리소스 ====================
 /// 전체 게임의 상태를 관리하는 리소스
+///
+/// 블라인드 오목에서는 실제 판은 심판만 알고 있으며,
+/// 각 플레이어는 자신에게 공개된 정보만 볼 수 있습니다.
+/// 규칙은 모두 `BlindGame`이 처리하고 이 리소스는 Bevy에 노출하는 역할만 합니다.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct GameState(BlindGame);
+
+/// 진행 중인 오프닝의 상태를 관리하는 리소스
+///
+/// 오프닝이 끝나기 전까지는 클릭과 키 입력이 오프닝 단계로 전달됩니다.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct OpeningState(Opening);
+
+/// 컴퓨터 상대의 설정을 관리하는 리소스
+#[derive(Resource, Default)]
+struct AiPlayer {
+    color: Option<StoneColor>, // AI가 맡은 색 (없으면 사람끼리 대국)
+    rng: Rng,                  // 수마다 탐색 시드를 뽑는 난수 생성기 (게임마다 다시 시작)
+    strategy: AiStrategy,      // 수를 고르는 방식
+    belief: Belief,            // 피드백으로 알게 된 보이지 않는 상대 돌 정보
+}
+
+/// AI가 수를 고르는 방식
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum AiStrategy {
+    /// 보이지 않는 상대 돌의 위치를 추정한 판 여러 개에서 모의 대국으로 고름 (블라인드 규칙용)
+    #[default]
+    Belief,
+    /// 알고 있는 돌만 놓인 판에서 알파-베타 탐색으로 고름
+    Search,
+    /// Gomocup 프로토콜을 쓰는 외부 엔진 프로세스에 알고 있는 돌만 놓인 판을 보내 고름
+    External,
+}
+
+impl AiStrategy {
+    /// 다음 방식 반환 (방식 선택 키에서 사용)
+    fn next(self) -> Self {
+        match self {
+            AiStrategy::Belief => AiStrategy::Search,
+            AiStrategy::Search => AiStrategy::External,
+            AiStrategy::External => AiStrategy::Belief,
+        }
+    }
+}
+
+impl fmt::Display for AiStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AiStrategy::Belief => write!(f, "belief"),
+            AiStrategy::Search => write!(f, "search"),
+            AiStrategy::External => write!(f, "engine"),
+        }
+    }
+}
+
+impl std::str::FromStr for AiStrategy {
+    type Err = String;
+
+    /// 표시 이름을 읽음 (예: `engine`)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut strategy = AiStrategy::default();
+        loop {
+            if strategy.to_string() == text {
+                return Ok(strategy);
+            }
+            strategy = strategy.next();
+            if strategy == AiStrategy::default() {
+                return Err(format!("unknown AI strategy \"{text}\""));
+            }
+        }
+    }
+}
+
+/// 판 위에 표시 중인 강제승 수순의 재생 상태를 관리하는 리소스
+#[derive(Resource)]
+struct SolutionPlayback {
+    timer: Timer, // 다음 수를 보여 줄 때까지의 시간
+    shown: usize, // 지금까지 보여 준 수의 개수
+}
+
+impl Default for SolutionPlayback {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(SOLUTION_STEP_SECONDS, TimerMode::Repeating),
+            shown: 0,
+        }
+    }
+}
+
+/// 명령행의 `--position` 값을 읽음 (없으면 `None`)
+///
+/// 국면의 판 크기는 화면에서 고를 수 있는 범위(`BoardConfig`) 안이어야 합니다.
+fn start_position(args: impl IntoIterator<Item = String>) -> Result<Option<Position>, String> {
+    let mut args = args.into_iter();
+    let Some(text) = args.find(|arg| arg == "--position").map(|_| args.next()) else {
+        return Ok(None);
+    };
+    let text = text.ok_or("missing value")?;
+    let position: Position = text.parse().map_err(|err: PositionError| err.to_string())?;
+    let (width, height) = (position.board.width(), position.board.height());
+    let config = BoardConfig::new(width, height);
+    if (config.width, config.height) != (width, height) {
+        return Err(format!("{width}x{height} boards are not supported"));
+    }
+    Ok(Some(position))
+}
+
+/// 명령행의 `--seed` 값을 읽음 (없으면 `None`)
+fn ai_seed(args: impl IntoIterator<Item = String>) -> Result<Option<u64>, String> {
+    let mut args = args.into_iter();
+    let Some(text) = args.find(|arg| arg == "--seed").map(|_| args.next()) else {
+        return Ok(None);
+    };
+    let text = text.ok_or("missing value")?;
+    let seed = text
+        .parse()
+        .map_err(|_| format!("invalid seed \"{text}\""))?;
+    Ok(Some(seed))
+}
+
+/// 불러온 RenLib 라이브러리를 관리하는 리소스 (J 키로 불러옴)
+#[derive(Resource, Default)]
+struct LibraryState(Option<Library>);
+
+/// 화면에 시야를 보여 줄 플레이어를 관리하는 리소스
+///
+/// 사람끼리 대국할 때는 차례가 넘어가도 다음 플레이어가 넘겨받기 전까지 판을 가리므로
+/// 한 플레이어의 시야가 다른 플레이어에게 보이지 않습니다.
+#[derive(Resource)]
+struct Viewer {
+    color: Option<StoneColor>, // 시야를 보여 주는 플레이어 (넘겨주기 화면이면 `None`)
+    seat: StoneColor,          // 사람끼리 대국할 때 마지막으로 판을 넘겨받은 플레이어
+}
+
+impl Default for Viewer {
+    /// 흑이 판을 받은 상태
+    fn default() -> Self {
+        Self {
+            color: Some(StoneColor::Black),
+            seat: StoneColor::Black,
+        }
+    }
+}
+
+/// 연속 대국의 설정과 점수를 관리하는 리소스
 #[derive(Resource)]
-struct GameState {
-    board: [[Option<StoneColor>; BOARD_SIZE]; BOARD_SIZE], // 15x15 오목판 상태
-    current_player: StoneColor,                            // 현재 턴의 플레이어
-    game_over: bool,                                       // 게임 종료 여부
-    winner: Option<StoneColor>,                            // 승자 (있을 경우)
+struct MatchState {
+    swap_colors: bool,     // 게임마다 먼저 두는 플레이어를 바꿀지 여부
+    wins: [u32; 2],        // 플레이어별 승리 수 (Player 1, Player 2 순서)
+    draws: u32,            // 무승부 수
+    game_started: u64,     // 현재 게임을 시작한 시각 (유닉스 시간, 초, 기보에 기록)
+    clocks: [Duration; 2], // 현재 게임에서 색별로 자기 차례에 쓴 시간 (흑, 백 순서)
+}
+
+impl Default for MatchState {
+    fn default() -> Self {
+        Self {
+            swap_colors: false,
+            wins: [0; 2],
+            draws: 0,
+            game_started: record::now(),
+            clocks: [Duration::ZERO; 2],
+        }
+    }
+}
+
+// ==================== 오프닝 정석 에셋 ====================
+/// `assets` 폴더에서 읽어 온 오프닝 정석 에셋
+#[derive(Asset, TypePath, Deref)]
+struct BookAsset(OpeningBook);
+
+/// `.book` 파일을 `BookAsset`으로 읽는 에셋 로더 (형식은 `book` 모듈 참고)
+#[derive(Default)]
+struct BookLoader;
+
+impl AssetLoader for BookLoader {
+    type Asset = BookAsset;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<BookAsset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(BookAsset(OpeningBook::parse(std::str::from_utf8(&bytes)?)?))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["book"]
+    }
+}
+
+/// 불러온 오프닝 정석 에셋의 핸들을 보관하는 리소스
+///
+/// 파일이 없거나 형식이 잘못되었으면 정석 없이 진행합니다.
+#[derive(Resource, Default)]
+struct BookHandle(Handle<BookAsset>);
+
+/// 불러온 오프닝 정석을 찾는 시스템 매개변수 묶음
+#[derive(SystemParam)]
+struct BookLookup<'w> {
+    handle: Res<'w, BookHandle>,
+    books: Res<'w, Assets<BookAsset>>,
+}
+
+impl BookLookup<'_> {
+    /// 다 불러온 정석 (아직 불러오는 중이거나 실패했으면 `None`)
+    fn get(&self) -> Option<&OpeningBook> {
+        self.books.get(&self.handle.0).map(|book| &book.0)
+    }
+}
+
+// ==================== 블라인드 규칙 설정 ====================
+/// 블라인드 오목의 규칙 설정 리소스
+#[derive(Resource, Clone, Copy, Debug, Default)]
+struct BlindRules {
+    collision: CollisionRule, // 보이지 않는 돌 위에 놓으려 할 때의 처리 방식
+}
+
+// ==================== 게임 규칙 설정 ====================
+/// 게임 규칙 설정 리소스 (새 게임을 만들 때 적용됨)
+#[derive(Resource, Clone, Copy, Debug)]
+struct GameConfig {
+    rule_set: RuleSet,      // 승리 조건과 금수 규칙
+    opening: OpeningRule,   // 본 게임 전에 진행할 오프닝 규칙
+    early_draw: bool,       // 양쪽 모두 5목이 불가능해지면 바로 무승부 처리할지 여부
+    allow_undo: bool,       // 무르기 허용 여부 (블라인드 대국에서는 기본적으로 꺼짐)
+    difficulty: Difficulty, // AI 난이도
+    ai_seed: u64,           // AI 난수 시드 (`--seed`로 지정, 같은 시드면 같은 수를 둠)
+    show_forbidden: bool,   // 흑의 금수 자리를 판에 표시할지 여부
+    show_book: bool,        // 현재 국면의 정석 후보를 판에 표시할지 여부
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            rule_set: RuleSet::default(),
+            opening: OpeningRule::default(),
+            early_draw: false,
+            allow_undo: false,
+            difficulty: Difficulty::default(),
+            ai_seed: 0,
+            show_forbidden: true,
+            show_book: false,
+        }
+    }
+}
+
+impl GameConfig {
+    /// 현재 설정과 판 크기로 새 게임 생성
+    fn new_game(&self, board: &BoardConfig) -> BlindGame {
+        BlindGame::with_game(
+            Game::with_size(board.width, board.height)
+                .with_rule_set(self.rule_set)
+                .with_early_draw(self.early_draw),
+        )
+    }
+
+    /// 현재 설정으로 `first` 플레이어가 시작하는 새 오프닝 생성
+    fn new_opening(&self, first: Player) -> Opening {
+        Opening::with_first_player(self.opening, first)
+    }
+}
+
+// ==================== 오목판 크기 설정 ====================
+/// 오목판 크기 설정 리소스
+///
+/// 화점, 격자선, 좌표 변환, 창과 카메라 배율이 모두 이 설정에서 계산됩니다.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+struct BoardConfig {
+    width: usize,  // 가로 칸 수
+    height: usize, // 세로 칸 수
 }
 
-impl Default for GameState {
-    /// GameState의 기본값 설정
+impl Default for BoardConfig {
+    /// 15x15 판
     fn default() -> Self {
         Self {
-            board: [[None; BOARD_SIZE]; BOARD_SIZE], // 모든 칸을 빈 상태로 초기화
-            current_player: StoneColor::Black,       // 흑돌이 선공
-            game_over: false,                        // 게임 진행 중
-            winner: None,                            // 아직 승자 없음
+            width: DEFAULT_BOARD_SIZE,
+            height: DEFAULT_BOARD_SIZE,
         }
     }
 }
 
-// ==================== 돌 색깔 열거형 ====================
-/// 오목돌의 색깔을 나타내는 열거형
-#[derive(Clone, Copy, PartialEq, Debug)]
-enum StoneColor {
-    Black, // 흑돌
-    White, // 백돌
+impl BoardConfig {
+    /// 선택 가능한 최소 크기
+    const MIN_SIZE: usize = 9;
+    /// 선택 가능한 최대 크기
+    const MAX_SIZE: usize = 19;
+
+    /// 선택 가능한 범위로 제한된 설정 생성
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width: width.clamp(Self::MIN_SIZE, Self::MAX_SIZE),
+            height: height.clamp(Self::MIN_SIZE, Self::MAX_SIZE),
+        }
+    }
+
+    /// 격자 전체의 픽셀 크기 (첫 줄부터 마지막 줄까지)
+    fn grid_extent(&self) -> Vec2 {
+        Vec2::new(
+            (self.width - 1) as f32 * GRID_SIZE,
+            (self.height - 1) as f32 * GRID_SIZE,
+        )
+    }
+
+    /// 여백을 포함한 오목판 배경의 픽셀 크기
+    fn board_extent(&self) -> Vec2 {
+        self.grid_extent() + Vec2::splat(BOARD_PADDING)
+    }
+
+    /// 오목판과 UI가 모두 들어가는 창 크기
+    fn window_resolution(&self) -> (f32, f32) {
+        let size = self.board_extent() + UI_MARGIN;
+        (size.x, size.y)
+    }
+
+    /// 격자 좌표를 월드 좌표로 변환 (판 중앙이 원점)
+    fn grid_to_world(&self, x: usize, y: usize) -> Vec2 {
+        Vec2::new(x as f32, y as f32) * GRID_SIZE - self.grid_extent() / 2.0
+    }
+
+    /// 월드 좌표를 가장 가까운 격자 좌표로 변환 (판 밖이면 `None`)
+    fn world_to_grid(&self, world_pos: Vec2) -> Option<(usize, usize)> {
+        let grid = ((world_pos + self.grid_extent() / 2.0) / GRID_SIZE).round();
+        let (x, y) = (grid.x as i32, grid.y as i32);
+        (x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32)
+            .then_some((x as usize, y as usize))
+    }
+
+    /// 판 크기에 맞는 화점 위치 계산
+    ///
+    /// 모서리 화점은 13줄 이상이면 가장자리에서 4번째 줄, 그보다 작으면 3번째 줄에 놓입니다.
+    /// 15줄 이상인 방향에는 변 화점을, 양쪽이 모두 홀수면 중앙 화점을 추가합니다.
+    fn star_points(&self) -> Vec<(usize, usize)> {
+        /// 한 방향의 화점 좌표 목록과 중앙 좌표
+        fn axis(size: usize) -> (Vec<usize>, Option<usize>) {
+            let edge = if size >= 13 { 3 } else { 2 };
+            let center = (size % 2 == 1).then_some(size / 2);
+            let mut lines = vec![edge, size - 1 - edge];
+            if size >= 15 {
+                lines.extend(center);
+            }
+            (lines, center)
+        }
+
+        let (xs, center_x) = axis(self.width);
+        let (ys, center_y) = axis(self.height);
+        let mut points: Vec<(usize, usize)> = xs
+            .iter()
+            .flat_map(|&x| ys.iter().map(move |&y| (x, y)))
+            .collect();
+        if let (Some(cx), Some(cy)) = (center_x, center_y) {
+            if !points.contains(&(cx, cy)) {
+                points.push((cx, cy));
+            }
+        }
+        points
+    }
 }
 
+// ==================== 블라인드 피드백 이벤트 ====================
+/// 플레이어에게 전달되는 피드백 이벤트
+#[derive(Event, Clone, Copy, Debug, Deref)]
+struct BlindFeedback(Feedback);
+
+/// 오프닝 단계에서 허용되지 않은 동작을 알리는 이벤트
+#[derive(Event, Clone, Copy, Debug, Deref)]
+struct OpeningFeedback(OpeningError);
+
+// ==================== 돌 색깔 표시 ====================
 impl StoneColor {
     /// 돌 색깔을 Bevy Color로 변환
-    fn to_color(&self) -> Color {
+    fn to_color(self) -> Color {
         match self {
             StoneColor::Black => BLACK_STONE_COLOR,
             StoneColor::White => WHITE_STONE_COLOR,
@@ -76,20 +658,12 @@ impl StoneColor {
     }
 
     /// 돌 색깔을 한국어 문자열로 변환
-    fn to_korean(&self) -> &'static str {
+    fn to_korean(self) -> &'static str {
         match self {
             StoneColor::Black => "Black",
             StoneColor::White => "White",
         }
     }
-
-    /// 상대방 돌 색깔 반환
-    fn opposite(&self) -> Self {
-        match self {
-            StoneColor::Black => StoneColor::White,
-            StoneColor::White => StoneColor::Black,
-        }
-    }
 }
 
 // ==================== 컴포넌트 정의 ====================
@@ -100,10 +674,6 @@ struct Stone {
     grid_pos: (usize, usize), // 격자상의 위치 (x, y)
 }
 
-/// 격자선을 나타내는 컴포넌트 (사용되지 않음, 하위 호환성을 위해 유지)
-#[derive(Component)]
-struct GridLine;
-
 /// 화점(별)을 나타내는 컴포넌트
 #[derive(Component)]
 struct StarPoint;
@@ -116,6 +686,79 @@ struct TurnDisplay;
 #[derive(Component)]
 struct GameOverDisplay;
 
+/// 블라인드 피드백 메시지를 나타내는 컴포넌트
+#[derive(Component)]
+struct FeedbackDisplay;
+
+/// 게임 설명 및 규칙 UI를 나타내는 컴포넌트
+#[derive(Component)]
+struct RulesDisplay;
+
+/// 금수 자리 표시를 나타내는 컴포넌트
+#[derive(Component)]
+struct ForbiddenMark;
+
+/// 오프닝 제한 구역과 5수 후보 표시를 나타내는 컴포넌트
+#[derive(Component)]
+struct OpeningMark;
+
+/// 마지막 수와 승리한 줄 표시를 나타내는 컴포넌트
+#[derive(Component)]
+struct MoveMark;
+
+/// 정석 후보와 비율 표시를 나타내는 컴포넌트
+#[derive(Component)]
+struct BookMark;
+
+/// 라이브러리 변화 표시(판 글자)를 나타내는 컴포넌트
+#[derive(Component)]
+struct LibraryMark;
+
+/// 라이브러리의 현재 국면 설명을 나타내는 컴포넌트
+#[derive(Component)]
+struct LibraryCommentDisplay;
+
+/// 진행 중인 AI 탐색 작업을 나타내는 컴포넌트
+#[derive(Component)]
+struct AiTask {
+    task: Task<Option<SearchResult>>, // 작업 스레드에서 실행 중인 탐색
+    view: Board,                      // 탐색을 시작할 때 AI에게 공개되어 있던 판
+    color: StoneColor,                // AI가 둘 색
+}
+
+/// 진행 중인 강제승 풀이 작업을 나타내는 컴포넌트
+#[derive(Component)]
+struct SolveTask {
+    task: Task<Option<(SolveMode, Vec<Step>)>>, // 작업 스레드에서 실행 중인 풀이
+    view: Board,       // 풀이를 시작할 때 현재 플레이어에게 공개되어 있던 판
+    color: StoneColor, // 강제승을 찾는 플레이어
+}
+
+/// 강제승 수순의 한 수 표시(반투명 돌과 수 번호, 또는 넘김 표시)를 나타내는 컴포넌트
+#[derive(Component)]
+struct SolutionMark {
+    step: usize, // 수순에서 몇 번째 수인지 (0부터)
+}
+
+/// AI 난이도 메뉴의 버튼을 나타내는 컴포넌트
+#[derive(Component)]
+struct DifficultyButton(Difficulty);
+
+/// 새 게임 버튼을 나타내는 컴포넌트
+#[derive(Component)]
+struct RestartButton;
+
+/// 사람끼리 대국할 때 판을 가리는 넘겨주기 화면을 나타내는 컴포넌트
+#[derive(Component)]
+struct HandOverDisplay;
+
+/// 무르기 / 다시 두기 버튼을 나타내는 컴포넌트
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+enum HistoryButton {
+    Undo, // 마지막 수 무르기
+    Redo, // 무른 수 다시 두기
+}
+
 // ==================== 컴포넌트 번들 정의 ====================
 /// 오목판 배경을 나타내는 컴포넌트 (Sprite와 Transform을 자동으로 포함)
 #[derive(Component)]
@@ -129,16 +772,16 @@ struct GridLineComponent;
 
 impl BoardBackground {
     /// 새로운 보드 배경 생성
-    fn new() -> (Self, Sprite, Transform) {
+    fn new(config: &BoardConfig) -> (Self, Sprite, Transform) {
         // 보드 크기 계산 (격자 크기 + 여백)
-        let board_size = (BOARD_SIZE - 1) as f32 * GRID_SIZE + 60.0;
+        let board_size = config.board_extent();
 
         (
             BoardBackground,
             Sprite::from_color(BOARD_COLOR, Vec2::ONE), // 나무색 배경
             Transform {
                 translation: Vec3::new(0.0, 0.0, -1.0), // z축 -1로 설정하여 배경으로
-                scale: Vec3::new(board_size, board_size, 1.0),
+                scale: board_size.extend(1.0),
                 ..default()
             },
         )
@@ -147,11 +790,11 @@ impl BoardBackground {
 
 impl GridLineComponent {
     /// 세로 격자선 생성
-    fn vertical(index: usize) -> (Self, Sprite, Transform) {
+    fn vertical(index: usize, config: &BoardConfig) -> (Self, Sprite, Transform) {
         // 격자선의 x 좌표 계산 (중앙 기준)
-        let x = (index as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
+        let x = config.grid_to_world(index, 0).x;
         // 격자선 길이 계산
-        let length = (BOARD_SIZE - 1) as f32 * GRID_SIZE;
+        let length = config.grid_extent().y;
 
         (
             GridLineComponent,
@@ -165,11 +808,11 @@ impl GridLineComponent {
     }
 
     /// 가로 격자선 생성
-    fn horizontal(index: usize) -> (Self, Sprite, Transform) {
+    fn horizontal(index: usize, config: &BoardConfig) -> (Self, Sprite, Transform) {
         // 격자선의 y 좌표 계산 (중앙 기준)
-        let y = (index as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
+        let y = config.grid_to_world(0, index).y;
         // 격자선 길이 계산
-        let length = (BOARD_SIZE - 1) as f32 * GRID_SIZE;
+        let length = config.grid_extent().x;
 
         (
             GridLineComponent,
@@ -183,55 +826,130 @@ impl GridLineComponent {
     }
 }
 
-// ==================== 초기 설정 시스템 ====================
-/// 게임 시작 시 필요한 모든 엔티티를 생성하는 함수
-fn setup(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-) {
-    // 2D 카메라 생성
-    commands.spawn(Camera2d);
+// ==================== 시스템 매개변수 묶음 ====================
+/// 판 위에 돌과 표시를 생성하는 시스템 매개변수 묶음
+#[derive(SystemParam)]
+struct BoardPainter<'w, 's> {
+    commands: Commands<'w, 's>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<ColorMaterial>>,
+    config: Res<'w, BoardConfig>,
+}
 
-    // 오목판 배경 생성
-    commands.spawn(BoardBackground::new());
+impl BoardPainter<'_, '_> {
+    /// 격자 좌표에 돌 엔티티 생성 (처음에는 숨겨진 상태)
+    fn spawn_stone(&mut self, color: StoneColor, pos: (usize, usize)) {
+        spawn_stone(
+            &mut self.commands,
+            &mut self.meshes,
+            &mut self.materials,
+            &self.config,
+            color,
+            pos,
+        );
+    }
+}
+
+/// 착수를 엔진에 요청하고 결과를 화면과 피드백 이벤트에 반영하는 시스템 매개변수 묶음
+///
+/// 사람과 AI, 외부 엔진의 착수가 모두 `play`를 거칩니다.
+#[derive(SystemParam)]
+struct MovePlayer<'w, 's> {
+    painter: BoardPainter<'w, 's>,
+    game_state: ResMut<'w, GameState>,
+    rules: Res<'w, BlindRules>,
+    feedback: EventWriter<'w, BlindFeedback>,
+}
+
+impl MovePlayer<'_, '_> {
+    /// 현재 플레이어의 착수를 엔진에 요청하고 결과를 화면에 반영
+    ///
+    /// 엔진이 거부한 수이면 `None`을 반환합니다.
+    fn play(&mut self, pos: (usize, usize)) -> Option<Feedback> {
+        // 엔진에 착수를 요청 (충돌 처리도 엔진이 담당)
+        let event = self
+            .game_state
+            .play(pos.0, pos.1, self.rules.collision)
+            .ok()?;
+
+        match event {
+            Feedback::Placed { player, pos } => {
+                // 돌 생성 (표시 여부는 update_stone_visibility에서 결정)
+                self.painter.spawn_stone(player, pos);
+
+                // 승부 판정 결과 표시 (종료 후에는 모든 돌이 공개됨)
+                if let Some(result) = self.game_state.game().result() {
+                    spawn_result_message(&mut self.painter.commands, result);
+                }
+            }
+            Feedback::Forfeit { player, .. } => {
+                spawn_game_over_message(
+                    &mut self.painter.commands,
+                    format!("{}이 기권패했습니다!", player.to_korean()),
+                    WIN_MESSAGE_COLOR,
+                );
+            }
+            _ => {}
+        }
 
-    // 격자선 생성 (15개의 세로선과 15개의 가로선)
-    for i in 0..BOARD_SIZE {
-        commands.spawn(GridLineComponent::vertical(i)); // 세로선
-        commands.spawn(GridLineComponent::horizontal(i)); // 가로선
+        self.feedback.write(BlindFeedback(event));
+        Some(event)
     }
 
-    // 화점(별) 생성 - 바둑판의 특별한 점들
-    let star_points = [
-        (3, 3),
-        (3, 11),
-        (11, 3),
-        (11, 11), // 모서리 화점 4개
-        (7, 7),   // 중앙 화점 1개
-        (3, 7),
-        (11, 7),
-        (7, 3),
-        (7, 11), // 변 화점 4개
-    ];
+    /// `color`를 기권패로 처리하고 `message`를 게임 종료 대화상자로 띄움
+    fn resign(&mut self, color: StoneColor, message: String) {
+        self.game_state.resign(color);
+        spawn_game_over_message(&mut self.painter.commands, message, WIN_MESSAGE_COLOR);
+    }
+}
 
-    for &(grid_x, grid_y) in &star_points {
-        // 격자 좌표를 월드 좌표로 변환
-        let world_x = (grid_x as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
-        let world_y = (grid_y as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
+/// 새 게임을 시작하거나 다른 게임을 불러올 때 판과 게임 상태를 바꾸는 시스템 매개변수 묶음
+#[derive(SystemParam)]
+struct GameSetup<'w, 's> {
+    commands: Commands<'w, 's>,
+    board_config: ResMut<'w, BoardConfig>,
+    game_config: ResMut<'w, GameConfig>,
+    game_state: ResMut<'w, GameState>,
+    opening: ResMut<'w, OpeningState>,
+    entities: Query<'w, 's, Entity, GameEntityFilter>,
+    feedback_display: Query<'w, 's, &'static mut Text, With<FeedbackDisplay>>,
+}
 
-        // 작은 원으로 화점 표시
-        commands.spawn((
-            Mesh2d(meshes.add(Circle::default())),
-            MeshMaterial2d(materials.add(STAR_COLOR)),
-            Transform {
-                translation: Vec3::new(world_x, world_y, 0.1), // 격자선보다 약간 위에
-                scale: Vec3::splat(6.0),                       // 작은 원
-                ..default()
-            },
-            StarPoint,
-        ));
+impl GameSetup<'_, '_> {
+    /// 이전 게임의 돌과 대화상자, 진행 중인 작업을 지움
+    fn clear(&mut self) {
+        for entity in &self.entities {
+            self.commands.entity(entity).despawn();
+        }
+    }
+
+    /// 이전 게임을 지우고 `config` 크기의 판에서 `game`을 오프닝 없이 이어서 두도록 설정
+    ///
+    /// 판은 `rebuild_board`가 돌과 함께 다시 만들며, `black`이 흑을 맡습니다.
+    fn load(&mut self, config: BoardConfig, game: BlindGame, black: Player) {
+        self.clear();
+        *self.board_config = config;
+        self.board_config.set_changed();
+        self.game_config.rule_set = game.game().rule_set();
+        **self.game_state = game;
+        **self.opening = Opening::with_first_player(OpeningRule::None, black);
+    }
+
+    /// 피드백 표시줄의 내용을 `message`로 바꿈
+    fn show(&mut self, message: String) {
+        if let Ok(mut text) = self.feedback_display.single_mut() {
+            **text = message;
+        }
     }
+}
+
+// ==================== 초기 설정 시스템 ====================
+/// 게임 시작 시 필요한 카메라와 UI 엔티티를 생성하는 함수
+///
+/// 오목판은 `rebuild_board`가 `BoardConfig`에 맞춰 생성합니다.
+fn setup(mut commands: Commands) {
+    // 2D 카메라 생성
+    commands.spawn(Camera2d);
 
     // 턴 표시 UI 생성 (화면 왼쪽 위)
     commands.spawn((
@@ -250,14 +968,50 @@ fn setup(
         },
     ));
 
-    // 게임 설명 UI 생성 (화면 왼쪽 아래)
+    // 블라인드 피드백 UI 생성 (턴 표시 아래)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(FEEDBACK_COLOR),
+        FeedbackDisplay,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Px(20.0),
+            ..default()
+        },
+    ));
+
+    // 라이브러리 설명 UI 생성 (피드백 아래, 내용은 update_library_marks에서 채움)
+    commands.spawn((
+        Text::default(),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(LIBRARY_MARK_COLOR),
+        LibraryCommentDisplay,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            left: Val::Px(20.0),
+            max_width: Val::Px(UI_MARGIN.x - 40.0),
+            ..default()
+        },
+    ));
+
+    // 게임 설명 UI 생성 (화면 왼쪽 아래, 내용은 update_rules_display에서 채움)
     commands.spawn((
-        Text::new("마우스 클릭으로 돌을 놓으세요"),
+        Text::default(),
         TextFont {
             font_size: 18.0,
             ..default()
         },
         TextColor(TEXT_COLOR),
+        RulesDisplay,
         Node {
             position_type: PositionType::Absolute,
             bottom: Val::Px(20.0),
@@ -265,180 +1019,1763 @@ fn setup(
             ..default()
         },
     ));
+
+    // 외부 엔진 기록 창 생성 (화면 오른쪽 아래, 내용은 update_engine_log에서 채움)
+    commands.spawn((
+        Text::default(),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(FEEDBACK_COLOR),
+        EngineLogDisplay,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            right: Val::Px(20.0),
+            max_width: Val::Px(360.0),
+            ..default()
+        },
+    ));
+
+    // 새 게임, 무르기, 다시 두기 버튼 생성 (화면 오른쪽 위)
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            right: Val::Px(20.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            spawn_button(parent, "New Game", RestartButton);
+            spawn_button(parent, "Undo", HistoryButton::Undo);
+            spawn_button(parent, "Redo", HistoryButton::Redo);
+
+            // AI 난이도 메뉴
+            parent.spawn((
+                Text::new("AI Level"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(12.0)),
+                    ..default()
+                },
+            ));
+            for difficulty in Difficulty::ALL {
+                spawn_button(
+                    parent,
+                    &difficulty.to_string(),
+                    DifficultyButton(difficulty),
+                );
+            }
+
+            // 평가 막대 (흰 바탕에 흑이 유리한 만큼 검은 부분이 채워짐)
+            parent.spawn((
+                Text::new("Eval"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(12.0)),
+                    ..default()
+                },
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(EVAL_BAR_SIZE.x),
+                        height: Val::Px(EVAL_BAR_SIZE.y),
+                        ..default()
+                    },
+                    BackgroundColor(WHITE_STONE_COLOR),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(BLACK_STONE_COLOR),
+                    EvalBarFill,
+                ));
+        });
 }
 
-// ==================== 돌 놓기 처리 시스템 ====================
-/// 마우스 클릭을 감지하여 돌을 놓는 시스템
-fn handle_stone_placement(
-    mut commands: Commands,
-    mut game_state: ResMut<GameState>,
+/// `assets` 폴더에서 오프닝 정석을 불러오기 시작하는 시스템
+fn load_opening_book(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(BookHandle(asset_server.load(BOOK_PATH)));
+}
+
+/// 글자가 적힌 UI 버튼 생성 (`marker`로 어떤 버튼인지 구분)
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            marker,
+            Node {
+                width: Val::Px(110.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(BUTTON_COLOR),
+        ))
+        .with_child((
+            Text::new(label),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(BUTTON_TEXT_COLOR),
+        ));
+}
+
+// ==================== 오목판 생성 시스템 ====================
+/// 판 크기가 바뀔 때 다시 만들어야 하는 엔티티 필터
+type BoardEntityFilter = Or<(
+    With<BoardBackground>,
+    With<GridLineComponent>,
+    With<StarPoint>,
+    With<Stone>,
+)>;
+
+/// `BoardConfig`에 맞춰 오목판(배경, 격자선, 화점)을 다시 만드는 시스템
+///
+/// 판 크기가 바뀌면 이전 판과 돌을 모두 지우고 새로 생성하며,
+/// 현재 게임에 놓인 돌은 새 판 위에 다시 만듭니다 (기보를 불러올 때 사용).
+fn rebuild_board(
+    mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    mouse_button: Res<ButtonInput<MouseButton>>,
+    config: Res<BoardConfig>,
+    game_state: Res<GameState>,
+    old_entities: Query<Entity, BoardEntityFilter>,
+) {
+    // 이전 판 제거
+    for entity in &old_entities {
+        commands.entity(entity).despawn();
+    }
+
+    // 오목판 배경 생성
+    commands.spawn(BoardBackground::new(&config));
+
+    // 격자선 생성 (가로 칸 수만큼 세로선, 세로 칸 수만큼 가로선)
+    for i in 0..config.width {
+        commands.spawn(GridLineComponent::vertical(i, &config)); // 세로선
+    }
+    for i in 0..config.height {
+        commands.spawn(GridLineComponent::horizontal(i, &config)); // 가로선
+    }
+
+    // 화점(별) 생성 - 바둑판의 특별한 점들
+    for (grid_x, grid_y) in config.star_points() {
+        // 격자 좌표를 월드 좌표로 변환
+        let world_pos = config.grid_to_world(grid_x, grid_y);
+
+        // 작은 원으로 화점 표시
+        commands.spawn((
+            Mesh2d(meshes.add(Circle::default())),
+            MeshMaterial2d(materials.add(STAR_COLOR)),
+            Transform {
+                translation: world_pos.extend(0.1), // 격자선보다 약간 위에
+                scale: Vec3::splat(6.0),            // 작은 원
+                ..default()
+            },
+            StarPoint,
+        ));
+    }
+
+    // 현재 게임의 돌 생성
+    for (x, y, color) in game_state.game().board().stones() {
+        spawn_stone(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &config,
+            color,
+            (x, y),
+        );
+    }
+}
+
+/// 창 크기에 맞춰 오목판 전체가 보이도록 카메라 배율을 조정하는 시스템
+fn fit_camera_to_board(
+    config: Res<BoardConfig>,
     windows: Query<&Window>,
-    camera_q: Query<(&Camera, &GlobalTransform)>,
-) {
-    // 게임이 끝났거나 마우스 왼쪽 버튼을 누르지 않았으면 무시
-    if game_state.game_over || !mouse_button.just_pressed(MouseButton::Left) {
-        return;
-    }
-
-    // 윈도우와 카메라 정보 가져오기
-    let window = windows.single().unwrap();
-    let (camera, camera_transform) = camera_q.single().unwrap();
-
-    // 마우스 커서 위치 확인
-    if let Some(cursor_pos) = window.cursor_position() {
-        // 화면 좌표를 월드 좌표로 변환
-        if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) {
-            // 월드 좌표를 격자 좌표로 변환
-            let grid_x = ((world_pos.x + (BOARD_SIZE - 1) as f32 / 2.0 * GRID_SIZE) / GRID_SIZE)
-                .round() as i32;
-            let grid_y = ((world_pos.y + (BOARD_SIZE - 1) as f32 / 2.0 * GRID_SIZE) / GRID_SIZE)
-                .round() as i32;
-
-            // 격자 좌표가 유효한 범위 내인지 확인
-            if grid_x >= 0
-                && grid_x < BOARD_SIZE as i32
-                && grid_y >= 0
-                && grid_y < BOARD_SIZE as i32
-            {
-                let gx = grid_x as usize;
-                let gy = grid_y as usize;
-
-                // 해당 위치가 비어있는지 확인
-                if game_state.board[gx][gy].is_none() {
-                    // 게임 상태에 돌 정보 저장
-                    game_state.board[gx][gy] = Some(game_state.current_player);
-
-                    // 격자 좌표를 월드 좌표로 다시 변환 (정확한 위치에 돌 배치)
-                    let world_x = (gx as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
-                    let world_y = (gy as f32 - (BOARD_SIZE - 1) as f32 / 2.0) * GRID_SIZE;
-
-                    // 돌 스프라이트 생성
-                    commands.spawn((
-                        Mesh2d(meshes.add(Circle::default())), // 원형 메시
-                        MeshMaterial2d(materials.add(game_state.current_player.to_color())), // 돌 색상
-                        Transform {
-                            translation: Vec3::new(world_x, world_y, 1.0), // 격자선보다 위에 배치
-                            scale: Vec3::splat(STONE_RADIUS * 2.0),        // 돌 크기
-                            ..default()
-                        },
-                        Stone {
-                            color: game_state.current_player,
-                            grid_pos: (gx, gy),
-                        },
-                    ));
-
-                    // 승부 판정
-                    if check_win(&game_state.board, gx, gy, game_state.current_player) {
-                        // 게임 종료 처리
-                        game_state.game_over = true;
-                        game_state.winner = Some(game_state.current_player);
-
-                        // 승리 메시지 UI 생성
-                        commands.spawn((
-                            Text::new(format!(
-                                "{}이 승리했습니다!",
-                                game_state.current_player.to_korean()
-                            )),
-                            TextFont {
-                                font_size: 36.0,
-                                ..default()
-                            },
-                            TextColor(Color::srgb(1.0, 0.2, 0.2)), // 빨간색 텍스트
-                            GameOverDisplay,
-                            Node {
-                                position_type: PositionType::Absolute,
-                                top: Val::Px(100.0),
-                                left: Val::Px(50.0),
-                                ..default()
-                            },
-                        ));
-                    } else {
-                        // 승부가 나지 않았으면 턴 교체
-                        game_state.current_player = game_state.current_player.opposite();
+    mut projections: Query<&mut Projection, With<Camera2d>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Ok(mut projection) = projections.single_mut() else {
+        return;
+    };
+
+    // UI 공간을 뺀 영역에 오목판이 들어가도록 배율 계산 (확대는 하지 않음)
+    let available = (window.size() - UI_MARGIN).max(Vec2::ONE);
+    let ratio = config.board_extent() / available;
+    let scale = ratio.x.max(ratio.y).max(1.0);
+
+    if let Projection::Orthographic(orthographic) = projection.as_ref() {
+        if orthographic.scale == scale {
+            return; // 변경 감지를 불필요하게 일으키지 않음
+        }
+    }
+    if let Projection::Orthographic(orthographic) = projection.as_mut() {
+        orthographic.scale = scale;
+    }
+}
+
+/// 첫 수를 두기 전에 키보드로 판 크기를 바꾸는 시스템
+///
+/// `-`/`=` 키로 가로, `[`/`]` 키로 세로 칸 수를 조절합니다.
+fn change_board_size(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<BoardConfig>,
+    game_config: Res<GameConfig>,
+    mut game_state: ResMut<GameState>,
+    mut opening: ResMut<OpeningState>,
+) {
+    // 이미 돌이 놓였으면 판 크기를 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    let (mut width, mut height) = (config.width, config.height);
+    if keyboard.just_pressed(KeyCode::Minus) {
+        width = width.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::Equal) {
+        width += 1;
+    }
+    if keyboard.just_pressed(KeyCode::BracketLeft) {
+        height = height.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::BracketRight) {
+        height += 1;
+    }
+
+    let new_config = BoardConfig::new(width, height);
+    if new_config != *config {
+        *config = new_config;
+        **game_state = game_config.new_game(&new_config);
+        **opening = game_config.new_opening(opening.first_player());
+    }
+}
+
+// ==================== 돌 놓기 처리 시스템 ====================
+/// 마우스로 누른 격자 좌표를 구하는 시스템 매개변수 묶음
+#[derive(SystemParam)]
+struct BoardCursor<'w, 's> {
+    mouse_button: Res<'w, ButtonInput<MouseButton>>,
+    windows: Query<'w, 's, &'static Window>,
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    config: Res<'w, BoardConfig>,
+}
+
+impl BoardCursor<'_, '_> {
+    /// 이번 프레임에 마우스 왼쪽 버튼으로 누른 격자 좌표 (누르지 않았거나 판 밖이면 `None`)
+    fn clicked(&self) -> Option<(usize, usize)> {
+        if !self.mouse_button.just_pressed(MouseButton::Left) {
+            return None;
+        }
+
+        // 윈도우와 카메라 정보 가져오기
+        let window = self.windows.single().unwrap();
+        let (camera, camera_transform) = self.camera.single().unwrap();
+
+        // 마우스 커서 위치를 월드 좌표로, 다시 격자 좌표로 변환
+        let cursor_pos = window.cursor_position()?;
+        let world_pos = camera
+            .viewport_to_world_2d(camera_transform, cursor_pos)
+            .ok()?;
+        self.config.world_to_grid(world_pos)
+    }
+}
+
+/// 마우스 클릭을 감지하여 돌을 놓는 시스템
+///
+/// 오프닝 중에는 클릭이 오프닝 단계로 전달되며, 오프닝 돌은 양쪽 모두에게 공개됩니다.
+/// 본 게임의 돌은 실제 판에 기록되지만 화면에는 각 플레이어의 시야에 따라 표시됩니다.
+/// 결과는 `BlindFeedback` 이벤트로 전달됩니다.
+fn handle_stone_placement(
+    mut player: MovePlayer,
+    mut opening: ResMut<OpeningState>,
+    mut opening_feedback: EventWriter<OpeningFeedback>,
+    cursor: BoardCursor,
+    ai: Res<AiPlayer>,
+    viewer: Res<Viewer>,
+) {
+    // 게임이 끝났거나 넘겨주기 화면이 떠 있으면 무시
+    let game = player.game_state.game();
+    if game.is_over() || viewer.color.is_none() {
+        return;
+    }
+
+    // 본 게임에서 AI의 차례이면 클릭 무시
+    if opening.is_done() && ai.color == Some(game.current_player()) {
+        return;
+    }
+
+    // 누른 격자 좌표 (누르지 않았거나 판 밖이면 무시)
+    let Some((gx, gy)) = cursor.clicked() else {
+        return;
+    };
+
+    // 오프닝 중이면 클릭을 오프닝 단계로 전달 (놓인 돌은 모두 공개됨)
+    if !opening.is_done() {
+        let moves_before = player.game_state.game().history().len();
+        if let Err(err) = player
+            .game_state
+            .play_public(|game| opening.place(game, gx, gy))
+        {
+            opening_feedback.write(OpeningFeedback(err));
+            return;
+        }
+        let placed = player.game_state.game().history()[moves_before..].to_vec();
+        for stone in placed {
+            player.painter.spawn_stone(stone.color, (stone.x, stone.y));
+        }
+        return;
+    }
+
+    player.play((gx, gy));
+}
+
+/// 격자 좌표에 돌 엔티티 생성 (처음에는 숨겨진 상태)
+fn spawn_stone(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    config: &BoardConfig,
+    color: StoneColor,
+    pos: (usize, usize),
+) {
+    // 격자 좌표를 월드 좌표로 변환 (정확한 위치에 돌 배치)
+    let world_pos = config.grid_to_world(pos.0, pos.1);
+
+    commands.spawn((
+        Mesh2d(meshes.add(Circle::default())),           // 원형 메시
+        MeshMaterial2d(materials.add(color.to_color())), // 돌 색상
+        Transform {
+            translation: world_pos.extend(1.0),     // 격자선보다 위에 배치
+            scale: Vec3::splat(STONE_RADIUS * 2.0), // 돌 크기
+            ..default()
+        },
+        Visibility::Hidden,
+        Stone {
+            color,
+            grid_pos: pos,
+        },
+    ));
+}
+
+// ==================== 오프닝 선택 시스템 ====================
+/// 오프닝의 선택 단계에서 키보드 입력을 처리하는 시스템
+///
+/// B/W: 색 선택, T: Swap2 2수 추가 또는 Taraguchi 5수 후보 10개,
+/// S: Taraguchi 5수 하나, 1~8: Soosõrv 5수 후보 개수 선언
+fn handle_opening_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut opening: ResMut<OpeningState>,
+    mut opening_feedback: EventWriter<OpeningFeedback>,
+) {
+    const DIGITS: [KeyCode; 8] = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+    ];
+
+    let game = game_state.game();
+    let pressed = |key| keyboard.just_pressed(key);
+    let result = match opening.phase() {
+        Phase::ChooseColor { .. } | Phase::Swap2Choice { .. } if pressed(KeyCode::KeyB) => {
+            opening.choose_color(game, StoneColor::Black)
+        }
+        Phase::ChooseColor { .. } | Phase::Swap2Choice { .. } if pressed(KeyCode::KeyW) => {
+            opening.choose_color(game, StoneColor::White)
+        }
+        Phase::Swap2Choice { .. } if pressed(KeyCode::KeyT) => opening.place_two_more(),
+        Phase::TaraguchiFifth { .. } if pressed(KeyCode::KeyS) => opening.choose_fifth(false),
+        Phase::TaraguchiFifth { .. } if pressed(KeyCode::KeyT) => opening.choose_fifth(true),
+        Phase::DeclareCount { .. } => match DIGITS.iter().position(|&key| pressed(key)) {
+            Some(index) => opening.declare_count(index + 1),
+            None => return,
+        },
+        _ => return,
+    };
+
+    if let Err(err) = result {
+        opening_feedback.write(OpeningFeedback(err));
+    }
+}
+
+// ==================== 게임 종료 메시지 ====================
+/// 게임 결과(승리 또는 무승부) 메시지 UI 생성
+fn spawn_result_message(commands: &mut Commands, result: GameResult) {
+    match result {
+        GameResult::Win(winner) => spawn_game_over_message(
+            commands,
+            format!("{}이 승리했습니다!", winner.to_korean()),
+            WIN_MESSAGE_COLOR,
+        ),
+        GameResult::Draw => {
+            spawn_game_over_message(commands, "무승부입니다!".to_string(), DRAW_MESSAGE_COLOR)
+        }
+    }
+}
+
+/// 게임 종료 대화상자 UI 생성 (결과 메시지와 새 게임 버튼)
+fn spawn_game_over_message(commands: &mut Commands, message: String, color: Color) {
+    commands
+        .spawn((
+            GameOverDisplay,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(100.0),
+                left: Val::Px(50.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            BackgroundColor(DIALOG_COLOR),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(color),
+            ));
+            spawn_button(parent, "New Game", RestartButton);
+        });
+}
+
+// ==================== AI 시스템 ====================
+/// AI의 수를 기다리는 작업 엔티티 필터 (탐색 또는 외부 엔진)
+type PendingMoveFilter = Or<(With<AiTask>, With<EngineTask>)>;
+
+/// AI 차례가 되면 작업 스레드에서 다음 수 탐색을 시작하는 시스템
+///
+/// AI는 블라인드 규칙을 지켜 자신에게 공개된 돌만 보고 탐색하며,
+/// 오프닝이 끝난 뒤부터 둡니다. 판 전체가 보이고 정석에 있는 국면이면 탐색 없이 정석을 따릅니다.
+/// 외부 엔진을 쓸 때는 `start_engine_move`가 대신 엔진에 판을 보냅니다.
+fn start_ai_move(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    game_config: Res<GameConfig>,
+    mut ai: ResMut<AiPlayer>,
+    book: BookLookup,
+    tasks: Query<(), PendingMoveFilter>,
+) {
+    let game = game_state.game();
+    let Some(color) = ai.color else {
+        return;
+    };
+    if ai.strategy == AiStrategy::External
+        || game.is_over()
+        || !opening.is_done()
+        || game.current_player() != color
+        || !tasks.is_empty()
+    {
+        return;
+    }
+
+    let view = game_state.view(color).board().clone();
+    let rule_set = game.rule_set();
+    let profile = game_config.difficulty.profile();
+    let seed = ai.rng.next_u64();
+    let pool = AsyncComputeTaskPool::get();
+
+    // 보이지 않는 상대 돌이 없으면 공개된 판이 실제 판이므로 정석을 그대로 쓸 수 있음
+    let book_move = book
+        .get()
+        .filter(|_| game_state.hidden_stones(color) == 0)
+        .and_then(|book| book.choose(&view, color, &mut ai.rng))
+        .filter(|&(x, y)| rule_set.forbidden(&view, x, y, color).is_none());
+    if let Some((x, y)) = book_move {
+        let result = SearchResult {
+            x,
+            y,
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        };
+        let task = pool.spawn(async move { Some(result) });
+        commands.spawn(AiTask { task, view, color });
+        return;
+    }
+
+    let task = match ai.strategy {
+        AiStrategy::Belief => {
+            // 상대 돌의 개수는 공개 정보이므로 위치만 추정
+            let (board, belief) = (view.clone(), ai.belief.clone());
+            let hidden = game_state.hidden_stones(color);
+            pool.spawn(async move {
+                belief::choose_move(&board, &belief, color, hidden, rule_set, &profile, seed)
+            })
+        }
+        AiStrategy::Search | AiStrategy::External => {
+            let board = ai.belief.assumed_board(&view, color);
+            pool.spawn(async move { ai::choose_move(&board, rule_set, color, &profile, seed) })
+        }
+    };
+    commands.spawn(AiTask { task, view, color });
+}
+
+/// 탐색이 끝난 AI의 수를 판에 두는 시스템
+fn finish_ai_move(
+    mut tasks: Query<(Entity, &mut AiTask)>,
+    mut player: MovePlayer,
+    mut ai: ResMut<AiPlayer>,
+) {
+    for (entity, mut ai_task) in &mut tasks {
+        let Some(result) = block_on(future::poll_once(&mut ai_task.task)) else {
+            continue; // 아직 탐색 중
+        };
+        player.painter.commands.entity(entity).despawn();
+
+        // 탐색하는 동안 게임이 바뀌었으면 결과를 버림
+        let game = player.game_state.game();
+        if game.is_over()
+            || game.current_player() != ai_task.color
+            || player.game_state.view(ai_task.color).board() != &ai_task.view
+        {
+            continue;
+        }
+
+        // 탐색이 수를 찾지 못하면 둘 수 있는 아무 자리에 둠 (같은 탐색을 되풀이하지 않도록)
+        let color = ai_task.color;
+        let pos = result.map(|result| (result.x, result.y)).or_else(|| {
+            ai.belief
+                .fallback_move(&ai_task.view, color, game.rule_set())
+        });
+        let event = pos.and_then(|pos| player.play(pos));
+
+        // 색이 공개되지 않은 충돌 자리와 보이지 않는 돌 때문에 생긴 금수 자리를 기억
+        match event {
+            Some(event) => ai.belief.observe(&event),
+            None => {
+                // 둘 자리가 없으면 기권 (다음 프레임에 같은 탐색을 다시 시작하지 않음)
+                player.resign(
+                    color,
+                    format!("{}이 둘 곳이 없어 기권했습니다!", color.to_korean()),
+                );
+            }
+        }
+    }
+}
+
+// ==================== 강제승 풀이 시스템 ====================
+/// G 키로 현재 플레이어의 강제승(VCF, 없으면 VCT) 수순 풀이를 작업 스레드에서 시작하는 시스템
+///
+/// 블라인드 규칙을 지켜 현재 플레이어에게 공개된 돌만 놓인 판에서 풀며,
+/// 화면에 그 플레이어의 시야를 보여 주고 있을 때만 시작합니다.
+fn start_solve(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    viewer: Res<Viewer>,
+    tasks: Query<(), With<SolveTask>>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    let game = game_state.game();
+    if !keyboard.just_pressed(KeyCode::KeyG)
+        || game.is_over()
+        || !opening.is_done()
+        || viewer.color != Some(game.current_player())
+        || !tasks.is_empty()
+    {
+        return;
+    }
+
+    let color = game.current_player();
+    let view = game_state.view(color).board().clone();
+    let (board, rule_set) = (view.clone(), game.rule_set());
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let limits = SolverLimits::default();
+        [SolveMode::Vcf, SolveMode::Vct]
+            .into_iter()
+            .find_map(|mode| {
+                solver::solve(&board, rule_set, color, mode, &limits).map(|line| (mode, line))
+            })
+    });
+    commands.spawn(SolveTask { task, view, color });
+
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = format!("{color}: searching for a forced win...");
+    }
+}
+
+/// 풀이가 끝나면 찾은 수순을 판 위에 표시하는 시스템
+///
+/// 수순의 돌은 처음에는 숨겨 두고 `animate_solution`이 한 수씩 보여 줍니다.
+fn finish_solve(
+    mut painter: BoardPainter,
+    mut tasks: Query<(Entity, &mut SolveTask)>,
+    mut playback: ResMut<SolutionPlayback>,
+    game_state: Res<GameState>,
+    marks: Query<Entity, With<SolutionMark>>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    for (entity, mut solve_task) in &mut tasks {
+        let Some(result) = block_on(future::poll_once(&mut solve_task.task)) else {
+            continue; // 아직 풀이 중
+        };
+        painter.commands.entity(entity).despawn();
+
+        // 풀이하는 동안 판이 바뀌었으면 결과를 버림
+        let game = game_state.game();
+        let color = solve_task.color;
+        if game.current_player() != color || game_state.view(color).board() != &solve_task.view {
+            continue;
+        }
+
+        // 이전 수순 제거
+        for mark in &marks {
+            painter.commands.entity(mark).despawn();
+        }
+        *playback = SolutionPlayback::default();
+
+        let message = match result {
+            Some((mode, line)) => {
+                let mut last = None;
+                for (step, &solution_step) in line.iter().enumerate() {
+                    match solution_step {
+                        Step::Stone(stone) => {
+                            spawn_solution_mark(&mut painter, step, &stone);
+                            last = Some(stone);
+                        }
+                        // 넘김은 막지 못한 직전 공격 수 아래에 표시
+                        Step::Pass(_) => {
+                            if let Some(last) = last {
+                                spawn_pass_mark(&mut painter, step, &last);
+                            }
+                        }
                     }
                 }
+                let stones = line
+                    .iter()
+                    .filter(|step| matches!(step, Step::Stone(_)))
+                    .count();
+                format!("{color}: {mode} found ({stones} moves)")
             }
+            None => format!("{color}: no forced win found"),
+        };
+        if let Ok(mut text) = feedback_display.single_mut() {
+            **text = message;
         }
     }
 }
 
+/// 강제승 수순의 한 수를 반투명 돌과 수 번호로 생성 (처음에는 숨김)
+fn spawn_solution_mark(painter: &mut BoardPainter, step: usize, stone: &Move) {
+    let (stone_color, text_color) = match stone.color {
+        StoneColor::Black => (BLACK_STONE_COLOR, WHITE_STONE_COLOR),
+        StoneColor::White => (WHITE_STONE_COLOR, BLACK_STONE_COLOR),
+    };
+    let position = painter.config.grid_to_world(stone.x, stone.y);
+    let mesh = painter.meshes.add(Circle::default());
+    let material = painter
+        .materials
+        .add(stone_color.with_alpha(SOLUTION_ALPHA));
+
+    let commands = &mut painter.commands;
+    commands.spawn((
+        Mesh2d(mesh),
+        MeshMaterial2d(material),
+        Transform {
+            translation: position.extend(3.0), // 돌과 수 표시보다 위
+            scale: Vec3::splat(STONE_RADIUS * 2.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        SolutionMark { step },
+    ));
+    commands.spawn((
+        Text2d::new((step + 1).to_string()),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(text_color),
+        Transform::from_translation(position.extend(3.5)),
+        Visibility::Hidden,
+        SolutionMark { step },
+    ));
+}
+
+/// 수비자가 막지 못하고 차례를 넘긴 수를 `previous` 돌 아래에 수 번호와 글자로 생성 (처음에는 숨김)
+fn spawn_pass_mark(painter: &mut BoardPainter, step: usize, previous: &Move) {
+    let position =
+        painter.config.grid_to_world(previous.x, previous.y) - Vec2::new(0.0, STONE_RADIUS + 8.0);
+    painter.commands.spawn((
+        Text2d::new(format!("{} pass", step + 1)),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(FEEDBACK_COLOR),
+        Transform::from_translation(position.extend(3.5)),
+        Visibility::Hidden,
+        SolutionMark { step },
+    ));
+}
+
+/// 강제승 수순을 한 수씩 차례로 보여 주는 시스템
+fn animate_solution(
+    time: Res<Time>,
+    mut playback: ResMut<SolutionPlayback>,
+    mut marks: Query<(&SolutionMark, &mut Visibility)>,
+) {
+    if marks.is_empty() || !playback.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    playback.shown += 1;
+    for (mark, mut visibility) in &mut marks {
+        if mark.step < playback.shown {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// 판이 바뀌면 표시 중인 강제승 수순을 지우는 시스템
+fn clear_solution_marks(mut commands: Commands, marks: Query<Entity, With<SolutionMark>>) {
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+}
+
+// ==================== AI 설정 시스템 ====================
+/// 난이도 메뉴에서 누른 난이도를 게임 설정에 저장하는 시스템
+fn select_difficulty(
+    buttons: Query<(&Interaction, &DifficultyButton), Changed<Interaction>>,
+    mut config: ResMut<GameConfig>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction == Interaction::Pressed && config.difficulty != button.0 {
+            config.difficulty = button.0;
+        }
+    }
+}
+
+/// 선택된 난이도 버튼을 강조하는 시스템
+fn update_difficulty_buttons(
+    config: Res<GameConfig>,
+    mut buttons: Query<(&DifficultyButton, &mut BackgroundColor)>,
+) {
+    for (button, mut background) in &mut buttons {
+        background.0 = if button.0 == config.difficulty {
+            SELECTED_BUTTON_COLOR
+        } else {
+            BUTTON_COLOR
+        };
+    }
+}
+
+/// A 키로 AI가 맡을 색을 바꾸는 시스템 (끔 → 백 → 흑 → 끔)
+fn cycle_ai_player(keyboard: Res<ButtonInput<KeyCode>>, mut ai: ResMut<AiPlayer>) {
+    if !keyboard.just_pressed(KeyCode::KeyA) {
+        return;
+    }
+
+    ai.color = match ai.color {
+        None => Some(StoneColor::White),
+        Some(StoneColor::White) => Some(StoneColor::Black),
+        Some(StoneColor::Black) => None,
+    };
+    ai.belief = Belief::new();
+}
+
+/// M 키로 AI가 수를 고르는 방식을 바꾸는 시스템
+fn cycle_ai_strategy(keyboard: Res<ButtonInput<KeyCode>>, mut ai: ResMut<AiPlayer>) {
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        ai.strategy = ai.strategy.next();
+    }
+}
+
+// ==================== 무르기 / 다시 두기 시스템 ====================
+/// 무르기/다시 두기 요청을 읽는 시스템 매개변수 묶음
+#[derive(SystemParam)]
+struct HistoryInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    buttons: Query<'w, 's, (&'static Interaction, &'static HistoryButton), Changed<Interaction>>,
+    game_config: Res<'w, GameConfig>,
+    opening: Res<'w, OpeningState>,
+}
+
+impl HistoryInput<'_, '_> {
+    /// 키보드 또는 버튼으로 요청된 동작 (무르기를 허용하지 않았거나 오프닝 중이면 `None`)
+    fn action(&self) -> Option<HistoryButton> {
+        let action = if self.keyboard.just_pressed(KeyCode::KeyZ) {
+            Some(HistoryButton::Undo)
+        } else if self.keyboard.just_pressed(KeyCode::KeyY) {
+            Some(HistoryButton::Redo)
+        } else {
+            self.buttons
+                .iter()
+                .find(|(interaction, _)| **interaction == Interaction::Pressed)
+                .map(|(_, &button)| button)
+        };
+        action.filter(|_| self.game_config.allow_undo && self.opening.is_done())
+    }
+}
+
+/// Z 키(또는 Undo 버튼)로 마지막 수를 무르고, Y 키(또는 Redo 버튼)로 다시 두는 시스템
+///
+/// 무르기를 허용한 게임에서 오프닝이 끝난 뒤에만 사용할 수 있으며,
+/// 오프닝 중에 놓인 돌은 무를 수 없습니다.
+fn handle_undo_redo(
+    mut painter: BoardPainter,
+    mut game_state: ResMut<GameState>,
+    input: HistoryInput,
+    stones: Query<(Entity, &Stone)>,
+    game_over_messages: Query<Entity, With<GameOverDisplay>>,
+    mut ai: ResMut<AiPlayer>,
+) {
+    let Some(action) = input.action() else {
+        return;
+    };
+
+    match action {
+        HistoryButton::Undo => {
+            // AI와 둘 때는 사람의 차례가 될 때까지 무름 (오프닝 돌은 무를 수 없음)
+            while game_state.game().history().len() > input.opening.stones() {
+                let Some(undone) = game_state.undo() else {
+                    break;
+                };
+
+                // 무른 돌 제거
+                for (entity, stone) in &stones {
+                    if stone.grid_pos == (undone.x, undone.y) {
+                        painter.commands.entity(entity).despawn();
+                    }
+                }
+                if ai.color != Some(game_state.game().current_player()) {
+                    break;
+                }
+            }
+
+            // 게임 종료 메시지와 AI가 기억한 충돌 자리 제거
+            for entity in &game_over_messages {
+                painter.commands.entity(entity).despawn();
+            }
+            ai.belief = Belief::new();
+        }
+        HistoryButton::Redo => {
+            // AI와 둘 때는 사람의 차례가 될 때까지 다시 둠
+            while let Some((redone, _)) = game_state.redo() {
+                painter.spawn_stone(redone.color, (redone.x, redone.y));
+                let game = game_state.game();
+                if let Some(result) = game.result() {
+                    spawn_result_message(&mut painter.commands, result);
+                    break;
+                }
+                if ai.color != Some(game.current_player()) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// 무르기를 허용하지 않는 게임에서는 무르기 버튼을 숨기는 시스템
+fn update_history_buttons(
+    game_config: Res<GameConfig>,
+    mut buttons: Query<&mut Visibility, With<HistoryButton>>,
+) {
+    for mut visibility in &mut buttons {
+        *visibility = if game_config.allow_undo {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+/// U 키로 무르기 허용 여부를 바꾸는 시스템 (첫 수를 두기 전에만 가능)
+fn toggle_undo(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut config: ResMut<GameConfig>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyU) {
+        return;
+    }
+
+    // 이미 돌이 놓였으면 설정을 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    config.allow_undo = !config.allow_undo;
+}
+
+// ==================== 새 게임 시스템 ====================
+/// 새 게임을 시작할 때 지워야 하는 엔티티 필터
+type GameEntityFilter = Or<(
+    With<Stone>,
+    With<GameOverDisplay>,
+    With<ContinueDisplay>,
+    With<AiTask>,
+    With<EngineTask>,
+    With<SolveTask>,
+    With<HintTask>,
+)>;
+
+/// R 키(또는 New Game 버튼)로 프로그램을 다시 실행하지 않고 새 게임을 시작하는 시스템
+///
+/// 끝난 게임의 결과는 연속 대국 점수에 더해지며,
+/// 색 교대를 켜면 다음 게임은 지난 게임에서 나중에 두었던 플레이어가 먼저 둡니다.
+fn restart_game(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
+    mut setup: GameSetup,
+    mut match_state: ResMut<MatchState>,
+    mut ai: ResMut<AiPlayer>,
+) {
+    let pressed = keyboard.just_pressed(KeyCode::KeyR)
+        || buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed);
+    if !pressed {
+        return;
+    }
+
+    // 끝난 게임의 결과를 점수에 반영
+    let opening = &setup.opening;
+    match setup.game_state.game().result() {
+        Some(GameResult::Win(color)) => match_state.wins[opening.player_of(color).index()] += 1,
+        Some(GameResult::Draw) => match_state.draws += 1,
+        None => {}
+    }
+
+    // 새 게임 시작 (필요하면 먼저 두는 플레이어 교대)
+    let first = if match_state.swap_colors {
+        opening.first_player().opposite()
+    } else {
+        opening.first_player()
+    };
+    **setup.game_state = setup.game_config.new_game(&setup.board_config);
+    **setup.opening = setup.game_config.new_opening(first);
+    ai.belief = Belief::new();
+    ai.rng = Rng::new(setup.game_config.ai_seed);
+    match_state.game_started = record::now();
+    match_state.clocks = [Duration::ZERO; 2];
+
+    // 지난 게임의 돌과 종료 메시지 제거
+    setup.clear();
+    setup.show(String::new());
+}
+
+/// X 키로 게임마다 색을 교대할지 여부를 바꾸는 시스템
+fn toggle_swap_colors(keyboard: Res<ButtonInput<KeyCode>>, mut match_state: ResMut<MatchState>) {
+    if keyboard.just_pressed(KeyCode::KeyX) {
+        match_state.swap_colors = !match_state.swap_colors;
+    }
+}
+
+// ==================== 기보 저장 / 불러오기 시스템 ====================
+/// E 키로 현재 게임의 기보를 `RECORD_DIR` 폴더에 SGF와 좌표 표기 두 가지로 저장하는 시스템
+///
+/// 블라인드 대국이 끝나기 전에 저장하면 보이지 않는 돌까지 기록되므로,
+/// 어느 쪽에든 숨겨진 돌이 있으면 게임이 끝난 뒤에만 저장합니다.
+fn export_record(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    game_config: Res<GameConfig>,
+    match_state: Res<MatchState>,
+    ai: Res<AiPlayer>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyE) {
+        return;
+    }
+
+    let game = game_state.game();
+    let hidden = [StoneColor::Black, StoneColor::White]
+        .into_iter()
+        .any(|color| game_state.hidden_stones(color) > 0);
+    let message = if hidden && !game.is_over() {
+        "Finish the game before saving (hidden stones would be revealed)".to_string()
+    } else {
+        // 대국자 이름은 플레이어 번호 (AI가 맡은 색은 AI 설정)
+        let name = |color: StoneColor| {
+            if ai.color != Some(color) {
+                opening.player_of(color).to_string()
+            } else if ai.strategy == AiStrategy::External {
+                "AI (engine)".to_string()
+            } else {
+                format!("AI ({})", game_config.difficulty)
+            }
+        };
+        let record = Record {
+            black: name(StoneColor::Black),
+            white: name(StoneColor::White),
+            moves: game.history().to_vec(),
+            result: game.result(),
+            started: Some(match_state.game_started),
+            finished: game.is_over().then(record::now),
+            ..Record::new(game.board().width(), game.board().height(), game.rule_set())
+        };
+
+        // 파일 이름은 게임을 시작한 시각 (같은 게임을 다시 저장하면 덮어씀)
+        let stamp = record::format_timestamp(match_state.game_started).replace(':', "-");
+        let path = Path::new(RECORD_DIR).join(format!("game-{stamp}"));
+        match save_record(&record, &path) {
+            Ok(()) => format!("Saved {}.sgf / .txt", path.display()),
+            Err(err) => format!("Could not save the game: {err}"),
+        }
+    };
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = message;
+    }
+}
+
+/// 기보를 `path`에 `.sgf`와 `.txt` 확장자로 저장 (폴더가 없으면 만듦)
+fn save_record(record: &Record, path: &Path) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path.with_extension("sgf"), record.to_sgf())?;
+    std::fs::write(path.with_extension("txt"), record.to_text())
+}
+
+/// L 키로 `RECORD_DIR` 폴더에서 가장 최근에 바뀐 기보(`.sgf` 또는 `.txt`)를 불러오는 시스템
+///
+/// 불러온 돌은 양쪽 모두에게 공개되며, 오프닝 없이 기보의 다음 수부터 이어서 둘 수 있습니다.
+/// 판 크기와 규칙은 기보를 따르며, 판은 `rebuild_board`가 돌과 함께 다시 만듭니다.
+fn import_record(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut setup: GameSetup,
+    mut match_state: ResMut<MatchState>,
+    mut ai: ResMut<AiPlayer>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+
+    let loaded = latest_record().and_then(|(path, record)| {
+        let config = BoardConfig::new(record.width, record.height);
+        if (config.width, config.height) != (record.width, record.height) {
+            return Err(format!(
+                "{}: {}x{} boards are not supported",
+                path.display(),
+                record.width,
+                record.height
+            ));
+        }
+        let mut game = BlindGame::with_game(
+            Game::with_size(record.width, record.height).with_rule_set(record.rule_set),
+        );
+        game.play_public(|game| record.replay(game))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok((path, record, config, game))
+    });
+    let message = match loaded {
+        Ok((path, record, config, game)) => {
+            // 이전 게임의 엔티티를 지우고 판을 다시 만듦 (돌은 rebuild_board가 생성)
+            let result = game.game().result();
+            setup.load(config, game, Player::First);
+            if let Some(result) = result {
+                spawn_result_message(&mut setup.commands, result);
+            }
+            match_state.game_started = record.started.unwrap_or_else(record::now);
+            match_state.clocks = [Duration::ZERO; 2];
+            ai.belief = Belief::new();
+            ai.rng = Rng::new(setup.game_config.ai_seed);
+            format!(
+                "Loaded {} ({} vs {}, {} moves)",
+                path.display(),
+                record.black,
+                record.white,
+                record.moves.len()
+            )
+        }
+        Err(err) => format!("Could not load a game: {err}"),
+    };
+    setup.show(message);
+}
+
+/// `RECORD_DIR` 폴더에서 확장자가 `extensions` 중 하나인 가장 최근에 바뀐 파일
+fn latest_file(extensions: &[&str]) -> Result<PathBuf, String> {
+    let entries = std::fs::read_dir(RECORD_DIR).map_err(|err| format!("{RECORD_DIR}: {err}"))?;
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| extensions.contains(&extension))
+        })
+        .max_by_key(|path| path.metadata().and_then(|meta| meta.modified()).ok())
+        .ok_or_else(|| format!("no .{} files in {RECORD_DIR}", extensions.join("/.")))
+}
+
+/// `RECORD_DIR` 폴더에서 가장 최근에 바뀐 기보 파일을 읽음
+fn latest_record() -> Result<(PathBuf, Record), String> {
+    let path = latest_file(&["sgf", "txt"])?;
+    let text =
+        std::fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let record = Record::parse(&text).map_err(|err| format!("{}: {err}", path.display()))?;
+    Ok((path, record))
+}
+
+/// J 키로 `RECORD_DIR` 폴더에서 가장 최근에 바뀐 RenLib 라이브러리(`.lib`)를 불러오는 시스템
+///
+/// 라이브러리는 현재 게임의 수순을 따라가며 `update_library_marks`가 판 위에 다음 변화를 표시합니다.
+fn load_library(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut library: ResMut<LibraryState>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyJ) {
+        return;
+    }
+
+    let loaded = latest_file(&["lib"]).and_then(|path| {
+        let bytes = std::fs::read(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let parsed = Library::parse(&bytes).map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok((path, parsed))
+    });
+    let message = match loaded {
+        Ok((path, parsed)) => {
+            let message = format!("Loaded {} ({} moves)", path.display(), parsed.len());
+            library.0 = Some(parsed);
+            message
+        }
+        Err(err) => format!("Could not load a library: {err}"),
+    };
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = message;
+    }
+}
+
+/// P 키로 현재 국면 문자열을 로그에 남기는 시스템
+///
+/// 숨은 돌까지 모두 적으므로 화면에는 보여 주지 않고 로그(표준 출력)로만 내보냅니다.
+/// 적힌 문자열을 `--position`에 주면 같은 국면에서 다시 시작할 수 있습니다.
+fn copy_position(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+
+    info!("position: {}", Position::from_game(&game_state));
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = "Position written to the log".to_string();
+    }
+}
+
 // ==================== 턴 표시 업데이트 시스템 ====================
-/// 현재 턴을 화면에 표시하는 시스템
+/// 현재 턴(오프닝 중에는 해야 할 일)을 화면에 표시하는 시스템
 fn update_turn_display(
     game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    match_state: Res<MatchState>,
+    rules: Res<BlindRules>,
     mut turn_display: Query<&mut Text, With<TurnDisplay>>,
 ) {
     // 턴 표시 텍스트 컴포넌트 찾기
-    if let Ok(mut text) = turn_display.get_single_mut() {
+    if let Ok(mut text) = turn_display.single_mut() {
         // 게임이 진행 중일 때만 턴 정보 업데이트
-        if !game_state.game_over {
-            **text = format!("This Turn: {}", game_state.current_player.to_korean());
+        let game = game_state.game();
+        if game.is_over() {
+            return;
+        }
+
+        // 오프닝 중에는 현재 단계의 안내 문구 표시
+        if !opening.is_done() {
+            **text = opening.prompt(game);
+            return;
         }
+
+        // 오프닝이나 색 교대로 색이 바뀔 수 있으면 어느 플레이어의 차례인지도 표시
+        let player = game.current_player();
+        let turn = if opening.rule() == OpeningRule::None && !match_state.swap_colors {
+            player.to_korean().to_string()
+        } else {
+            format!("{} ({})", player.to_korean(), opening.player_of(player))
+        };
+        let clocks = format!(
+            "Time: Black {} / White {}",
+            format_clock(match_state.clocks[StoneColor::Black.index()]),
+            format_clock(match_state.clocks[StoneColor::White.index()])
+        );
+        **text = match rules.collision {
+            // 경고 규칙일 때는 현재 경고 횟수도 표시
+            CollisionRule::Strikes { limit } => format!(
+                "This Turn: {} (strikes {}/{})\n{}",
+                turn,
+                game_state.strikes(player),
+                limit,
+                clocks
+            ),
+            _ => format!("This Turn: {}\n{}", turn, clocks),
+        };
     }
 }
 
-// ==================== 승부 판정 함수 ====================
-/// 5목이 완성되었는지 확인하는 함수
-///
-/// # 매개변수
-/// * `board` - 현재 게임판 상태
-/// * `x`, `y` - 마지막에 놓인 돌의 위치
-/// * `color` - 확인할 돌의 색상
-///
-/// # 반환값
-/// * `bool` - 5목이 완성되었으면 true, 아니면 false
-fn check_win(
-    board: &[[Option<StoneColor>; BOARD_SIZE]; BOARD_SIZE],
-    x: usize,
-    y: usize,
-    color: StoneColor,
-) -> bool {
-    // 확인할 4가지 방향: 가로, 세로, 대각선 2개
-    let directions = [
-        (1, 0),  // 가로 (→)
-        (0, 1),  // 세로 (↑)
-        (1, 1),  // 대각선 (↗)
-        (1, -1), // 대각선 (↘)
-    ];
+// ==================== 규칙 선택 시스템 ====================
+/// C 키로 충돌 규칙을 바꾸는 시스템 (첫 수를 두기 전에만 가능)
+fn cycle_collision_rule(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut rules: ResMut<BlindRules>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+
+    // 이미 돌이 놓였으면 규칙을 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    rules.collision = rules.collision.next();
+}
+
+/// V 키로 오목 규칙을 바꾸는 시스템 (첫 수를 두기 전에만 가능)
+fn cycle_rule_set(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    board_config: Res<BoardConfig>,
+    mut config: ResMut<GameConfig>,
+    mut game_state: ResMut<GameState>,
+    mut opening: ResMut<OpeningState>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyV) {
+        return;
+    }
+
+    // 이미 돌이 놓였으면 규칙을 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    config.rule_set = config.rule_set.next();
+    **game_state = config.new_game(&board_config);
+    **opening = config.new_opening(opening.first_player());
+}
 
-    // 각 방향에 대해 5목 여부 확인
-    for &(dx, dy) in &directions {
-        let mut count = 1; // 현재 놓은 돌 포함하여 카운트 시작
-
-        // 한 방향으로 연속된 돌 개수 세기
-        let mut nx = x as i32 + dx;
-        let mut ny = y as i32 + dy;
-        while nx >= 0 && nx < BOARD_SIZE as i32 && ny >= 0 && ny < BOARD_SIZE as i32 {
-            if board[nx as usize][ny as usize] == Some(color) {
-                count += 1;
-                nx += dx; // 다음 위치로 이동
-                ny += dy;
+/// O 키로 오프닝 규칙을 바꾸는 시스템 (첫 수를 두기 전에만 가능)
+fn cycle_opening_rule(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut config: ResMut<GameConfig>,
+    mut opening: ResMut<OpeningState>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyO) {
+        return;
+    }
+
+    // 이미 돌이 놓였으면 규칙을 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    config.opening = config.opening.next();
+    **opening = config.new_opening(opening.first_player());
+}
+
+/// D 키로 조기 무승부 판정을 켜고 끄는 시스템 (첫 수를 두기 전에만 가능)
+fn toggle_early_draw(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    board_config: Res<BoardConfig>,
+    mut config: ResMut<GameConfig>,
+    mut game_state: ResMut<GameState>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyD) {
+        return;
+    }
+
+    // 이미 돌이 놓였으면 설정을 바꿀 수 없음
+    let game = game_state.game();
+    if !game.board().is_empty() || game.is_over() {
+        return;
+    }
+
+    config.early_draw = !config.early_draw;
+    **game_state = config.new_game(&board_config);
+}
+
+/// F 키로 금수 표시를 켜고 끄는 시스템
+fn toggle_forbidden_marks(keyboard: Res<ButtonInput<KeyCode>>, mut config: ResMut<GameConfig>) {
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        config.show_forbidden = !config.show_forbidden;
+    }
+}
+
+/// K 키로 정석 후보 표시를 켜고 끄는 시스템
+fn toggle_book_marks(keyboard: Res<ButtonInput<KeyCode>>, mut config: ResMut<GameConfig>) {
+    if keyboard.just_pressed(KeyCode::KeyK) {
+        config.show_book = !config.show_book;
+    }
+}
+
+/// 게임 설명 및 규칙 UI를 현재 설정에 맞게 갱신하는 시스템
+fn update_rules_display(
+    rules: Res<BlindRules>,
+    config: Res<BoardConfig>,
+    game_config: Res<GameConfig>,
+    match_state: Res<MatchState>,
+    ai: Res<AiPlayer>,
+    mut rules_display: Query<&mut Text, With<RulesDisplay>>,
+) {
+    if let Ok(mut text) = rules_display.single_mut() {
+        **text = format!(
+            "마우스 클릭으로 돌을 놓으세요 (상대 돌은 보이지 않습니다)\n\
+             {} [C: change]\n\
+             Rules: {} [V: change, F: forbidden marks {}]\n\
+             Opening: {} [O: change], Early draw: {} [D: toggle]\n\
+             Undo: {} [U: toggle, Z: undo, Y: redo]\n\
+             Match: Player 1 {} - {} Player 2, draws {} [R: new game, X: swap colors {}]\n\
+             Record: [E: save to {}/, L: load latest, I: replay latest, J: load RenLib library, P: log position]\n\
+             AI: {} ({}, {}) [A: change, M: strategy, G: solve, H: hint, K: book {}]\n\
+             Board: {}x{} [-/=: width, [/]: height]",
+            rules.collision.description(),
+            game_config.rule_set,
+            if game_config.show_forbidden {
+                "on"
             } else {
-                break; // 다른 색 돌이나 빈 공간을 만나면 중단
-            }
+                "off"
+            },
+            game_config.opening,
+            if game_config.early_draw { "on" } else { "off" },
+            if game_config.allow_undo { "on" } else { "off" },
+            match_state.wins[Player::First.index()],
+            match_state.wins[Player::Second.index()],
+            match_state.draws,
+            if match_state.swap_colors { "on" } else { "off" },
+            RECORD_DIR,
+            ai.color
+                .map_or("off".to_string(), |color| color.to_string()),
+            game_config.difficulty,
+            ai.strategy,
+            if game_config.show_book { "on" } else { "off" },
+            config.width,
+            config.height
+        );
+    }
+}
+
+// ==================== 금수 표시 시스템 ====================
+/// 화면에 흑의 시야를 보여 줄 때 흑의 금수 자리를 판에 표시하는 시스템
+///
+/// 블라인드 규칙에서는 착수할 때와 같이 흑이 알고 있는 돌만으로 금수를 계산하므로,
+/// 표시한 자리는 항상 거부되고 표시하지 않은 빈 자리는 금수 때문에 거부되지 않습니다.
+fn update_forbidden_marks(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    config: Res<GameConfig>,
+    board_config: Res<BoardConfig>,
+    viewer: Res<Viewer>,
+    marks: Query<Entity, With<ForbiddenMark>>,
+) {
+    // 이전 표시 제거
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+
+    let game = game_state.game();
+    let player = StoneColor::Black;
+    if !config.show_forbidden || game.is_over() || viewer.color != Some(player) {
+        return;
+    }
+
+    let known = game_state.view(player).board();
+    for (x, y) in known.points() {
+        if known.get(x, y).is_some() || game.rule_set().forbidden(known, x, y, player).is_none() {
+            continue;
         }
 
-        // 반대 방향으로 연속된 돌 개수 세기
-        nx = x as i32 - dx;
-        ny = y as i32 - dy;
-        while nx >= 0 && nx < BOARD_SIZE as i32 && ny >= 0 && ny < BOARD_SIZE as i32 {
-            if board[nx as usize][ny as usize] == Some(color) {
-                count += 1;
-                nx -= dx; // 반대 방향으로 이동
-                ny -= dy;
-            } else {
-                break; // 다른 색 돌이나 빈 공간을 만나면 중단
-            }
+        // 작은 마름모로 금수 자리 표시
+        commands.spawn((
+            Sprite::from_color(FORBIDDEN_COLOR, Vec2::ONE),
+            Transform {
+                translation: board_config.grid_to_world(x, y).extend(0.5), // 화점보다 위, 돌보다 아래
+                rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+                scale: Vec3::splat(10.0),
+            },
+            ForbiddenMark,
+        ));
+    }
+}
+
+// ==================== 정석 표시 시스템 ====================
+/// 화면에 시야를 보여 주는 플레이어의 차례일 때 정석 후보와 고를 비율을 판에 표시하는 시스템
+///
+/// 그 플레이어에게 공개된 돌만으로 정석을 찾으므로 보이지 않는 돌에 대한 정보가 새어 나가지 않습니다.
+fn update_book_marks(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    viewer: Res<Viewer>,
+    config: Res<GameConfig>,
+    board_config: Res<BoardConfig>,
+    book: BookLookup,
+    marks: Query<Entity, With<BookMark>>,
+) {
+    // 이전 표시 제거
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+
+    let game = game_state.game();
+    let Some(book) = book.get().filter(|_| config.show_book && !game.is_over()) else {
+        return;
+    };
+
+    let player = game.current_player();
+    if viewer.color != Some(player) {
+        return;
+    }
+    let moves = book.lookup(game_state.view(player).board(), player);
+    let total: u64 = moves.iter().map(|m| m.weight as u64).sum();
+    for m in moves.iter().filter(|_| total > 0) {
+        // 후보 자리에 고를 비율(%) 표시
+        commands.spawn((
+            Text2d::new(format!("{}%", m.weight as u64 * 100 / total)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(BOOK_MARK_COLOR),
+            Transform::from_translation(board_config.grid_to_world(m.x, m.y).extend(0.5)), // 화점보다 위, 돌보다 아래
+            BookMark,
+        ));
+    }
+}
+
+// ==================== 라이브러리 표시 시스템 ====================
+/// 현재 게임의 수순을 라이브러리에서 따라가 다음 변화와 설명을 표시하는 시스템
+///
+/// 변화는 라이브러리의 판 글자로 (없으면 `A`부터 차례로) 표시하고, 표시한 수는 다른 색으로 보여 줍니다.
+/// 수순을 따라가려면 실제 수를 모두 알아야 하므로 화면에 시야를 보여 주는 플레이어에게
+/// 보이지 않는 돌이 있으면 표시하지 않습니다.
+fn update_library_marks(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    viewer: Res<Viewer>,
+    board_config: Res<BoardConfig>,
+    library: Res<LibraryState>,
+    marks: Query<Entity, With<LibraryMark>>,
+    mut comment_display: Query<&mut Text, With<LibraryCommentDisplay>>,
+) {
+    // 이전 표시 제거
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+    let Ok(mut comment) = comment_display.single_mut() else {
+        return;
+    };
+    comment.clear();
+
+    let game = game_state.game();
+    let Some(library) = library.0.as_ref().filter(|_| {
+        (board_config.width, board_config.height) == (LIBRARY_SIZE, LIBRARY_SIZE)
+            && viewer
+                .color
+                .is_some_and(|color| game_state.hidden_stones(color) == 0)
+    }) else {
+        return;
+    };
+    let Some(node) = library.find(game.history()) else {
+        return;
+    };
+
+    if let Some(text) = &library.node(node).comment {
+        **comment = text.clone();
+    }
+    let children = library
+        .children(node)
+        .filter_map(|(_, child)| child.stone.map(|stone| (stone, child)));
+    for (index, (stone, child)) in children.enumerate() {
+        let label = child
+            .board_text
+            .clone()
+            .unwrap_or_else(|| char::from(b'A' + (index % 26) as u8).to_string());
+        let color = if child.marked {
+            LIBRARY_MARKED_COLOR
+        } else {
+            LIBRARY_MARK_COLOR
+        };
+        commands.spawn((
+            Text2d::new(label),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(color),
+            Transform::from_translation(
+                board_config.grid_to_world(stone.x, stone.y).extend(0.5), // 화점보다 위, 돌보다 아래
+            ),
+            LibraryMark,
+        ));
+    }
+}
+
+// ==================== 수 표시 시스템 ====================
+/// 마지막 수와 승리한 줄을 판에 표시하는 시스템
+///
+/// 블라인드 규칙에서는 화면에 시야를 보여 주는 플레이어가 알고 있는 돌 중 가장 최근 수에만
+/// 표시하므로 상대가 방금 둔 자리가 새어 나가지 않습니다. 게임이 끝나면 실제 마지막 수에 표시합니다.
+fn update_move_marks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    game_state: Res<GameState>,
+    config: Res<BoardConfig>,
+    viewer: Res<Viewer>,
+    marks: Query<Entity, With<MoveMark>>,
+) {
+    // 이전 표시 제거
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+
+    let game = game_state.game();
+    let Some(color) = viewer.color else {
+        return;
+    };
+    let view = game_state.view(color);
+    let last_known = game
+        .history()
+        .iter()
+        .rev()
+        .find(|stone| game.is_over() || view.knows(stone.x, stone.y));
+
+    // 마지막 수 위에 작은 점 표시
+    if let Some(stone) = last_known {
+        commands.spawn((
+            Mesh2d(meshes.add(Circle::default())),
+            MeshMaterial2d(materials.add(LAST_MOVE_COLOR)),
+            Transform {
+                translation: config.grid_to_world(stone.x, stone.y).extend(2.0), // 돌보다 위
+                scale: Vec3::splat(STONE_RADIUS * 0.6),
+                ..default()
+            },
+            MoveMark,
+        ));
+    }
+
+    // 승리한 줄의 양 끝을 잇는 선 표시
+    if let (Some(&(x1, y1)), Some(&(x2, y2))) =
+        (game.winning_line().first(), game.winning_line().last())
+    {
+        let start = config.grid_to_world(x1, y1);
+        let end = config.grid_to_world(x2, y2);
+        let delta = end - start;
+        commands.spawn((
+            Sprite::from_color(
+                WIN_LINE_COLOR,
+                Vec2::new(delta.length() + GRID_SIZE, WIN_LINE_WIDTH),
+            ),
+            Transform {
+                translation: ((start + end) / 2.0).extend(1.5), // 돌 위, 마지막 수 표시 아래
+                rotation: Quat::from_rotation_z(delta.y.atan2(delta.x)),
+                ..default()
+            },
+            MoveMark,
+        ));
+    }
+}
+
+// ==================== 블라인드 피드백 표시 시스템 ====================
+/// 가장 최근의 피드백 이벤트를 화면에 시야를 보여 주는 플레이어의 시점으로 표시하는 시스템
+///
+/// 상대의 피드백에는 좌표가 나오지 않으며, 시야가 바뀌면 같은 피드백도 새 플레이어의 시점으로
+/// 다시 적습니다. 넘겨주기 화면에서는 비우고, 게임이 끝나면 모든 돌이 공개되므로 좌표까지 보여 줍니다.
+fn update_feedback_display(
+    mut events: EventReader<BlindFeedback>,
+    mut opening_events: EventReader<OpeningFeedback>,
+    game_state: Res<GameState>,
+    viewer: Res<Viewer>,
+    mut last: Local<Option<Feedback>>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    // 이번 프레임의 마지막 피드백만 표시
+    let received = events.read().last().map(|event| event.0);
+    let opening = opening_events.read().last().map(|event| event.to_string());
+    if received.is_some() || opening.is_some() {
+        *last = received;
+    }
+    let message = if received.is_some() || viewer.is_changed() {
+        last.map(|feedback| match viewer.color {
+            _ if game_state.game().is_over() => feedback.message(),
+            Some(color) => feedback.message_for(color),
+            None => String::new(),
+        })
+    } else {
+        None
+    };
+    if let Some(message) = message.or(opening) {
+        if let Ok(mut text) = feedback_display.single_mut() {
+            **text = message;
         }
+    }
+}
 
-        // 5개 이상 연속이면 승리
-        if count >= 5 {
-            return true;
+// ==================== 오프닝 표시 시스템 ====================
+/// 오프닝의 착수 제한 구역과 5수 후보를 판에 표시하는 시스템
+fn update_opening_marks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    config: Res<BoardConfig>,
+    marks: Query<Entity, With<OpeningMark>>,
+) {
+    // 이전 표시 제거
+    for entity in &marks {
+        commands.entity(entity).despawn();
+    }
+
+    // 착수 제한 구역을 반투명 사각형으로 표시
+    if let Some(area) = opening.area() {
+        let (min_x, min_y, max_x, max_y) = opening::area_bounds(game_state.game().board(), area);
+        let min = config.grid_to_world(min_x, min_y) - Vec2::splat(GRID_SIZE / 2.0);
+        let max = config.grid_to_world(max_x, max_y) + Vec2::splat(GRID_SIZE / 2.0);
+        commands.spawn((
+            Sprite::from_color(OPENING_AREA_COLOR, Vec2::ONE),
+            Transform {
+                translation: ((min + max) / 2.0).extend(0.05), // 격자선 바로 위
+                scale: (max - min).extend(1.0),
+                ..default()
+            },
+            OpeningMark,
+        ));
+    }
+
+    // 5수 후보를 반투명 흑돌로 표시
+    for &(x, y) in opening.candidates() {
+        commands.spawn((
+            Mesh2d(meshes.add(Circle::default())),
+            MeshMaterial2d(materials.add(BLACK_STONE_COLOR.with_alpha(CANDIDATE_ALPHA))),
+            Transform {
+                translation: config.grid_to_world(x, y).extend(1.0),
+                scale: Vec3::splat(STONE_RADIUS * 2.0),
+                ..default()
+            },
+            OpeningMark,
+        ));
+    }
+}
+
+// ==================== 돌 표시 시스템 ====================
+/// 화면에 보여 줄 시야를 정하는 시스템
+///
+/// AI와 대국할 때는 차례와 관계없이 항상 사람의 시야를 보여 주므로 AI가 생각하는 동안에도
+/// AI의 시야가 드러나지 않습니다. 사람끼리 대국할 때는 차례가 넘어가면 넘겨주기 화면으로 판을 가리고,
+/// 다음 플레이어가 Space 키를 누르면 그 플레이어의 시야를 보여 줍니다.
+/// 기보를 재생하거나 게임이 끝나면 모든 돌이 공개되므로 가리지 않습니다.
+fn update_viewer(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    ai: Res<AiPlayer>,
+    replay: Res<ReplayState>,
+    mut viewer: ResMut<Viewer>,
+    screens: Query<Entity, With<HandOverDisplay>>,
+) {
+    let game = game_state.game();
+    let player = game.current_player();
+    let color = if replay.is_active() || game.is_over() {
+        Some(player)
+    } else if let Some(ai_color) = ai.color {
+        Some(ai_color.opposite())
+    } else {
+        if viewer.seat != player && keyboard.just_pressed(KeyCode::Space) {
+            viewer.seat = player;
         }
+        (viewer.seat == player).then_some(player)
+    };
+    if viewer.color == color {
+        return;
     }
+    viewer.color = color;
 
-    false // 어떤 방향으로도 5목이 완성되지 않음
+    for entity in &screens {
+        commands.entity(entity).despawn();
+    }
+    if color.is_none() {
+        // 판과 다른 UI를 모두 가리는 넘겨주기 화면
+        commands
+            .spawn((
+                HandOverDisplay,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(BACKGROUND_COLOR),
+                GlobalZIndex(1),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(format!(
+                        "Pass the board to {}\nPress Space when ready",
+                        player.to_korean()
+                    )),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ));
+            });
+    }
+}
+
+/// 화면에 시야를 보여 주는 플레이어가 아는 돌만 보이고 나머지는 숨기는 시스템
+///
+/// 게임이 끝나면 실제 판의 모든 돌을 공개하며, 넘겨주기 화면에서는 모든 돌을 숨깁니다.
+fn update_stone_visibility(
+    game_state: Res<GameState>,
+    viewer: Res<Viewer>,
+    mut stones: Query<(&Stone, &mut Visibility)>,
+) {
+    let game_over = game_state.game().is_over();
+    let view = viewer.color.map(|color| game_state.view(color));
+    for (stone, mut visibility) in &mut stones {
+        let (x, y) = stone.grid_pos;
+        let known = view.is_some_and(|view| view.knows(x, y));
+        *visibility = if game_over || known {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
 }
@@ -0,0 +1,842 @@
+//! 대회용 오프닝 규칙 (Swap, Swap2, Soosõrv-8, Taraguchi-10)
+//!
+//! 본 게임 전에 진행되는 오프닝 단계를 상태 기계로 구현합니다.
+//! 돌의 색은 게임 순서(흑, 백, 흑, ...)를 그대로 따르며, 오프닝은
+//! 어느 플레이어가 돌을 놓고 색을 고르는지와 착수 가능 구역만 관리합니다.
+//! Bevy에 의존하지 않습니다.
+
+use std::fmt;
+
+use super::engine::{Board, Game, MoveError, StoneColor};
+
+/// Soosõrv-8에서 선언할 수 있는 5수 후보의 최대 개수
+const SOOSORV_MAX_CANDIDATES: usize = 8;
+
+/// Taraguchi-10에서 한꺼번에 놓는 5수 후보의 개수
+const TARAGUCHI_CANDIDATES: usize = 10;
+
+// ==================== 오프닝 규칙 ====================
+/// 게임 시작 전에 적용할 오프닝 규칙
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum OpeningRule {
+    /// 오프닝 없음 (첫 번째 플레이어가 흑으로 바로 시작)
+    #[default]
+    None,
+    /// 첫 번째 플레이어가 3수(흑, 백, 흑)를 두고 두 번째 플레이어가 색을 고름
+    Swap,
+    /// Swap과 같지만 두 번째 플레이어가 2수를 더 두고 색 선택을 넘길 수 있음
+    Swap2,
+    /// 중앙 제한 구역 3수, 교대, 4수와 5수 후보 개수 선언, 교대, 5수 후보 중 선택
+    Soosorv8,
+    /// 1~5수를 점점 넓어지는 중앙 구역에 두며 매 수마다 교대 가능
+    Taraguchi10,
+}
+
+impl OpeningRule {
+    /// 다음 오프닝 규칙 반환 (규칙 선택 키에서 사용)
+    pub fn next(self) -> Self {
+        match self {
+            OpeningRule::None => OpeningRule::Swap,
+            OpeningRule::Swap => OpeningRule::Swap2,
+            OpeningRule::Swap2 => OpeningRule::Soosorv8,
+            OpeningRule::Soosorv8 => OpeningRule::Taraguchi10,
+            OpeningRule::Taraguchi10 => OpeningRule::None,
+        }
+    }
+}
+
+impl fmt::Display for OpeningRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpeningRule::None => write!(f, "None"),
+            OpeningRule::Swap => write!(f, "Swap"),
+            OpeningRule::Swap2 => write!(f, "Swap2"),
+            OpeningRule::Soosorv8 => write!(f, "Soosorv-8"),
+            OpeningRule::Taraguchi10 => write!(f, "Taraguchi-10"),
+        }
+    }
+}
+
+impl std::str::FromStr for OpeningRule {
+    type Err = String;
+
+    /// 표시 이름을 대소문자와 `-` 구분 없이 읽음 (예: `swap2`, `soosorv8`)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let normalize = |name: &str| name.replace('-', "").to_ascii_lowercase();
+        let mut rule = OpeningRule::default();
+        loop {
+            if normalize(&rule.to_string()) == normalize(text) {
+                return Ok(rule);
+            }
+            rule = rule.next();
+            if rule == OpeningRule::default() {
+                return Err(format!("unknown opening rule \"{text}\""));
+            }
+        }
+    }
+}
+
+// ==================== 플레이어 ====================
+/// 색과 무관한 플레이어 구분 (오프닝에서 색이 바뀔 수 있음)
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Player {
+    #[default]
+    First, // 첫 번째 플레이어
+    Second, // 두 번째 플레이어
+}
+
+impl Player {
+    /// 상대 플레이어 반환
+    pub fn opposite(self) -> Self {
+        match self {
+            Player::First => Player::Second,
+            Player::Second => Player::First,
+        }
+    }
+
+    /// 플레이어별 배열에서 사용할 인덱스 반환
+    pub fn index(self) -> usize {
+        match self {
+            Player::First => 0,
+            Player::Second => 1,
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Player::First => write!(f, "Player 1"),
+            Player::Second => write!(f, "Player 2"),
+        }
+    }
+}
+
+// ==================== 오프닝 단계 ====================
+/// 오프닝 상태 기계의 현재 단계
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    /// `player`가 다음 수(색은 게임 순서대로)를 둠, `area`가 있으면 중앙 `area`x`area` 안에만 둘 수 있음
+    Place { player: Player, area: Option<usize> },
+    /// `player`가 자신이 잡을 색을 고름 (교대 여부 결정)
+    ChooseColor { player: Player },
+    /// Swap2: `player`가 흑, 백 또는 2수 추가 중에서 고름
+    Swap2Choice { player: Player },
+    /// Soosõrv: `player`가 5수 후보의 개수를 선언
+    DeclareCount { player: Player },
+    /// `player`가 5수 후보 `count`개를 놓음
+    PlaceCandidates { player: Player, count: usize },
+    /// `player`가 5수 후보 중 하나를 고름
+    ChooseCandidate { player: Player },
+    /// Taraguchi: `player`가 5수를 하나만 둘지(9x9 안) 후보 10개를 둘지 고름
+    TaraguchiFifth { player: Player },
+    /// 오프닝 종료 (본 게임 진행)
+    Done,
+}
+
+/// 오프닝 단계에서 허용되지 않는 동작
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OpeningError {
+    /// 현재 단계에서 할 수 없는 동작
+    WrongPhase,
+    /// 제한 구역 밖의 좌표
+    OutsideArea(usize),
+    /// 이미 고른 5수 후보와 같은 자리
+    DuplicateCandidate,
+    /// 5수 후보가 아닌 자리
+    NotCandidate,
+    /// 선언할 수 없는 후보 개수
+    InvalidCount,
+    /// 엔진이 거부한 착수
+    Move(MoveError),
+}
+
+impl fmt::Display for OpeningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpeningError::WrongPhase => write!(f, "not allowed in this phase of the opening"),
+            OpeningError::OutsideArea(area) => {
+                write!(f, "the move must be inside the central {area}x{area}")
+            }
+            OpeningError::DuplicateCandidate => write!(f, "that candidate is already placed"),
+            OpeningError::NotCandidate => write!(f, "that point is not a candidate"),
+            OpeningError::InvalidCount => write!(
+                f,
+                "the count must be between 1 and {SOOSORV_MAX_CANDIDATES}"
+            ),
+            OpeningError::Move(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for OpeningError {}
+
+impl From<MoveError> for OpeningError {
+    fn from(err: MoveError) -> Self {
+        OpeningError::Move(err)
+    }
+}
+
+// ==================== 오프닝 상태 ====================
+/// 진행 중인 오프닝의 상태
+#[derive(Clone, Debug)]
+pub struct Opening {
+    rule: OpeningRule,               // 적용 중인 오프닝 규칙
+    phase: Phase,                    // 현재 단계
+    first: Player,                   // 오프닝을 시작하는 (잠정 흑) 플레이어
+    black: Player,                   // 현재 흑을 잡은 플레이어
+    candidates: Vec<(usize, usize)>, // 놓인 5수 후보
+    declared: usize,                 // Soosõrv에서 선언된 5수 후보 개수
+    extended: bool,                  // Swap2에서 2수를 추가했는지 여부
+    stones: usize,                   // 오프닝 중에 판에 놓인 돌의 수
+}
+
+impl Default for Opening {
+    fn default() -> Self {
+        Self::new(OpeningRule::default())
+    }
+}
+
+impl Opening {
+    /// 첫 번째 플레이어가 시작하는 오프닝 (빈 판 기준)
+    pub fn new(rule: OpeningRule) -> Self {
+        Self::with_first_player(rule, Player::First)
+    }
+
+    /// `first` 플레이어가 잠정 흑으로 시작하는 오프닝 (빈 판 기준)
+    ///
+    /// 연속 대국에서 게임마다 먼저 두는 플레이어를 바꿀 때 사용합니다.
+    pub fn with_first_player(rule: OpeningRule, first: Player) -> Self {
+        let phase = match rule {
+            OpeningRule::None => Phase::Done,
+            OpeningRule::Swap | OpeningRule::Swap2 => Phase::Place {
+                player: first,
+                area: None,
+            },
+            // 첫 수는 반드시 정중앙
+            OpeningRule::Soosorv8 | OpeningRule::Taraguchi10 => Phase::Place {
+                player: first,
+                area: Some(1),
+            },
+        };
+        Self {
+            rule,
+            phase,
+            first,
+            black: first,
+            candidates: Vec::new(),
+            declared: 0,
+            extended: false,
+            stones: 0,
+        }
+    }
+
+    /// 적용 중인 오프닝 규칙
+    pub fn rule(&self) -> OpeningRule {
+        self.rule
+    }
+
+    /// 현재 단계
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// 오프닝이 끝났는지 확인
+    pub fn is_done(&self) -> bool {
+        self.phase == Phase::Done
+    }
+
+    /// 오프닝을 시작한 (잠정 흑) 플레이어
+    pub fn first_player(&self) -> Player {
+        self.first
+    }
+
+    /// 현재 흑을 잡은 플레이어
+    pub fn black_player(&self) -> Player {
+        self.black
+    }
+
+    /// 해당 색을 잡은 플레이어
+    pub fn player_of(&self, color: StoneColor) -> Player {
+        match color {
+            StoneColor::Black => self.black,
+            StoneColor::White => self.black.opposite(),
+        }
+    }
+
+    /// 해당 플레이어가 잡은 색
+    pub fn color_of(&self, player: Player) -> StoneColor {
+        if player == self.black {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        }
+    }
+
+    /// 오프닝 중에 판에 놓인 돌의 수 (이 수들은 무를 수 없음)
+    pub fn stones(&self) -> usize {
+        self.stones
+    }
+
+    /// 놓인 5수 후보
+    pub fn candidates(&self) -> &[(usize, usize)] {
+        &self.candidates
+    }
+
+    /// 현재 단계에서 착수가 제한된 중앙 구역의 크기
+    pub fn area(&self) -> Option<usize> {
+        match self.phase {
+            Phase::Place { area, .. } => area,
+            _ => None,
+        }
+    }
+
+    /// 현재 단계에서 해야 할 일을 설명하는 문구
+    pub fn prompt(&self, game: &Game) -> String {
+        let color = game.current_player();
+        let number = game.history().len() + 1;
+        match self.phase {
+            Phase::Place { player, area } => {
+                let area = area
+                    .map(|area| format!(" inside the central {area}x{area}"))
+                    .unwrap_or_default();
+                format!("{player}: place move {number} ({color}){area}")
+            }
+            Phase::ChooseColor { player } => {
+                format!("{player}: choose your color [B: Black, W: White]")
+            }
+            Phase::Swap2Choice { player } => {
+                format!("{player}: choose [B: Black, W: White, T: place two more]")
+            }
+            Phase::DeclareCount { player } => format!(
+                "{player}: declare the number of 5th moves [1-{SOOSORV_MAX_CANDIDATES}]"
+            ),
+            Phase::PlaceCandidates { player, count } => format!(
+                "{player}: place 5th move candidates ({}/{count})",
+                self.candidates.len()
+            ),
+            Phase::ChooseCandidate { player } => {
+                format!("{player}: choose one of the 5th move candidates")
+            }
+            Phase::TaraguchiFifth { player } => format!(
+                "{player}: [S: one 5th move inside 9x9, T: {TARAGUCHI_CANDIDATES} 5th moves anywhere]"
+            ),
+            Phase::Done => format!("This Turn: {} ({})", color, self.player_of(color)),
+        }
+    }
+
+    /// 현재 단계에 맞게 (x, y)를 클릭했을 때의 처리
+    ///
+    /// 돌 놓기, 5수 후보 놓기, 5수 후보 고르기 단계에서만 사용할 수 있습니다.
+    pub fn place(&mut self, game: &mut Game, x: usize, y: usize) -> Result<(), OpeningError> {
+        match self.phase {
+            Phase::Place { area, .. } => {
+                if let Some(area) = area {
+                    if !in_area(game.board(), x, y, area) {
+                        return Err(OpeningError::OutsideArea(area));
+                    }
+                }
+                game.play(x, y)?;
+                self.stones += 1;
+                self.after_stone(game);
+                Ok(())
+            }
+            Phase::PlaceCandidates { count, .. } => {
+                game.check_move(x, y)?;
+                if self.candidates.contains(&(x, y)) {
+                    return Err(OpeningError::DuplicateCandidate);
+                }
+                self.candidates.push((x, y));
+                if self.candidates.len() == count {
+                    // 후보는 흑이 놓고, 고르는 것은 백을 잡은 플레이어
+                    self.phase = Phase::ChooseCandidate {
+                        player: self.black.opposite(),
+                    };
+                }
+                Ok(())
+            }
+            Phase::ChooseCandidate { .. } => {
+                if !self.candidates.contains(&(x, y)) {
+                    return Err(OpeningError::NotCandidate);
+                }
+                game.play(x, y)?;
+                self.stones += 1;
+                self.candidates.clear();
+                self.phase = Phase::Done;
+                Ok(())
+            }
+            _ => Err(OpeningError::WrongPhase),
+        }
+    }
+
+    /// 색 선택 단계에서 플레이어가 잡을 색을 고름
+    pub fn choose_color(&mut self, game: &Game, color: StoneColor) -> Result<(), OpeningError> {
+        let player = match self.phase {
+            Phase::ChooseColor { player } | Phase::Swap2Choice { player } => player,
+            _ => return Err(OpeningError::WrongPhase),
+        };
+        self.black = match color {
+            StoneColor::Black => player,
+            StoneColor::White => player.opposite(),
+        };
+        self.after_color_choice(game);
+        Ok(())
+    }
+
+    /// Swap2에서 2수를 더 두고 색 선택을 상대에게 넘김
+    pub fn place_two_more(&mut self) -> Result<(), OpeningError> {
+        let Phase::Swap2Choice { player } = self.phase else {
+            return Err(OpeningError::WrongPhase);
+        };
+        self.extended = true;
+        self.phase = Phase::Place { player, area: None };
+        Ok(())
+    }
+
+    /// Soosõrv에서 5수 후보 개수를 선언
+    pub fn declare_count(&mut self, count: usize) -> Result<(), OpeningError> {
+        let Phase::DeclareCount { player } = self.phase else {
+            return Err(OpeningError::WrongPhase);
+        };
+        if !(1..=SOOSORV_MAX_CANDIDATES).contains(&count) {
+            return Err(OpeningError::InvalidCount);
+        }
+        self.declared = count;
+        // 선언 후 상대가 교대 여부를 결정
+        self.phase = Phase::ChooseColor {
+            player: player.opposite(),
+        };
+        Ok(())
+    }
+
+    /// Taraguchi에서 5수 방식을 고름 (`many`가 참이면 후보 10개)
+    pub fn choose_fifth(&mut self, many: bool) -> Result<(), OpeningError> {
+        let Phase::TaraguchiFifth { player } = self.phase else {
+            return Err(OpeningError::WrongPhase);
+        };
+        self.phase = if many {
+            Phase::PlaceCandidates {
+                player,
+                count: TARAGUCHI_CANDIDATES,
+            }
+        } else {
+            Phase::Place {
+                player,
+                area: Some(9),
+            }
+        };
+        Ok(())
+    }
+
+    /// 돌이 하나 놓인 뒤 다음 단계 결정
+    fn after_stone(&mut self, game: &Game) {
+        let moves = game.history().len();
+        self.phase = match self.rule {
+            OpeningRule::None => Phase::Done,
+            OpeningRule::Swap if moves < 3 => Phase::Place {
+                player: self.first,
+                area: None,
+            },
+            OpeningRule::Swap => Phase::ChooseColor {
+                player: self.first.opposite(),
+            },
+            OpeningRule::Swap2 if !self.extended && moves < 3 => Phase::Place {
+                player: self.first,
+                area: None,
+            },
+            OpeningRule::Swap2 if !self.extended => Phase::Swap2Choice {
+                player: self.first.opposite(),
+            },
+            OpeningRule::Swap2 if moves < 5 => Phase::Place {
+                player: self.first.opposite(),
+                area: None,
+            },
+            OpeningRule::Swap2 => Phase::ChooseColor { player: self.first },
+            // 1~3수는 첫 번째 플레이어가 1x1, 3x3, 5x5 안에 둠
+            OpeningRule::Soosorv8 if moves < 3 => Phase::Place {
+                player: self.first,
+                area: Some(2 * moves + 1),
+            },
+            OpeningRule::Soosorv8 if moves == 3 => Phase::ChooseColor {
+                player: self.first.opposite(),
+            },
+            // 4수를 둔 백이 5수 후보 개수를 선언
+            OpeningRule::Soosorv8 => Phase::DeclareCount {
+                player: self.player_of(StoneColor::White),
+            },
+            // 매 수마다 방금 둔 플레이어의 상대가 교대 여부를 결정
+            OpeningRule::Taraguchi10 => {
+                let mover = game
+                    .last_move()
+                    .map_or(self.first, |last| self.player_of(last.color));
+                Phase::ChooseColor {
+                    player: mover.opposite(),
+                }
+            }
+        };
+    }
+
+    /// 색 선택이 끝난 뒤 다음 단계 결정
+    fn after_color_choice(&mut self, game: &Game) {
+        let moves = game.history().len();
+        self.phase = match self.rule {
+            OpeningRule::Soosorv8 if moves == 3 => Phase::Place {
+                player: self.player_of(StoneColor::White),
+                area: None,
+            },
+            OpeningRule::Soosorv8 => Phase::PlaceCandidates {
+                player: self.black,
+                count: self.declared,
+            },
+            // 다음 수는 그 색을 잡은 플레이어가 한 칸씩 넓어진 구역 안에 둠
+            OpeningRule::Taraguchi10 if moves < 4 => Phase::Place {
+                player: self.player_of(game.current_player()),
+                area: Some(2 * moves + 1),
+            },
+            OpeningRule::Taraguchi10 if moves == 4 => Phase::TaraguchiFifth { player: self.black },
+            _ => Phase::Done,
+        };
+    }
+}
+
+/// (x, y)가 판 중앙의 `area`x`area` 구역 안에 있는지 확인
+fn in_area(board: &Board, x: usize, y: usize, area: usize) -> bool {
+    let (cx, cy) = (board.width() / 2, board.height() / 2);
+    let half = area / 2;
+    x.abs_diff(cx) <= half && y.abs_diff(cy) <= half
+}
+
+/// 판 중앙의 `area`x`area` 구역을 `(최소 x, 최소 y, 최대 x, 최대 y)`로 반환 (판 밖은 잘라냄)
+pub fn area_bounds(board: &Board, area: usize) -> (usize, usize, usize, usize) {
+    let (cx, cy) = (board.width() / 2, board.height() / 2);
+    let half = area / 2;
+    (
+        cx.saturating_sub(half),
+        cy.saturating_sub(half),
+        (cx + half).min(board.width() - 1),
+        (cy + half).min(board.height() - 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 지금 단계에서 행동하는 플레이어 (오프닝이 끝났으면 `None`)
+    fn actor(opening: &Opening) -> Option<Player> {
+        match opening.phase() {
+            Phase::Place { player, .. }
+            | Phase::ChooseColor { player }
+            | Phase::Swap2Choice { player }
+            | Phase::DeclareCount { player }
+            | Phase::PlaceCandidates { player, .. }
+            | Phase::ChooseCandidate { player }
+            | Phase::TaraguchiFifth { player } => Some(player),
+            Phase::Done => None,
+        }
+    }
+
+    #[test]
+    fn swap_lets_the_second_player_choose_after_three_stones() {
+        let mut game = Game::new();
+        let mut opening = Opening::new(OpeningRule::Swap);
+        for (x, y) in [(7, 7), (8, 8), (6, 8)] {
+            assert_eq!(
+                opening.phase(),
+                Phase::Place {
+                    player: Player::First,
+                    area: None
+                }
+            );
+            assert_eq!(
+                opening.choose_color(&game, StoneColor::Black),
+                Err(OpeningError::WrongPhase)
+            );
+            opening.place(&mut game, x, y).unwrap();
+        }
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseColor {
+                player: Player::Second
+            }
+        );
+        assert_eq!(opening.black_player(), Player::First);
+
+        // 두 번째 플레이어가 흑을 골라 교대
+        opening.choose_color(&game, StoneColor::Black).unwrap();
+        assert!(opening.is_done());
+        assert_eq!(opening.black_player(), Player::Second);
+        assert_eq!(opening.player_of(game.current_player()), Player::First);
+        assert_eq!(opening.stones(), 3);
+    }
+
+    #[test]
+    fn swap2_can_hand_the_choice_back_after_two_more_stones() {
+        let mut game = Game::new();
+        let mut opening = Opening::new(OpeningRule::Swap2);
+        for (x, y) in [(7, 7), (8, 8), (6, 8)] {
+            assert_eq!(actor(&opening), Some(Player::First));
+            opening.place(&mut game, x, y).unwrap();
+        }
+        assert_eq!(
+            opening.phase(),
+            Phase::Swap2Choice {
+                player: Player::Second
+            }
+        );
+        assert_eq!(
+            opening.place(&mut game, 9, 9),
+            Err(OpeningError::WrongPhase)
+        );
+
+        // 두 번째 플레이어가 2수(백, 흑)를 더 두고 첫 번째 플레이어가 색을 고름
+        opening.place_two_more().unwrap();
+        assert_eq!(opening.place_two_more(), Err(OpeningError::WrongPhase));
+        for (x, y) in [(9, 9), (5, 9)] {
+            assert_eq!(
+                opening.phase(),
+                Phase::Place {
+                    player: Player::Second,
+                    area: None
+                }
+            );
+            opening.place(&mut game, x, y).unwrap();
+        }
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseColor {
+                player: Player::First
+            }
+        );
+        assert_eq!(opening.black_player(), Player::First);
+
+        opening.choose_color(&game, StoneColor::White).unwrap();
+        assert!(opening.is_done());
+        assert_eq!(opening.black_player(), Player::Second);
+        assert_eq!(game.history().len(), 5);
+        assert_eq!(opening.player_of(game.current_player()), Player::First);
+    }
+
+    #[test]
+    fn swap2_choice_can_take_a_color_right_away() {
+        let mut game = Game::new();
+        let mut opening = Opening::new(OpeningRule::Swap2);
+        for (x, y) in [(7, 7), (8, 8), (6, 8)] {
+            opening.place(&mut game, x, y).unwrap();
+        }
+        opening.choose_color(&game, StoneColor::White).unwrap();
+        assert!(opening.is_done());
+        assert_eq!(opening.black_player(), Player::First);
+        assert_eq!(opening.player_of(game.current_player()), Player::Second);
+    }
+
+    #[test]
+    fn soosorv_walks_areas_count_declaration_and_candidate_choice() {
+        let mut game = Game::new();
+        let mut opening = Opening::new(OpeningRule::Soosorv8);
+
+        // 1~3수는 첫 번째 플레이어가 1x1, 3x3, 5x5 안에 둠
+        for (area, outside, inside) in [
+            (1, (8, 8), (7, 7)),
+            (3, (9, 9), (8, 7)),
+            (5, (10, 7), (9, 9)),
+        ] {
+            assert_eq!(
+                opening.phase(),
+                Phase::Place {
+                    player: Player::First,
+                    area: Some(area)
+                }
+            );
+            assert_eq!(
+                opening.place(&mut game, outside.0, outside.1),
+                Err(OpeningError::OutsideArea(area))
+            );
+            opening.place(&mut game, inside.0, inside.1).unwrap();
+        }
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseColor {
+                player: Player::Second
+            }
+        );
+
+        // 두 번째 플레이어가 흑으로 교대하고, 백이 된 첫 번째 플레이어가 4수를 둠
+        opening.choose_color(&game, StoneColor::Black).unwrap();
+        assert_eq!(opening.black_player(), Player::Second);
+        assert_eq!(
+            opening.phase(),
+            Phase::Place {
+                player: Player::First,
+                area: None
+            }
+        );
+        opening.place(&mut game, 6, 6).unwrap();
+
+        // 4수를 둔 백이 5수 후보 개수를 선언
+        assert_eq!(
+            opening.phase(),
+            Phase::DeclareCount {
+                player: Player::First
+            }
+        );
+        assert_eq!(opening.declare_count(0), Err(OpeningError::InvalidCount));
+        assert_eq!(
+            opening.declare_count(SOOSORV_MAX_CANDIDATES + 1),
+            Err(OpeningError::InvalidCount)
+        );
+        opening.declare_count(2).unwrap();
+
+        // 상대가 교대 여부를 결정: 백을 골라 첫 번째 플레이어가 다시 흑
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseColor {
+                player: Player::Second
+            }
+        );
+        opening.choose_color(&game, StoneColor::White).unwrap();
+        assert_eq!(opening.black_player(), Player::First);
+
+        // 흑이 후보 2개를 놓음
+        assert_eq!(
+            opening.phase(),
+            Phase::PlaceCandidates {
+                player: Player::First,
+                count: 2
+            }
+        );
+        opening.place(&mut game, 5, 5).unwrap();
+        assert_eq!(
+            opening.place(&mut game, 5, 5),
+            Err(OpeningError::DuplicateCandidate)
+        );
+        assert_eq!(
+            opening.place(&mut game, 7, 7),
+            Err(OpeningError::Move(MoveError::Occupied(StoneColor::Black)))
+        );
+        opening.place(&mut game, 10, 10).unwrap();
+        assert_eq!(opening.candidates(), &[(5, 5), (10, 10)]);
+        assert_eq!(game.history().len(), 4);
+
+        // 백이 후보 중 하나를 고름
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseCandidate {
+                player: Player::Second
+            }
+        );
+        assert_eq!(
+            opening.place(&mut game, 3, 3),
+            Err(OpeningError::NotCandidate)
+        );
+        opening.place(&mut game, 10, 10).unwrap();
+        assert!(opening.is_done());
+        assert!(opening.candidates().is_empty());
+        assert_eq!(opening.stones(), 5);
+        assert_eq!(game.board().get(10, 10), Some(StoneColor::Black));
+        assert_eq!(game.board().get(5, 5), None);
+        assert_eq!(opening.player_of(game.current_player()), Player::Second);
+    }
+
+    /// Taraguchi-10을 5수 방식 선택 직전까지 진행 (3수 뒤에 두 번째 플레이어가 흑으로 교대)
+    fn taraguchi_before_fifth(game: &mut Game) -> Opening {
+        let mut opening = Opening::new(OpeningRule::Taraguchi10);
+        // (구역, 둘 플레이어, 둘 자리, 교대 여부를 정하는 플레이어, 고른 색)
+        let steps = [
+            (1, Player::First, (7, 7), Player::Second, StoneColor::White),
+            (3, Player::Second, (8, 8), Player::First, StoneColor::Black),
+            (5, Player::First, (9, 7), Player::Second, StoneColor::Black),
+            (7, Player::First, (4, 7), Player::Second, StoneColor::Black),
+        ];
+        for (area, mover, (x, y), chooser, color) in steps {
+            assert_eq!(
+                opening.phase(),
+                Phase::Place {
+                    player: mover,
+                    area: Some(area)
+                }
+            );
+            assert_eq!(
+                opening.place(game, 7 + area / 2 + 1, 7),
+                Err(OpeningError::OutsideArea(area))
+            );
+            opening.place(game, x, y).unwrap();
+            assert_eq!(opening.phase(), Phase::ChooseColor { player: chooser });
+            opening.choose_color(game, color).unwrap();
+        }
+        assert_eq!(opening.black_player(), Player::Second);
+        assert_eq!(
+            opening.phase(),
+            Phase::TaraguchiFifth {
+                player: Player::Second
+            }
+        );
+        opening
+    }
+
+    #[test]
+    fn taraguchi_single_fifth_must_be_inside_nine_by_nine() {
+        let mut game = Game::new();
+        let mut opening = taraguchi_before_fifth(&mut game);
+        opening.choose_fifth(false).unwrap();
+        assert_eq!(
+            opening.phase(),
+            Phase::Place {
+                player: Player::Second,
+                area: Some(9)
+            }
+        );
+        assert_eq!(
+            opening.place(&mut game, 12, 7),
+            Err(OpeningError::OutsideArea(9))
+        );
+        opening.place(&mut game, 11, 7).unwrap();
+
+        // 5수 뒤에도 상대가 교대 여부를 결정하고 오프닝이 끝남
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseColor {
+                player: Player::First
+            }
+        );
+        opening.choose_color(&game, StoneColor::Black).unwrap();
+        assert!(opening.is_done());
+        assert_eq!(opening.black_player(), Player::First);
+        assert_eq!(opening.stones(), 5);
+    }
+
+    #[test]
+    fn taraguchi_ten_fifths_may_be_placed_anywhere() {
+        let mut game = Game::new();
+        let mut opening = taraguchi_before_fifth(&mut game);
+        assert_eq!(opening.choose_fifth(true), Ok(()));
+        assert_eq!(opening.choose_fifth(true), Err(OpeningError::WrongPhase));
+        for y in 0..TARAGUCHI_CANDIDATES {
+            assert_eq!(
+                opening.phase(),
+                Phase::PlaceCandidates {
+                    player: Player::Second,
+                    count: TARAGUCHI_CANDIDATES
+                }
+            );
+            opening.place(&mut game, 0, y).unwrap();
+        }
+        assert_eq!(game.history().len(), 4);
+
+        // 백을 잡은 첫 번째 플레이어가 후보 중 하나를 고름
+        assert_eq!(
+            opening.phase(),
+            Phase::ChooseCandidate {
+                player: Player::First
+            }
+        );
+        assert_eq!(
+            opening.place(&mut game, 14, 14),
+            Err(OpeningError::NotCandidate)
+        );
+        opening.place(&mut game, 0, 3).unwrap();
+        assert!(opening.is_done());
+        assert_eq!(opening.black_player(), Player::Second);
+        assert_eq!(game.board().get(0, 3), Some(StoneColor::Black));
+    }
+}
@@ -0,0 +1,306 @@
+//! 국면 문자열
+//!
+//! 판 하나를 체스의 FEN처럼 한 줄로 적어 주고받습니다. 버그 제보나 디버깅에서 국면을
+//! 그대로 옮기는 용도이며, 수순은 담지 않습니다. Bevy에 의존하지 않습니다.
+//!
+//! ```text
+//! 15x15 15/15/15/15/15/15/6X8/7Xo6/8O6/15/15/15/15/15/15 b renju
+//! ```
+//!
+//! 공백으로 나눈 네 항목을 차례로 적습니다.
+//!
+//! - 판 크기: `<가로>x<세로>`
+//! - 돌: 맨 위 행부터 `/`로 나눈 행들. 각 행은 왼쪽(x = 0)부터 적으며, 숫자는 빈 칸의 개수입니다.
+//!   `X`/`O`는 양쪽 모두 아는 흑/백 돌, `x`/`o`는 놓은 플레이어만 아는 흑/백 돌입니다 (블라인드 시야).
+//! - 둘 차례: `b`(흑) 또는 `w`(백)
+//! - 규칙: `freestyle`, `standard`, `renju`, `caro` (대소문자 구분 없음)
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::blind::BlindGame;
+use super::engine::{Board, Game, StoneColor, MAX_BOARD_SIZE};
+use super::rules::RuleSet;
+
+// ==================== 오류 ====================
+/// 국면 문자열을 읽을 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PositionError {
+    /// 항목 개수가 4개가 아님 (읽은 개수)
+    FieldCount(usize),
+    /// 읽을 수 없거나 지원하지 않는 판 크기
+    InvalidSize(String),
+    /// 행 개수가 판 세로 크기와 다름 (읽은 개수)
+    RowCount(usize),
+    /// 모르는 글자가 있거나 칸 수가 판 가로 크기와 다른 행 (위에서부터 센 번호, 1부터)
+    InvalidRow(usize),
+    /// 둘 차례가 `b`나 `w`가 아님
+    InvalidSideToMove(String),
+    /// 모르는 규칙 이름
+    InvalidRuleSet(String),
+}
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionError::FieldCount(count) => write!(f, "expected 4 fields, found {count}"),
+            PositionError::InvalidSize(size) => write!(f, "invalid board size \"{size}\""),
+            PositionError::RowCount(count) => {
+                write!(f, "row count {count} does not match the board")
+            }
+            PositionError::InvalidRow(row) => write!(f, "row {row}: malformed"),
+            PositionError::InvalidSideToMove(side) => write!(f, "invalid side to move \"{side}\""),
+            PositionError::InvalidRuleSet(rule) => write!(f, "unknown rule set \"{rule}\""),
+        }
+    }
+}
+
+impl std::error::Error for PositionError {}
+
+// ==================== 국면 ====================
+/// 한 줄로 주고받을 수 있는 국면 (판, 블라인드 시야, 둘 차례, 규칙)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Position {
+    pub board: Board,        // 실제 판
+    pub revealed: Board,     // 상대에게도 알려진 돌 (나머지 돌은 놓은 플레이어만 앎)
+    pub to_move: StoneColor, // 둘 차례
+    pub rule_set: RuleSet,   // 승리 조건과 금수 규칙
+}
+
+impl Position {
+    /// 블라인드 게임의 현재 국면
+    pub fn from_game(game: &BlindGame) -> Self {
+        let board = game.game().board().clone();
+        let mut revealed = Board::with_size(board.width(), board.height());
+        for (x, y, color) in board.stones() {
+            if game.view(color.opposite()).get(x, y) == Some(color) {
+                revealed.set(x, y, Some(color));
+            }
+        }
+        Self {
+            board,
+            revealed,
+            to_move: game.game().current_player(),
+            rule_set: game.game().rule_set(),
+        }
+    }
+
+    /// 이 국면에서 시작하는 블라인드 게임 생성 (기보는 비어 있음)
+    pub fn to_game(&self, early_draw: bool) -> BlindGame {
+        let game = Game::from_position(self.board.clone(), self.rule_set, self.to_move)
+            .with_early_draw(early_draw);
+        BlindGame::with_known(game, [&self.revealed, &self.revealed])
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (self.board.width(), self.board.height());
+        write!(f, "{width}x{height} ")?;
+        for y in (0..height).rev() {
+            let mut empty = 0;
+            for x in 0..width {
+                let Some(color) = self.board.get(x, y) else {
+                    empty += 1;
+                    continue;
+                };
+                if empty > 0 {
+                    write!(f, "{empty}")?;
+                    empty = 0;
+                }
+                let letter = match color {
+                    StoneColor::Black => 'x',
+                    StoneColor::White => 'o',
+                };
+                if self.revealed.get(x, y) == Some(color) {
+                    write!(f, "{}", letter.to_ascii_uppercase())?;
+                } else {
+                    write!(f, "{letter}")?;
+                }
+            }
+            if empty > 0 {
+                write!(f, "{empty}")?;
+            }
+            if y > 0 {
+                write!(f, "/")?;
+            }
+        }
+        let side = match self.to_move {
+            StoneColor::Black => 'b',
+            StoneColor::White => 'w',
+        };
+        write!(f, " {side} {}", self.rule_set.to_string().to_lowercase())
+    }
+}
+
+impl FromStr for Position {
+    type Err = PositionError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        let &[size, rows, side, rule] = fields.as_slice() else {
+            return Err(PositionError::FieldCount(fields.len()));
+        };
+
+        let invalid_size = || PositionError::InvalidSize(size.to_string());
+        let (width, height) = size.split_once('x').ok_or_else(invalid_size)?;
+        let width: usize = width.parse().map_err(|_| invalid_size())?;
+        let height: usize = height.parse().map_err(|_| invalid_size())?;
+        if !(1..=MAX_BOARD_SIZE).contains(&width) || !(1..=MAX_BOARD_SIZE).contains(&height) {
+            return Err(invalid_size());
+        }
+
+        let rows: Vec<&str> = rows.split('/').collect();
+        if rows.len() != height {
+            return Err(PositionError::RowCount(rows.len()));
+        }
+        let mut board = Board::with_size(width, height);
+        let mut revealed = Board::with_size(width, height);
+        for (index, row) in rows.iter().enumerate() {
+            let y = height - 1 - index;
+            let invalid_row = PositionError::InvalidRow(index + 1);
+            let mut x = 0;
+            let mut chars = row.chars().peekable();
+            while let Some(c) = chars.next() {
+                if let Some(digit) = c.to_digit(10) {
+                    let mut empty = digit as usize;
+                    if empty == 0 {
+                        return Err(invalid_row);
+                    }
+                    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                        // 판보다 긴 숫자는 더 읽지 않고 거부 (자릿수가 많아도 넘치지 않도록)
+                        empty = empty * 10 + digit as usize;
+                        if empty > width {
+                            return Err(invalid_row);
+                        }
+                        chars.next();
+                    }
+                    x += empty;
+                    if x > width {
+                        return Err(invalid_row);
+                    }
+                    continue;
+                }
+                let color = match c.to_ascii_lowercase() {
+                    'x' => StoneColor::Black,
+                    'o' => StoneColor::White,
+                    _ => return Err(invalid_row),
+                };
+                if x >= width {
+                    return Err(invalid_row);
+                }
+                board.set(x, y, Some(color));
+                if c.is_ascii_uppercase() {
+                    revealed.set(x, y, Some(color));
+                }
+                x += 1;
+            }
+            if x != width {
+                return Err(invalid_row);
+            }
+        }
+
+        let to_move = match side {
+            "b" => StoneColor::Black,
+            "w" => StoneColor::White,
+            _ => return Err(PositionError::InvalidSideToMove(side.to_string())),
+        };
+        let rule_set = rule
+            .parse()
+            .map_err(|_| PositionError::InvalidRuleSet(rule.to_string()))?;
+        Ok(Self {
+            board,
+            revealed,
+            to_move,
+            rule_set,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::blind::CollisionRule;
+    use super::*;
+
+    #[test]
+    fn round_trips_with_hidden_stones() {
+        let text = "9x7 9/9/3Xo4/2xO5/4O4/9/8x w renju";
+        let position: Position = text.parse().unwrap();
+        assert_eq!(position.board.get(3, 4), Some(StoneColor::Black));
+        assert_eq!(position.revealed.get(3, 4), Some(StoneColor::Black));
+        assert_eq!(position.board.get(4, 4), Some(StoneColor::White));
+        assert_eq!(position.revealed.get(4, 4), None);
+        assert_eq!(position.board.get(8, 0), Some(StoneColor::Black));
+        assert_eq!(position.revealed.get(8, 0), None);
+        assert_eq!(position.to_move, StoneColor::White);
+        assert_eq!(position.rule_set, RuleSet::Renju);
+        assert_eq!(position.to_string(), text);
+    }
+
+    #[test]
+    fn round_trips_a_blind_game() {
+        let mut game = BlindGame::with_size(11, 9);
+        for (x, y) in [(5, 4), (6, 4), (6, 4), (4, 4), (5, 5)] {
+            game.play(x, y, CollisionRule::RevealToBoth).unwrap();
+        }
+        let position = Position::from_game(&game);
+        // 흑이 (6, 4)에 부딪혀 다시 둠: 공개된 백 (6, 4)만 대문자, 나머지는 놓은 사람만 앎
+        assert_eq!(
+            position.to_string(),
+            "11x9 11/11/11/5o5/4xxO4/11/11/11/11 b freestyle"
+        );
+        let parsed: Position = position.to_string().parse().unwrap();
+        assert_eq!(parsed, position);
+
+        let restored = parsed.to_game(false);
+        for player in [StoneColor::Black, StoneColor::White] {
+            assert_eq!(restored.view(player).board(), game.view(player).board());
+        }
+    }
+
+    #[test]
+    fn accepts_multi_digit_runs() {
+        let position: Position = "15x1 7X7 b caro".parse().unwrap();
+        assert_eq!(position.board.get(7, 0), Some(StoneColor::Black));
+        assert_eq!(position.to_string(), "15x1 7X7 b caro");
+    }
+
+    #[test]
+    fn rejects_malformed_rows() {
+        let parse = |text: &str| text.parse::<Position>().unwrap_err();
+        assert_eq!(parse("3x2 3/4 b renju"), PositionError::InvalidRow(2));
+        assert_eq!(parse("3x2 3/2 b renju"), PositionError::InvalidRow(2));
+        assert_eq!(parse("3x2 03/3 b renju"), PositionError::InvalidRow(1));
+        assert_eq!(parse("3x2 3/XOXO b renju"), PositionError::InvalidRow(2));
+        assert_eq!(parse("3x2 3/1Z1 b renju"), PositionError::InvalidRow(2));
+        // usize를 넘치는 숫자도 오류로 거부
+        assert_eq!(
+            parse("3x2 99999999999999999999999999/3 b renju"),
+            PositionError::InvalidRow(1)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_fields() {
+        let parse = |text: &str| text.parse::<Position>().unwrap_err();
+        assert_eq!(parse("3x1 3 b"), PositionError::FieldCount(3));
+        assert_eq!(
+            parse("3y1 3 b renju"),
+            PositionError::InvalidSize("3y1".to_string())
+        );
+        assert_eq!(
+            parse("0x1 3 b renju"),
+            PositionError::InvalidSize("0x1".to_string())
+        );
+        assert_eq!(parse("3x2 3 b renju"), PositionError::RowCount(1));
+        assert_eq!(
+            parse("3x1 3 B renju"),
+            PositionError::InvalidSideToMove("B".to_string())
+        );
+        assert_eq!(
+            parse("3x1 3 b gomoku"),
+            PositionError::InvalidRuleSet("gomoku".to_string())
+        );
+        assert!("3x1 3 b RENJU".parse::<Position>().is_ok());
+    }
+}
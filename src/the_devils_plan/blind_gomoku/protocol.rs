@@ -0,0 +1,527 @@
+//! Gomocup (Piskvork) 엔진 프로토콜
+//!
+//! 대국 관리 프로그램이 표준 입력으로 보내는 명령을 한 줄씩 받아 응답할 줄을 돌려줍니다.
+//! 판과 규칙은 `engine`/`rules`, 수 선택은 `ai`의 탐색을 그대로 사용하며 Bevy에 의존하지 않습니다.
+//! 입출력은 호출하는 쪽(`src/bin/pbrain.rs`)이 담당합니다.
+//!
+//! 지원하는 명령:
+//!
+//! - `START <크기>`, `RECTSTART <가로>,<세로>`: 새 판 준비 (`OK` 또는 `ERROR ...`)
+//! - `BEGIN`: 빈 판에서 먼저 둠
+//! - `TURN <x>,<y>`: 상대의 수를 받고 다음 수를 둠
+//! - `BOARD` ... `DONE`: `x,y,필드` 줄로 국면을 받고 다음 수를 둠 (필드 1 = 자신, 2 = 상대)
+//! - `INFO <키> <값>`: `timeout_turn`, `time_left`, `rule` 등 대국 정보 (응답 없음)
+//! - `TAKEBACK <x>,<y>`, `RESTART`: 수 무르기와 같은 크기로 새 대국 (`OK`)
+//! - `ABOUT`: 엔진 정보, `END`: 종료
+//!
+//! 좌표는 0부터 시작하는 `x,y`이며 이 프로젝트의 판 좌표와 같습니다.
+
+use std::time::Duration;
+
+use super::ai::{self, Difficulty, SearchLimits};
+use super::engine::{Board, StoneColor, MAX_BOARD_SIZE, WIN_LENGTH};
+use super::rules::RuleSet;
+
+/// `ABOUT` 명령의 응답
+pub const ABOUT: &str =
+    "name=\"blind-gomoku\", version=\"0.1\", author=\"bevy_examples\", country=\"KR\"";
+
+/// 시간 제한을 넘지 않도록 남겨 두는 여유 시간
+const TIME_MARGIN: Duration = Duration::from_millis(50);
+
+/// 시간 제한이 매우 짧아도 탐색에 쓰는 최소 시간
+const MIN_THINK_TIME: Duration = Duration::from_millis(10);
+
+/// 남은 대국 시간 중 한 수에 쓸 비율의 역수 (남은 시간의 1/20)
+const TIME_LEFT_SHARE: u32 = 20;
+
+// ==================== 좌표 표기 ====================
+/// `x,y` 형식의 좌표를 읽음
+pub fn parse_point(text: &str) -> Option<(usize, usize)> {
+    let (x, y) = text.split_once(',')?;
+    Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+/// (x, y)를 `x,y` 형식으로 변환
+pub fn format_point(x: usize, y: usize) -> String {
+    format!("{x},{y}")
+}
+
+/// `INFO rule` 값(비트 플래그)을 규칙으로 변환
+///
+/// 1 = 정확히 5목, 4 = 렌주, 8 = 카로이며 나머지 비트(연속 대국 등)는 무시합니다.
+pub fn rule_set_from_info(flags: u32) -> RuleSet {
+    if flags & 4 != 0 {
+        RuleSet::Renju
+    } else if flags & 8 != 0 {
+        RuleSet::Caro
+    } else if flags & 1 != 0 {
+        RuleSet::Standard
+    } else {
+        RuleSet::Freestyle
+    }
+}
+
+/// 규칙을 `INFO rule` 값으로 변환
+pub fn rule_set_to_info(rule_set: RuleSet) -> u32 {
+    match rule_set {
+        RuleSet::Freestyle => 0,
+        RuleSet::Standard => 1,
+        RuleSet::Renju => 4,
+        RuleSet::Caro => 8,
+    }
+}
+
+// ==================== 엔진 ====================
+/// 프로토콜 명령을 처리하는 엔진 상태
+///
+/// 엔진이 맡은 색은 BEGIN이면 흑, 빈 판에서 TURN을 받으면 백으로 정합니다.
+/// 프로토콜에는 색 정보가 없으므로 처음 받은 명령이 BOARD이면 돌 개수로 추정합니다
+/// (흑이 먼저 두므로 자신의 돌이 상대보다 적지 않으면 흑).
+#[derive(Clone, Debug, Default)]
+pub struct Brain {
+    board: Option<Board>,                     // 진행 중인 판 (START 전에는 없음)
+    own: Option<StoneColor>,                  // 엔진이 맡은 색 (첫 수를 받기 전에는 없음)
+    rule_set: RuleSet,                        // INFO rule로 받은 규칙
+    timeout_turn: Option<Duration>,           // 한 수의 시간 제한
+    time_left: Option<Duration>,              // 남은 대국 시간
+    pending: Option<Vec<(usize, usize, u8)>>, // BOARD 명령으로 받는 중인 돌 (x, y, 필드)
+    finished: bool,                           // END를 받았는지 여부
+}
+
+impl Brain {
+    /// 새 엔진 상태 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// END를 받아 종료해야 하는지 확인
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// 현재 판 (START 전에는 `None`)
+    pub fn board(&self) -> Option<&Board> {
+        self.board.as_ref()
+    }
+
+    /// 명령 한 줄을 처리하고 출력할 줄들을 반환
+    pub fn handle(&mut self, line: &str) -> Vec<String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Vec::new();
+        }
+
+        // BOARD 명령의 돌 목록은 DONE까지 이어짐
+        if let Some(stones) = &mut self.pending {
+            if line.eq_ignore_ascii_case("DONE") {
+                let stones = self.pending.take().unwrap_or_default();
+                return self.load_board(&stones);
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let stone = match fields[..] {
+                [x, y, field] => x
+                    .parse()
+                    .ok()
+                    .zip(y.parse().ok())
+                    .zip(field.parse().ok())
+                    .map(|((x, y), field)| (x, y, field)),
+                _ => None,
+            };
+            return match stone {
+                Some(stone) => {
+                    stones.push(stone);
+                    Vec::new()
+                }
+                None => vec![format!("ERROR invalid board line \"{line}\"")],
+            };
+        }
+
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+        match command.to_ascii_uppercase().as_str() {
+            "START" => match args.parse() {
+                Ok(size) => self.start(size, size),
+                Err(_) => vec![format!("ERROR invalid size \"{args}\"")],
+            },
+            "RECTSTART" => match parse_point(args) {
+                Some((width, height)) => self.start(width, height),
+                None => vec![format!("ERROR invalid size \"{args}\"")],
+            },
+            "RESTART" => match &self.board {
+                Some(board) => {
+                    let (width, height) = (board.width(), board.height());
+                    self.start(width, height)
+                }
+                None => vec!["ERROR no board, send START first".to_string()],
+            },
+            "BEGIN" => {
+                self.own = Some(StoneColor::Black);
+                self.think()
+            }
+            "TURN" => self.turn(args),
+            "BOARD" => {
+                self.pending = Some(Vec::new());
+                Vec::new()
+            }
+            "TAKEBACK" => self.take_back(args),
+            "INFO" => {
+                self.info(args);
+                Vec::new()
+            }
+            "ABOUT" => vec![ABOUT.to_string()],
+            "END" => {
+                self.finished = true;
+                Vec::new()
+            }
+            _ => vec![format!("UNKNOWN command \"{command}\"")],
+        }
+    }
+
+    /// `width`x`height` 빈 판으로 새 대국 준비
+    fn start(&mut self, width: usize, height: usize) -> Vec<String> {
+        let supported = WIN_LENGTH..=MAX_BOARD_SIZE;
+        if !supported.contains(&width) || !supported.contains(&height) {
+            return vec![format!(
+                "ERROR unsupported size {width}x{height} (supported {WIN_LENGTH} to {MAX_BOARD_SIZE})"
+            )];
+        }
+        self.board = Some(Board::with_size(width, height));
+        self.own = None;
+        self.pending = None;
+        vec!["OK".to_string()]
+    }
+
+    /// 상대의 수를 두고 다음 수를 둠
+    fn turn(&mut self, args: &str) -> Vec<String> {
+        let Some(board) = &mut self.board else {
+            return vec!["ERROR no board, send START first".to_string()];
+        };
+        match parse_point(args) {
+            Some((x, y))
+                if x < board.width() && y < board.height() && board.get(x, y).is_none() =>
+            {
+                let opponent = self
+                    .own
+                    .map_or_else(|| to_move(board), StoneColor::opposite);
+                board.set(x, y, Some(opponent));
+                self.own = Some(opponent.opposite());
+                self.think()
+            }
+            _ => vec![format!("ERROR invalid move \"{args}\"")],
+        }
+    }
+
+    /// BOARD 명령으로 받은 돌로 판을 다시 만들고 다음 수를 둠
+    fn load_board(&mut self, stones: &[(usize, usize, u8)]) -> Vec<String> {
+        let Some(board) = &self.board else {
+            return vec!["ERROR no board, send START first".to_string()];
+        };
+        let mut loaded = Board::with_size(board.width(), board.height());
+
+        // 색을 아직 모르면 자신의 돌 수가 상대보다 적지 않을 때 흑
+        let own = stones.iter().filter(|&&(_, _, field)| field == 1).count();
+        let own_color = self.own.unwrap_or(if own * 2 >= stones.len() {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        });
+        for &(x, y, field) in stones {
+            if x >= loaded.width() || y >= loaded.height() || loaded.get(x, y).is_some() {
+                return vec![format!("ERROR invalid stone {}", format_point(x, y))];
+            }
+            let color = match field {
+                1 => own_color,
+                _ => own_color.opposite(),
+            };
+            loaded.set(x, y, Some(color));
+        }
+
+        self.board = Some(loaded);
+        self.own = Some(own_color);
+        self.think()
+    }
+
+    /// (x, y)의 돌을 치움
+    fn take_back(&mut self, args: &str) -> Vec<String> {
+        let Some(board) = &mut self.board else {
+            return vec!["ERROR no board, send START first".to_string()];
+        };
+        match parse_point(args) {
+            Some((x, y))
+                if x < board.width() && y < board.height() && board.get(x, y).is_some() =>
+            {
+                board.set(x, y, None);
+                vec!["OK".to_string()]
+            }
+            _ => vec![format!("ERROR invalid move \"{args}\"")],
+        }
+    }
+
+    /// INFO 명령의 대국 정보 저장 (모르는 키는 무시)
+    fn info(&mut self, args: &str) {
+        let (key, value) = args.split_once(' ').unwrap_or((args, ""));
+        let Ok(value) = value.trim().parse::<u64>() else {
+            return;
+        };
+        match key {
+            "timeout_turn" => self.timeout_turn = Some(Duration::from_millis(value)),
+            "time_left" => self.time_left = Some(Duration::from_millis(value)),
+            "rule" => self.rule_set = rule_set_from_info(value as u32),
+            _ => {}
+        }
+    }
+
+    /// 이번 수에 쓸 탐색 한도 (한 수의 제한과 남은 시간 중 짧은 쪽에서 여유 시간을 뺌)
+    fn limits(&self) -> SearchLimits {
+        // 프로토콜은 시간으로 수읽기를 제한하므로 프리셋의 노드 수 한도는 쓰지 않음
+        let mut limits = Difficulty::Expert.profile().limits;
+        limits.max_nodes = None;
+        let mut budget = self.timeout_turn.unwrap_or(limits.time_limit);
+        if let Some(time_left) = self.time_left {
+            budget = budget.min(time_left / TIME_LEFT_SHARE);
+        }
+        limits.time_limit = budget.saturating_sub(TIME_MARGIN).max(MIN_THINK_TIME);
+        limits
+    }
+
+    /// 엔진이 맡은 색으로 다음 수를 골라 판에 두고 좌표를 반환
+    fn think(&mut self) -> Vec<String> {
+        let limits = self.limits();
+        let rule_set = self.rule_set;
+        let Some(board) = &mut self.board else {
+            return vec!["ERROR no board, send START first".to_string()];
+        };
+
+        let color = self.own.unwrap_or_else(|| to_move(board));
+        let found = ai::search(board, rule_set, color, &limits)
+            .map(|result| (result.x, result.y))
+            .or_else(|| {
+                // 탐색할 후보가 없으면 둘 수 있는 아무 자리
+                board.points().find(|&(x, y)| {
+                    board.get(x, y).is_none() && rule_set.forbidden(board, x, y, color).is_none()
+                })
+            });
+        match found {
+            Some((x, y)) => {
+                board.set(x, y, Some(color));
+                vec![format_point(x, y)]
+            }
+            None => vec!["ERROR no legal move".to_string()],
+        }
+    }
+}
+
+/// 돌 개수로 정한 둘 차례 (흑이 먼저 둠)
+fn to_move(board: &Board) -> StoneColor {
+    let black = board
+        .stones()
+        .filter(|&(_, _, color)| color == StoneColor::Black)
+        .count();
+    if black * 2 > board.stones().count() {
+        StoneColor::White
+    } else {
+        StoneColor::Black
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 명령을 차례로 보내고 마지막 명령의 응답을 반환
+    fn send(brain: &mut Brain, lines: &[&str]) -> Vec<String> {
+        let mut replies = Vec::new();
+        for line in lines {
+            replies = brain.handle(line);
+        }
+        replies
+    }
+
+    /// 탐색 시간을 줄인 15x15 판의 엔진
+    fn started() -> Brain {
+        let mut brain = Brain::new();
+        assert_eq!(send(&mut brain, &["START 15"]), ["OK"]);
+        assert!(send(&mut brain, &["INFO timeout_turn 100"]).is_empty());
+        brain
+    }
+
+    /// 응답이 판 안의 좌표 하나인지 확인하고 그 좌표를 반환
+    fn reply_point(replies: &[String]) -> (usize, usize) {
+        let [reply] = replies else {
+            panic!("expected one line: {replies:?}");
+        };
+        let (x, y) = parse_point(reply).unwrap_or_else(|| panic!("not a move: {reply}"));
+        assert!(x < 15 && y < 15, "{reply}");
+        (x, y)
+    }
+
+    #[test]
+    fn start_checks_the_size() {
+        let mut brain = Brain::new();
+        assert_eq!(send(&mut brain, &["start 19"]), ["OK"]);
+        assert_eq!(brain.board().map(Board::width), Some(19));
+        assert_eq!(send(&mut brain, &["RECTSTART 20,15"]), ["OK"]);
+        let board = brain.board().unwrap();
+        assert_eq!((board.width(), board.height()), (20, 15));
+        assert_eq!(send(&mut brain, &["RESTART"]), ["OK"]);
+        assert_eq!(brain.board().map(Board::height), Some(15));
+
+        for command in ["START 4", "START 1000", "START x", "RECTSTART 20"] {
+            let replies = brain.handle(command);
+            assert!(replies[0].starts_with("ERROR"), "{command}: {replies:?}");
+        }
+    }
+
+    #[test]
+    fn commands_need_a_board() {
+        for commands in [
+            &["BEGIN"][..],
+            &["TURN 7,7"],
+            &["BOARD", "7,7,1", "DONE"],
+            &["TAKEBACK 7,7"],
+            &["RESTART"],
+        ] {
+            let replies = send(&mut Brain::new(), commands);
+            assert_eq!(
+                replies,
+                ["ERROR no board, send START first"],
+                "{commands:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn begin_plays_black() {
+        let mut brain = started();
+        let (x, y) = reply_point(&brain.handle("BEGIN"));
+        assert_eq!(brain.board().unwrap().get(x, y), Some(StoneColor::Black));
+    }
+
+    #[test]
+    fn turn_places_the_opponent_stone() {
+        let mut brain = started();
+        let (x, y) = reply_point(&brain.handle("TURN 7,7"));
+        let board = brain.board().unwrap();
+        assert_eq!(board.get(7, 7), Some(StoneColor::Black));
+        assert_eq!(board.get(x, y), Some(StoneColor::White));
+
+        let (x, y) = reply_point(&brain.handle("TURN 3, 4"));
+        let board = brain.board().unwrap();
+        assert_eq!(board.get(3, 4), Some(StoneColor::Black));
+        assert_eq!(board.get(x, y), Some(StoneColor::White));
+    }
+
+    #[test]
+    fn turn_rejects_invalid_points() {
+        let mut brain = started();
+        brain.handle("TURN 7,7");
+        for args in ["15,3", "3,15", "7,7", "7", "-1,3", "a,b"] {
+            assert_eq!(
+                brain.handle(&format!("TURN {args}")),
+                [format!("ERROR invalid move \"{args}\"")]
+            );
+        }
+        assert_eq!(brain.board().unwrap().stones().count(), 2);
+    }
+
+    #[test]
+    fn board_loads_the_position() {
+        let mut brain = started();
+        let mut lines = vec!["BOARD"];
+        // 자신의 4목 (양쪽이 열림)과 흩어진 상대 돌
+        lines.extend(["3,7,1", "4,7,1", "5,7,1", "6,7,1"]);
+        lines.extend(["0,0,2", "14,0,2", "0,14,2", "14,14,2"]);
+        for line in &lines {
+            assert!(brain.handle(line).is_empty(), "{line}");
+        }
+        let (x, y) = reply_point(&brain.handle("DONE"));
+        assert!(matches!((x, y), (2, 7) | (7, 7)));
+        let board = brain.board().unwrap();
+        assert_eq!(board.get(3, 7), Some(StoneColor::Black));
+        assert_eq!(board.get(0, 0), Some(StoneColor::White));
+        assert_eq!(board.get(x, y), Some(StoneColor::Black));
+    }
+
+    #[test]
+    fn board_reports_malformed_blocks() {
+        let mut brain = started();
+        brain.handle("BOARD");
+        assert_eq!(brain.handle("7,7"), ["ERROR invalid board line \"7,7\""]);
+        assert_eq!(
+            brain.handle("7,x,1"),
+            ["ERROR invalid board line \"7,x,1\""]
+        );
+        // 잘못된 줄은 버리고 DONE까지 계속 받음
+        assert!(brain.handle("7,7,2").is_empty());
+        reply_point(&brain.handle("DONE"));
+
+        for stones in [&["15,0,1"][..], &["3,3,1", "3,3,2"]] {
+            let mut lines = vec!["BOARD"];
+            lines.extend(stones);
+            lines.push("DONE");
+            let replies = send(&mut brain, &lines);
+            assert!(replies[0].starts_with("ERROR invalid stone"), "{replies:?}");
+        }
+    }
+
+    #[test]
+    fn info_sets_rules_and_time() {
+        let mut brain = started();
+        for line in [
+            "INFO rule 4",
+            "INFO timeout_turn 1000",
+            "INFO time_left 10000",
+            "INFO max_memory 83886080",
+            "INFO rule x",
+        ] {
+            assert!(brain.handle(line).is_empty(), "{line}");
+        }
+        assert_eq!(brain.rule_set, RuleSet::Renju);
+        assert_eq!(brain.timeout_turn, Some(Duration::from_millis(1_000)));
+        // 남은 시간의 1/20에서 여유 시간을 뺌
+        assert_eq!(brain.limits().time_limit, Duration::from_millis(450));
+
+        brain.handle("INFO time_left 0");
+        assert_eq!(brain.limits().time_limit, MIN_THINK_TIME);
+    }
+
+    #[test]
+    fn takeback_removes_stones() {
+        let mut brain = started();
+        brain.handle("TURN 7,7");
+        assert_eq!(brain.handle("TAKEBACK 7,7"), ["OK"]);
+        assert_eq!(brain.board().unwrap().get(7, 7), None);
+        assert_eq!(brain.handle("TAKEBACK 7,7"), ["ERROR invalid move \"7,7\""]);
+    }
+
+    #[test]
+    fn about_end_and_unknown_commands() {
+        let mut brain = Brain::new();
+        assert_eq!(brain.handle("ABOUT"), [ABOUT]);
+        assert!(brain.handle("").is_empty());
+        assert_eq!(
+            brain.handle("SWAP2BOARD"),
+            ["UNKNOWN command \"SWAP2BOARD\""]
+        );
+        assert!(!brain.is_finished());
+        assert!(brain.handle("END").is_empty());
+        assert!(brain.is_finished());
+    }
+
+    #[test]
+    fn rule_flags_round_trip() {
+        for rule_set in [
+            RuleSet::Freestyle,
+            RuleSet::Standard,
+            RuleSet::Renju,
+            RuleSet::Caro,
+        ] {
+            assert_eq!(rule_set_from_info(rule_set_to_info(rule_set)), rule_set);
+        }
+        // 연속 대국 비트(2)는 무시
+        assert_eq!(rule_set_from_info(2 | 1), RuleSet::Standard);
+        assert_eq!(parse_point(" 3 , 4 "), Some((3, 4)));
+        assert_eq!(format_point(3, 4), "3,4");
+    }
+}
@@ -0,0 +1,800 @@
+//! 기보 저장과 불러오기
+//!
+//! 끝난 (또는 진행 중인) 대국을 두 가지 형식으로 주고받습니다. Bevy에 의존하지 않으므로
+//! 화면과 토너먼트 같은 창 없는 도구에서 함께 사용합니다.
+//!
+//! # 좌표 표기
+//!
+//! 사람이 읽기 쉬운 UTF-8 텍스트입니다. `# 항목: 값` 형식의 머리 줄 뒤에 흑부터 둔 좌표를
+//! 공백으로 나누어 적으며, 차례를 잃어 같은 색이 연달아 두었으면 그 사이에 `pass`를 적습니다.
+//! 머리 줄은 주석이기도 하므로 `build_book`의 대국 파일로도 그대로 쓸 수 있습니다.
+//!
+//! ```text
+//! # Black: Player 1
+//! # White: AI (Hard)
+//! # Size: 15x15
+//! # Rule: Renju
+//! # Result: 1-0
+//! # Started: 2026-10-18T09:30:00Z
+//! # Finished: 2026-10-18T09:41:12Z
+//! h8 i9 h9 pass h10
+//! ```
+//!
+//! - 좌표는 열 문자(`a`부터, x = 0)와 행 번호(`1`부터, y = 0)를 이어 씁니다. 예: `h8` = (7, 7)
+//! - 결과는 `1-0`(흑 승), `0-1`(백 승), `1/2-1/2`(무승부), `*`(진행 중)입니다.
+//! - 모르는 항목과 다른 주석은 무시하고, 빠진 항목은 기본값(15x15, 자유 규칙)을 씁니다.
+//!
+//! # SGF
+//!
+//! SGF 4판(`FF[4]`)의 오목/렌주 게임 형식(`GM[4]`)입니다. 판 크기(`SZ`), 규칙(`RU`),
+//! 대국자(`PB`, `PW`), 결과(`RE`), 날짜(`DT`)를 표준 속성으로 적고, 시작과 끝 시각은
+//! 이 프로그램 전용 속성 `XS`, `XE`에 적습니다 (다른 프로그램은 모르는 속성을 무시함).
+//! SGF의 행은 위에서부터 세므로 `y`를 뒤집어 적습니다. 읽을 때는 첫 게임의 주 수순
+//! (각 분기의 첫 변화)만 읽습니다.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::engine::{
+    Game, GameResult, Move, MoveError, StoneColor, DEFAULT_BOARD_SIZE, MAX_BOARD_SIZE,
+};
+use super::rules::RuleSet;
+
+/// 좌표 표기에서 차례를 넘긴 수
+pub const PASS: &str = "pass";
+
+/// SGF의 게임 종류 번호 (오목과 렌주)
+const SGF_GAME: &str = "4";
+
+/// SGF를 만든 프로그램 이름 (`AP` 속성)
+const SGF_APPLICATION: &str = concat!(env!("CARGO_PKG_NAME"), ":", env!("CARGO_PKG_VERSION"));
+
+// ==================== 오류 ====================
+/// 기보를 읽을 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RecordError {
+    /// 지원하지 않는 판 크기
+    InvalidSize,
+    /// 판 밖이거나 읽을 수 없는 좌표
+    InvalidPoint(String),
+    /// 형식이 잘못된 머리 줄 (줄 번호)
+    InvalidHeader(usize),
+    /// 오목이 아닌 SGF 게임 종류 (`GM` 값)
+    UnsupportedGame(String),
+    /// SGF 문법 오류 (설명)
+    InvalidSgf(String),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::InvalidSize => write!(f, "invalid board size"),
+            RecordError::InvalidPoint(point) => write!(f, "invalid point \"{point}\""),
+            RecordError::InvalidHeader(line) => write!(f, "line {line}: malformed header"),
+            RecordError::UnsupportedGame(game) => write!(f, "unsupported SGF game type {game}"),
+            RecordError::InvalidSgf(reason) => write!(f, "malformed SGF: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+// ==================== 기보 ====================
+/// 한 대국의 기보 (대국 정보와 수순)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Record {
+    pub width: usize,               // 판 가로 칸 수
+    pub height: usize,              // 판 세로 칸 수
+    pub rule_set: RuleSet,          // 승리 조건과 금수 규칙
+    pub black: String,              // 흑 대국자 이름
+    pub white: String,              // 백 대국자 이름
+    pub moves: Vec<Move>,           // 판에 놓인 수 (차례를 넘긴 수는 색으로 드러남)
+    pub result: Option<GameResult>, // 결과 (진행 중이면 없음)
+    pub started: Option<u64>,       // 대국을 시작한 시각 (유닉스 시간, 초)
+    pub finished: Option<u64>,      // 대국이 끝난 시각 (유닉스 시간, 초)
+}
+
+impl Default for Record {
+    /// 15x15 자유 규칙의 빈 기보
+    fn default() -> Self {
+        Self::new(DEFAULT_BOARD_SIZE, DEFAULT_BOARD_SIZE, RuleSet::default())
+    }
+}
+
+impl Record {
+    /// `width`x`height` 판의 빈 기보 생성 (대국자 이름은 색 이름)
+    pub fn new(width: usize, height: usize, rule_set: RuleSet) -> Self {
+        Self {
+            width,
+            height,
+            rule_set,
+            black: StoneColor::Black.to_string(),
+            white: StoneColor::White.to_string(),
+            moves: Vec::new(),
+            result: None,
+            started: None,
+            finished: None,
+        }
+    }
+
+    /// 내용을 보고 좌표 표기 또는 SGF로 읽음 (`(`로 시작하면 SGF)
+    pub fn parse(text: &str) -> Result<Self, RecordError> {
+        if text.trim_start().starts_with('(') {
+            Self::parse_sgf(text)
+        } else {
+            Self::parse_text(text)
+        }
+    }
+
+    /// 빈 판의 `game`에 수순을 다시 둠
+    ///
+    /// 같은 색이 연달아 둔 자리에서는 차례를 넘기고, 수순이 끝났는데도 게임이 끝나지 않았으면
+    /// 기록된 승패를 기권으로 반영합니다. 규칙에 맞지 않는 수가 있으면 그 수의 오류를 반환합니다.
+    pub fn replay(&self, game: &mut Game) -> Result<(), MoveError> {
+        for stone in &self.moves {
+            if game.current_player() != stone.color {
+                game.pass()?;
+            }
+            game.play(stone.x, stone.y)?;
+        }
+        if let Some(GameResult::Win(winner)) = self.result {
+            game.resign(winner.opposite());
+        }
+        Ok(())
+    }
+
+    /// 판 크기와 좌표가 올바른지 확인
+    fn validate(self) -> Result<Self, RecordError> {
+        let valid_size = |size: usize| (1..=MAX_BOARD_SIZE).contains(&size);
+        if !valid_size(self.width) || !valid_size(self.height) {
+            return Err(RecordError::InvalidSize);
+        }
+        if let Some(stone) = self
+            .moves
+            .iter()
+            .find(|stone| stone.x >= self.width || stone.y >= self.height)
+        {
+            return Err(RecordError::InvalidPoint(format_point(stone.x, stone.y)));
+        }
+        Ok(self)
+    }
+
+    /// 좌표 표기 텍스트로 변환
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "# Black: {}\n# White: {}\n# Size: {}x{}\n# Rule: {}\n# Result: {}\n",
+            self.black,
+            self.white,
+            self.width,
+            self.height,
+            self.rule_set,
+            format_result(self.result)
+        );
+        if let Some(started) = self.started {
+            text += &format!("# Started: {}\n", format_timestamp(started));
+        }
+        if let Some(finished) = self.finished {
+            text += &format!("# Finished: {}\n", format_timestamp(finished));
+        }
+        text += &format_moves(&self.moves);
+        text.push('\n');
+        text
+    }
+
+    /// 좌표 표기 텍스트를 읽음
+    pub fn parse_text(text: &str) -> Result<Self, RecordError> {
+        let mut record = Self::default();
+        let mut tokens = String::new();
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let Some(comment) = line.trim().strip_prefix('#') else {
+                // 줄 중간의 `#`부터는 주석
+                tokens += line.split('#').next().unwrap_or_default();
+                tokens.push(' ');
+                continue;
+            };
+
+            // `# 항목: 값` 형식이 아닌 주석은 무시
+            let Some((key, value)) = comment.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            let invalid = || RecordError::InvalidHeader(line_number);
+            match key.trim().to_ascii_lowercase().as_str() {
+                "black" => record.black = value.to_string(),
+                "white" => record.white = value.to_string(),
+                "size" => {
+                    let (width, height) = value.split_once('x').ok_or_else(invalid)?;
+                    record.width = width.trim().parse().map_err(|_| invalid())?;
+                    record.height = height.trim().parse().map_err(|_| invalid())?;
+                }
+                "rule" => record.rule_set = value.parse().map_err(|_| invalid())?,
+                "result" => record.result = parse_result(value).ok_or_else(invalid)?,
+                "started" => record.started = Some(parse_timestamp(value).ok_or_else(invalid)?),
+                "finished" => record.finished = Some(parse_timestamp(value).ok_or_else(invalid)?),
+                _ => {}
+            }
+        }
+
+        record.moves = parse_moves(&tokens).map_err(RecordError::InvalidPoint)?;
+        record.validate()
+    }
+
+    /// SGF 텍스트로 변환
+    pub fn to_sgf(&self) -> String {
+        let size = if self.width == self.height {
+            self.width.to_string()
+        } else {
+            format!("{}:{}", self.width, self.height)
+        };
+        let mut sgf = format!(
+            "(;FF[4]GM[{SGF_GAME}]CA[UTF-8]AP[{}]SZ[{size}]RU[{}]PB[{}]PW[{}]",
+            sgf_escape(SGF_APPLICATION),
+            self.rule_set,
+            sgf_escape(&self.black),
+            sgf_escape(&self.white)
+        );
+        match self.result {
+            Some(GameResult::Win(StoneColor::Black)) => sgf += "RE[B+]",
+            Some(GameResult::Win(StoneColor::White)) => sgf += "RE[W+]",
+            Some(GameResult::Draw) => sgf += "RE[0]",
+            None => {}
+        }
+        if let Some(started) = self.started {
+            let timestamp = format_timestamp(started);
+            sgf += &format!("DT[{}]XS[{timestamp}]", &timestamp[..10]);
+        }
+        if let Some(finished) = self.finished {
+            sgf += &format!("XE[{}]", format_timestamp(finished));
+        }
+
+        // 차례를 넘긴 수는 빈 값으로 적음
+        let mut color = StoneColor::Black;
+        for stone in &self.moves {
+            if stone.color != color {
+                sgf += &format!("\n;{}[]", sgf_color(color));
+            }
+            sgf += &format!(
+                "\n;{}[{}]",
+                sgf_color(stone.color),
+                sgf_point(stone.x, self.height - 1 - stone.y)
+            );
+            color = stone.color.opposite();
+        }
+        sgf += ")\n";
+        sgf
+    }
+
+    /// SGF 텍스트의 첫 게임을 읽음
+    pub fn parse_sgf(text: &str) -> Result<Self, RecordError> {
+        let nodes = SgfReader::new(text).game()?;
+        let mut record = Self::default();
+        let mut moves = Vec::new();
+        for (name, values) in nodes.iter().flatten() {
+            let value = values.first().map_or("", String::as_str);
+            match name.as_str() {
+                "GM" if value != SGF_GAME => {
+                    return Err(RecordError::UnsupportedGame(value.to_string()));
+                }
+                "SZ" => {
+                    let (width, height) = value.split_once(':').unwrap_or((value, value));
+                    record.width = width.trim().parse().map_err(|_| RecordError::InvalidSize)?;
+                    record.height = height
+                        .trim()
+                        .parse()
+                        .map_err(|_| RecordError::InvalidSize)?;
+                }
+                "RU" => record.rule_set = value.parse().unwrap_or_default(),
+                "PB" => record.black = value.to_string(),
+                "PW" => record.white = value.to_string(),
+                "RE" => {
+                    record.result = match value.chars().next() {
+                        Some('B' | 'b') => Some(GameResult::Win(StoneColor::Black)),
+                        Some('W' | 'w') => Some(GameResult::Win(StoneColor::White)),
+                        Some('0' | 'D' | 'd') => Some(GameResult::Draw),
+                        _ => None,
+                    };
+                }
+                "DT" => record.started = record.started.or_else(|| parse_timestamp(value)),
+                "XS" => record.started = parse_timestamp(value).or(record.started),
+                "XE" => record.finished = parse_timestamp(value),
+                "B" | "W" => moves.push((name.as_str(), value)),
+                "AB" | "AW" | "AE" => {
+                    return Err(RecordError::InvalidSgf(
+                        "setup stones are not supported".to_string(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        // 좌표는 판 크기를 안 뒤에 변환 (`tt`는 19줄 이하 판에서 차례 넘김)
+        for (name, value) in moves {
+            if value.is_empty() || (value == "tt" && record.width.max(record.height) <= 19) {
+                continue;
+            }
+            let color = if name == "B" {
+                StoneColor::Black
+            } else {
+                StoneColor::White
+            };
+            let invalid = || RecordError::InvalidPoint(value.to_string());
+            let (x, row) = parse_sgf_point(value).ok_or_else(invalid)?;
+            let y = record.height.checked_sub(row + 1).ok_or_else(invalid)?;
+            record.moves.push(Move { color, x, y });
+        }
+        record.validate()
+    }
+}
+
+// ==================== 좌표 표기 ====================
+/// `h8` 형식의 좌표를 (x, y)로 변환 (열 문자 `a` = x 0, 행 번호 `1` = y 0)
+pub fn parse_point(token: &str) -> Option<(usize, usize)> {
+    let mut chars = token.chars();
+    let column = chars.next()?.to_ascii_lowercase();
+    if !column.is_ascii_lowercase() {
+        return None;
+    }
+    // `usize`의 `parse`는 `+8`도 받으므로 숫자만 있는지 먼저 확인
+    let row = chars.as_str();
+    if row.is_empty() || !row.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let row: usize = row.parse().ok()?;
+    Some(((column as u8 - b'a') as usize, row.checked_sub(1)?))
+}
+
+/// (x, y)를 `h8` 형식의 좌표로 변환
+pub fn format_point(x: usize, y: usize) -> String {
+    format!("{}{}", (b'a' + x as u8) as char, y + 1)
+}
+
+/// 수순을 흑부터 번갈아 둔 좌표 목록으로 변환 (같은 색이 연달아 두었으면 사이에 `pass`)
+pub fn format_moves(moves: &[Move]) -> String {
+    let mut tokens = Vec::with_capacity(moves.len());
+    let mut color = StoneColor::Black;
+    for stone in moves {
+        if stone.color != color {
+            tokens.push(PASS.to_string());
+        }
+        tokens.push(format_point(stone.x, stone.y));
+        color = stone.color.opposite();
+    }
+    tokens.join(" ")
+}
+
+/// 공백으로 나눈 좌표 목록을 흑부터 번갈아 둔 수순으로 변환
+///
+/// `pass`는 돌을 놓지 않고 차례만 넘깁니다. 읽을 수 없는 좌표가 있으면 그 좌표를 오류로 반환합니다.
+pub fn parse_moves(text: &str) -> Result<Vec<Move>, String> {
+    let mut color = StoneColor::Black;
+    let mut moves = Vec::new();
+    for token in text.split_whitespace() {
+        if token.eq_ignore_ascii_case(PASS) {
+            color = color.opposite();
+            continue;
+        }
+        let (x, y) = parse_point(token).ok_or_else(|| token.to_string())?;
+        moves.push(Move { color, x, y });
+        color = color.opposite();
+    }
+    Ok(moves)
+}
+
+/// 결과를 `1-0`, `0-1`, `1/2-1/2`, `*`(진행 중)로 표기 (흑-백 순서)
+pub fn format_result(result: Option<GameResult>) -> &'static str {
+    match result {
+        Some(GameResult::Win(StoneColor::Black)) => "1-0",
+        Some(GameResult::Win(StoneColor::White)) => "0-1",
+        Some(GameResult::Draw) => "1/2-1/2",
+        None => "*",
+    }
+}
+
+/// `format_result`의 표기를 읽음 (읽을 수 없으면 `None`, 진행 중이면 `Some(None)`)
+pub fn parse_result(text: &str) -> Option<Option<GameResult>> {
+    match text {
+        "1-0" => Some(Some(GameResult::Win(StoneColor::Black))),
+        "0-1" => Some(Some(GameResult::Win(StoneColor::White))),
+        "1/2-1/2" => Some(Some(GameResult::Draw)),
+        "*" => Some(None),
+        _ => None,
+    }
+}
+
+// ==================== 시각 ====================
+/// 현재 시각 (유닉스 시간, 초)
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// 유닉스 시간을 `2026-10-18T09:30:00Z` 형식(UTC)으로 변환
+pub fn format_timestamp(seconds: u64) -> String {
+    let (days, time) = (seconds / 86_400, seconds % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+/// `2026-10-18T09:30:00Z` 또는 `2026-10-18` 형식(UTC)을 유닉스 시간으로 변환
+///
+/// 날짜와 시각 사이에는 `T` 대신 공백을 써도 되며, 끝의 `Z`는 생략할 수 있습니다.
+pub fn parse_timestamp(text: &str) -> Option<u64> {
+    let text = text.trim().trim_end_matches('Z');
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+
+    let mut date = date.split('-').map(str::parse::<u32>);
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    if date.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let seconds = match time {
+        Some(time) => {
+            let mut parts = time.split(':').map(str::parse::<u64>);
+            let (hour, minute, second) = (
+                parts.next()?.ok()?,
+                parts.next()?.ok()?,
+                parts.next().unwrap_or(Ok(0)).ok()?,
+            );
+            if parts.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+                return None;
+            }
+            hour * 3_600 + minute * 60 + second
+        }
+        None => 0,
+    };
+    let days = u64::try_from(days_from_civil(year as i64, month, day)).ok()?;
+    Some(days * 86_400 + seconds)
+}
+
+/// 1970-01-01부터 센 날 수를 (연, 월, 일)로 변환 (그레고리력)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153; // 3월부터 센 달 (0부터)
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// (연, 월, 일)을 1970-01-01부터 센 날 수로 변환 (그레고리력)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = i64::from((month + 9) % 12); // 3월부터 센 달 (0부터)
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// ==================== SGF 읽기와 쓰기 ====================
+/// SGF의 색 속성 이름
+fn sgf_color(color: StoneColor) -> &'static str {
+    match color {
+        StoneColor::Black => "B",
+        StoneColor::White => "W",
+    }
+}
+
+/// (열, 위에서부터 센 행)을 SGF 좌표로 변환 (예: (7, 7) = `hh`)
+fn sgf_point(column: usize, row: usize) -> String {
+    [column, row]
+        .iter()
+        .map(|&index| (b'a' + index as u8) as char)
+        .collect()
+}
+
+/// SGF 좌표를 (열, 위에서부터 센 행)으로 변환
+fn parse_sgf_point(value: &str) -> Option<(usize, usize)> {
+    let bytes = value.as_bytes();
+    let index = |byte: u8| byte.is_ascii_lowercase().then(|| (byte - b'a') as usize);
+    match bytes {
+        &[column, row] => Some((index(column)?, index(row)?)),
+        _ => None,
+    }
+}
+
+/// SGF 값 안의 `]`와 `\`를 이스케이프
+fn sgf_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace(']', "\\]")
+}
+
+/// 한 노드의 속성 목록 (속성 이름, 값 목록)
+type SgfNode = Vec<(String, Vec<String>)>;
+
+/// SGF 텍스트를 한 글자씩 읽는 파서
+struct SgfReader {
+    chars: Vec<char>, // SGF 텍스트
+    pos: usize,       // 다음에 읽을 글자의 위치
+}
+
+impl SgfReader {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    /// 첫 게임 트리의 주 수순에 있는 노드 목록
+    fn game(&mut self) -> Result<Vec<SgfNode>, RecordError> {
+        let mut nodes = Vec::new();
+        self.tree(&mut nodes)?;
+        if nodes.is_empty() {
+            return Err(RecordError::InvalidSgf("empty game tree".to_string()));
+        }
+        Ok(nodes)
+    }
+
+    /// `(`로 시작하는 게임 트리를 읽어 노드를 `nodes`에 더함 (첫 변화만 따라감)
+    fn tree(&mut self, nodes: &mut Vec<SgfNode>) -> Result<(), RecordError> {
+        self.expect('(')?;
+        while self.peek() == Some(';') {
+            self.pos += 1;
+            nodes.push(self.node()?);
+        }
+        let mut followed = false;
+        while self.peek() == Some('(') {
+            if followed {
+                self.skip_tree()?;
+            } else {
+                self.tree(nodes)?;
+                followed = true;
+            }
+        }
+        self.expect(')')
+    }
+
+    /// 한 노드의 속성들을 읽음
+    fn node(&mut self) -> Result<SgfNode, RecordError> {
+        let mut properties = Vec::new();
+        while let Some(first) = self.peek().filter(char::is_ascii_alphabetic) {
+            // FF[3] 이전 형식은 속성 이름에 소문자를 섞어 쓰므로 대문자만 남김
+            let mut name = String::new();
+            let mut next = Some(first);
+            while let Some(letter) = next.filter(char::is_ascii_alphabetic) {
+                if letter.is_ascii_uppercase() {
+                    name.push(letter);
+                }
+                self.pos += 1;
+                next = self.chars.get(self.pos).copied();
+            }
+
+            let mut values = Vec::new();
+            while self.peek() == Some('[') {
+                self.pos += 1;
+                values.push(self.value()?);
+            }
+            if values.is_empty() {
+                return Err(RecordError::InvalidSgf(format!(
+                    "property {name} has no value"
+                )));
+            }
+            properties.push((name, values));
+        }
+        Ok(properties)
+    }
+
+    /// `[` 다음부터 `]`까지의 값을 읽음 (이스케이프 처리)
+    fn value(&mut self) -> Result<String, RecordError> {
+        let mut value = String::new();
+        loop {
+            let Some(&c) = self.chars.get(self.pos) else {
+                return Err(RecordError::InvalidSgf("unterminated value".to_string()));
+            };
+            self.pos += 1;
+            match c {
+                ']' => return Ok(value),
+                '\\' => {
+                    // 줄바꿈 앞의 `\`는 줄을 잇는 표시이므로 둘 다 버림
+                    match self.chars.get(self.pos) {
+                        Some('\n') => {}
+                        Some(&escaped) => value.push(escaped),
+                        None => continue,
+                    }
+                    self.pos += 1;
+                }
+                _ => value.push(c),
+            }
+        }
+    }
+
+    /// 따라가지 않는 변화를 통째로 건너뜀
+    fn skip_tree(&mut self) -> Result<(), RecordError> {
+        let mut nodes = Vec::new();
+        self.tree(&mut nodes)
+    }
+
+    /// 공백을 건너뛴 다음 글자
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    /// 공백을 건너뛴 다음 글자가 `expected`인지 확인하고 넘어감
+    fn expect(&mut self, expected: char) -> Result<(), RecordError> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(RecordError::InvalidSgf(format!(
+                "expected '{expected}', found '{c}'"
+            ))),
+            None => Err(RecordError::InvalidSgf(format!(
+                "expected '{expected}', found end of text"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 흑과 백이 번갈아 두다가 백이 차례를 잃은 9x13 판의 기보
+    fn record() -> Record {
+        let mut record = Record::new(9, 13, RuleSet::Renju);
+        record.black = "Player 1".to_string();
+        record.white = "AI [Hard]".to_string();
+        let stone = |color, x, y| Move { color, x, y };
+        record.moves = vec![
+            stone(StoneColor::Black, 4, 6),
+            stone(StoneColor::White, 5, 7),
+            stone(StoneColor::Black, 4, 7),
+            stone(StoneColor::Black, 4, 8),
+            stone(StoneColor::White, 0, 12),
+            stone(StoneColor::Black, 8, 0),
+        ];
+        record.result = Some(GameResult::Win(StoneColor::White));
+        record.started = Some(1_792_315_800);
+        record.finished = Some(1_792_316_472);
+        record
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!(parse_point("h8"), Some((7, 7)));
+        assert_eq!(parse_point("A1"), Some((0, 0)));
+        assert_eq!(parse_point("o15"), Some((14, 14)));
+        for token in ["h+8", "h-8", "h 8", "h8a", "h", "h0", "8h", "", "\u{e9}8"] {
+            assert_eq!(parse_point(token), None, "{token}");
+        }
+        for (x, y) in [(0, 0), (7, 7), (8, 12)] {
+            assert_eq!(parse_point(&format_point(x, y)), Some((x, y)));
+        }
+    }
+
+    #[test]
+    fn parses_moves_with_passes() {
+        let moves = parse_moves("h8 PASS i9 pass").unwrap();
+        assert_eq!(
+            moves,
+            [
+                Move {
+                    color: StoneColor::Black,
+                    x: 7,
+                    y: 7
+                },
+                Move {
+                    color: StoneColor::Black,
+                    x: 8,
+                    y: 8
+                },
+            ]
+        );
+        assert_eq!(format_moves(&moves), "h8 pass i9");
+        assert_eq!(parse_moves("h8 i+9"), Err("i+9".to_string()));
+    }
+
+    #[test]
+    fn round_trips_text() {
+        let record = record();
+        let text = record.to_text();
+        assert!(text.contains("# Size: 9x13\n"));
+        assert!(text.contains("# Result: 0-1\n"));
+        assert!(text.ends_with("e7 f8 e8 pass e9 a13 i1\n"));
+        assert_eq!(Record::parse(&text), Ok(record));
+    }
+
+    #[test]
+    fn round_trips_sgf() {
+        let record = record();
+        let sgf = record.to_sgf();
+        assert!(sgf.contains("SZ[9:13]"));
+        assert!(sgf.contains("PW[AI [Hard\\]]"));
+        assert!(sgf.contains("RE[W+]"));
+        assert!(sgf.contains("DT[2026-10-18]"));
+        // 흑이 연달아 두었으므로 사이에 백의 빈 수, y는 위에서부터 셈
+        assert!(sgf.contains(";B[ef]\n;W[]\n;B[ee]\n;W[aa]\n;B[im]"));
+        assert_eq!(Record::parse(&sgf), Ok(record));
+    }
+
+    #[test]
+    fn round_trips_results() {
+        for result in [
+            None,
+            Some(GameResult::Draw),
+            Some(GameResult::Win(StoneColor::Black)),
+        ] {
+            let mut record = Record::new(15, 15, RuleSet::Caro);
+            record.result = result;
+            assert_eq!(Record::parse(&record.to_text()), Ok(record.clone()));
+            assert_eq!(Record::parse(&record.to_sgf()), Ok(record));
+        }
+    }
+
+    #[test]
+    fn reads_other_sgf() {
+        // 날짜만 있는 `DT`, `tt` 차례 넘김, 따라가지 않는 변화
+        let sgf = "(;GM[4]SZ[15]DT[2026-10-18]RE[Draw];B[hh](;W[tt];B[ii])(;W[aa]))";
+        let record = Record::parse(sgf).unwrap();
+        assert_eq!(record.started, Some(1_792_281_600));
+        assert_eq!(record.result, Some(GameResult::Draw));
+        assert_eq!(format_moves(&record.moves), "h8 pass i7");
+
+        assert_eq!(
+            Record::parse("(;GM[1]SZ[19])"),
+            Err(RecordError::UnsupportedGame("1".to_string()))
+        );
+        assert_eq!(
+            Record::parse("(;GM[4]SZ[9];B[jj])"),
+            Err(RecordError::InvalidPoint("jj".to_string()))
+        );
+        assert!(matches!(
+            Record::parse("(;GM[4];B[hh]"),
+            Err(RecordError::InvalidSgf(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_text() {
+        assert_eq!(
+            Record::parse("# Size: 9x9\ne5 j5\n"),
+            Err(RecordError::InvalidPoint("j5".to_string()))
+        );
+        assert_eq!(
+            Record::parse("# Size: 9\n"),
+            Err(RecordError::InvalidHeader(1))
+        );
+        assert_eq!(
+            Record::parse("# Size: 0x9\n"),
+            Err(RecordError::InvalidSize)
+        );
+        assert_eq!(
+            Record::parse("# Size: 9x9\ne+5\n"),
+            Err(RecordError::InvalidPoint("e+5".to_string()))
+        );
+    }
+
+    #[test]
+    fn round_trips_timestamps() {
+        for seconds in [0, 951_782_400, 1_792_316_472] {
+            assert_eq!(parse_timestamp(&format_timestamp(seconds)), Some(seconds));
+        }
+        assert_eq!(format_timestamp(1_792_316_472), "2026-10-18T09:41:12Z");
+        assert_eq!(parse_timestamp("2026-10-18 09:41"), Some(1_792_316_460));
+        assert_eq!(parse_timestamp("2026-13-01"), None);
+    }
+}
@@ -0,0 +1,474 @@
+//! RenLib 라이브러리 (`.lib`) 읽기
+//!
+//! RenLib은 렌주 연구 자료를 변화도(수순의 나무)로 모아 두는 프로그램이며,
+//! 라이브러리 파일은 15x15 판 전용의 이진 형식입니다. Bevy에 의존하지 않습니다.
+//!
+//! # 파일 형식 (버전 3)
+//!
+//! - 머리 20바이트: `FF "RenLib" FF`, 주 버전, 부 버전, 나머지 10바이트는 예약
+//! - 이어서 노드가 전위 순회 순서로 2바이트씩 (자리 바이트, 플래그 바이트) 적힙니다.
+//!   - 자리 바이트: 하위 4비트에서 1을 뺀 값이 열(0 = a), 상위 4비트가 위에서부터 센 행입니다.
+//!     0이면 수가 없는 노드이며, 첫 노드가 이렇다면 빈 판(뿌리)을 나타냅니다.
+//!   - 플래그: `DOWN`(첫 자식이 바로 뒤에 옴), `RIGHT`(자식들 다음에 다음 형제가 옴),
+//!     `COMMENT`/`OLD_COMMENT`(설명), `MARK`(표시한 수), `START`(처음 보여 줄 국면),
+//!     `EXTENSION`(플래그 2바이트가 더 붙음, 비트 16~23과 8~15 순서). `BOARD_TEXT`는 확장 비트입니다.
+//! - 설명과 판 글자는 노드 바로 뒤에 (설명 먼저) 0으로 끝나는 문자열로 적히며,
+//!   노드와 같이 2바이트 단위로 채워집니다. UTF-8이 아니면 Latin-1로 읽습니다.
+//!
+//! 같은 부모의 자식 중 처음 적힌 것이 주 변화입니다.
+
+use std::fmt;
+
+use super::engine::{Move, StoneColor};
+
+/// RenLib 라이브러리의 판 크기 (가로, 세로 모두)
+pub const LIBRARY_SIZE: usize = 15;
+
+/// 파일 첫 8바이트
+const MAGIC: &[u8; 8] = b"\xFFRenLib\xFF";
+
+/// 머리의 전체 길이
+const HEADER_LEN: usize = 20;
+
+/// 읽을 수 있는 주 버전
+const SUPPORTED_MAJOR: u8 = 3;
+
+// 노드 플래그
+const DOWN: u32 = 0x80;
+const RIGHT: u32 = 0x40;
+const OLD_COMMENT: u32 = 0x20;
+const MARK: u32 = 0x10;
+const COMMENT: u32 = 0x08;
+const START: u32 = 0x04;
+const EXTENSION: u32 = 0x01;
+const BOARD_TEXT: u32 = 0x100;
+
+// ==================== 오류 ====================
+/// 라이브러리를 읽을 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LibraryError {
+    /// RenLib 파일 머리가 아님
+    MissingHeader,
+    /// 지원하지 않는 형식 버전 (주 버전, 부 버전)
+    UnsupportedVersion(u8, u8),
+    /// 노드나 문자열 중간에 파일이 끝남 (바이트 위치)
+    Truncated(usize),
+    /// 판 밖을 가리키는 자리 바이트 (바이트 위치, 값)
+    InvalidPoint(usize, u8),
+}
+
+impl fmt::Display for LibraryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LibraryError::MissingHeader => write!(f, "not a RenLib file"),
+            LibraryError::UnsupportedVersion(major, minor) => {
+                write!(f, "unsupported RenLib version {major}.{minor}")
+            }
+            LibraryError::Truncated(offset) => write!(f, "file ends unexpectedly at byte {offset}"),
+            LibraryError::InvalidPoint(offset, value) => {
+                write!(f, "byte {offset}: invalid point 0x{value:02x}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LibraryError {}
+
+// ==================== 변화도 ====================
+/// 변화도의 한 노드 (한 수와 그 수에 붙은 정보)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LibraryNode {
+    pub stone: Option<Move>,        // 둔 수 (뿌리이거나 차례를 넘긴 노드면 없음)
+    pub to_move: StoneColor,        // 이 노드 다음에 둘 차례
+    pub comment: Option<String>,    // 설명
+    pub board_text: Option<String>, // 이 수 자리에 표시할 판 글자 (예: `a`, `1`)
+    pub marked: bool,               // 표시한 수인지 여부
+    pub start: bool,                // 라이브러리를 열 때 처음 보여 줄 국면인지 여부
+    pub parent: Option<usize>,      // 부모 노드 번호 (뿌리면 없음)
+    pub children: Vec<usize>,       // 자식 노드 번호 (첫째가 주 변화)
+}
+
+impl LibraryNode {
+    /// 수가 없는 노드 생성
+    fn empty(to_move: StoneColor, parent: Option<usize>) -> Self {
+        Self {
+            stone: None,
+            to_move,
+            comment: None,
+            board_text: None,
+            marked: false,
+            start: false,
+            parent,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// RenLib 라이브러리의 변화도
+///
+/// 노드는 번호로 가리키며 0번이 빈 판(뿌리)입니다.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Library {
+    version: (u8, u8),       // 파일 형식 버전 (주, 부)
+    nodes: Vec<LibraryNode>, // 전위 순회 순서의 노드 (0번이 뿌리)
+}
+
+impl Library {
+    /// 뿌리 노드 번호
+    pub const ROOT: usize = 0;
+
+    /// 파일 형식 버전 (주, 부)
+    pub fn version(&self) -> (u8, u8) {
+        self.version
+    }
+
+    /// 뿌리를 뺀 노드 수
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// 뿌리 말고 노드가 없는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `id`번 노드
+    pub fn node(&self, id: usize) -> &LibraryNode {
+        &self.nodes[id]
+    }
+
+    /// `id`번 노드의 자식 (번호, 노드)
+    pub fn children(&self, id: usize) -> impl Iterator<Item = (usize, &LibraryNode)> + '_ {
+        self.nodes[id]
+            .children
+            .iter()
+            .map(|&child| (child, &self.nodes[child]))
+    }
+
+    /// 뿌리에서 `id`번 노드까지 둔 수
+    pub fn path(&self, id: usize) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let mut current = Some(id);
+        while let Some(node) = current.map(|id| &self.nodes[id]) {
+            moves.extend(node.stone);
+            current = node.parent;
+        }
+        moves.reverse();
+        moves
+    }
+
+    /// 뿌리에서 `moves`를 차례로 따라간 노드 (변화도에 없는 수가 있으면 `None`)
+    ///
+    /// 차례를 넘긴 노드는 다음 수의 색이 맞을 때 건너뜁니다.
+    pub fn find(&self, moves: &[Move]) -> Option<usize> {
+        let mut current = Self::ROOT;
+        for stone in moves {
+            current = self.find_child(current, stone)?;
+        }
+        Some(current)
+    }
+
+    /// `id`번 노드의 자식 중 `stone`을 둔 노드 (차례를 넘긴 자식 아래도 찾음)
+    fn find_child(&self, id: usize, stone: &Move) -> Option<usize> {
+        self.children(id)
+            .find_map(|(child, node)| match node.stone {
+                Some(child_stone) if child_stone == *stone => Some(child),
+                None if node.to_move == stone.color => self.find_child(child, stone),
+                _ => None,
+            })
+    }
+
+    /// 뿌리에서 잎까지의 모든 수순 (주 변화부터)
+    pub fn lines(&self) -> Vec<Vec<Move>> {
+        (0..self.nodes.len())
+            .filter(|&id| id != Self::ROOT && self.nodes[id].children.is_empty())
+            .map(|id| self.path(id))
+            .collect()
+    }
+
+    /// RenLib 파일 내용을 읽음
+    pub fn parse(bytes: &[u8]) -> Result<Self, LibraryError> {
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(LibraryError::MissingHeader);
+        }
+        let version = (bytes[8], bytes[9]);
+        if version.0 != SUPPORTED_MAJOR {
+            return Err(LibraryError::UnsupportedVersion(version.0, version.1));
+        }
+
+        let mut reader = Reader {
+            bytes,
+            pos: HEADER_LEN,
+        };
+        let mut nodes = vec![LibraryNode::empty(StoneColor::Black, None)];
+        let mut pending = Vec::new(); // 자식들 다음에 형제가 올 노드
+        let mut parent = Self::ROOT; // 다음 노드의 부모
+        let mut first = true;
+        while !reader.is_done() {
+            let offset = reader.pos;
+            let (point, flags) = reader.node()?;
+            let comment = if flags & (COMMENT | OLD_COMMENT) != 0 {
+                Some(reader.string()?)
+            } else {
+                None
+            };
+            let board_text = if flags & BOARD_TEXT != 0 {
+                Some(reader.string()?)
+            } else {
+                None
+            };
+
+            // 수가 없는 첫 노드는 뿌리 자체
+            let id = if first && point == 0 {
+                Self::ROOT
+            } else {
+                let to_move = nodes[parent].to_move;
+                let stone = match point {
+                    0 => None,
+                    _ => {
+                        let (x, y) =
+                            decode_point(point).ok_or(LibraryError::InvalidPoint(offset, point))?;
+                        Some(Move {
+                            color: to_move,
+                            x,
+                            y,
+                        })
+                    }
+                };
+                let id = nodes.len();
+                nodes.push(LibraryNode {
+                    stone,
+                    ..LibraryNode::empty(to_move.opposite(), Some(parent))
+                });
+                nodes[parent].children.push(id);
+                id
+            };
+            first = false;
+
+            let node = &mut nodes[id];
+            node.comment = comment;
+            node.board_text = board_text;
+            node.marked = flags & MARK != 0;
+            node.start = flags & START != 0;
+
+            // 다음 노드의 부모 결정
+            if flags & DOWN != 0 {
+                if flags & RIGHT != 0 && id != Self::ROOT {
+                    pending.push(id);
+                }
+                parent = id;
+            } else if flags & RIGHT != 0 && id != Self::ROOT {
+                // 바로 다음이 형제이므로 부모는 그대로
+            } else {
+                match pending.pop() {
+                    Some(sibling) => parent = nodes[sibling].parent.unwrap_or(Self::ROOT),
+                    None => break, // 변화도 끝
+                }
+            }
+        }
+
+        Ok(Self { version, nodes })
+    }
+}
+
+/// 자리 바이트를 (x, y)로 변환 (y는 아래에서부터 셈)
+fn decode_point(point: u8) -> Option<(usize, usize)> {
+    let column = (point & 0x0F) as usize;
+    let row = (point >> 4) as usize;
+    if column == 0 || column > LIBRARY_SIZE || row >= LIBRARY_SIZE {
+        return None;
+    }
+    Some((column - 1, LIBRARY_SIZE - 1 - row))
+}
+
+/// 바이트 단위로 노드와 문자열을 읽는 도우미
+struct Reader<'a> {
+    bytes: &'a [u8], // 파일 전체
+    pos: usize,      // 다음에 읽을 위치
+}
+
+impl Reader<'_> {
+    /// 더 읽을 노드가 없는지 확인
+    fn is_done(&self) -> bool {
+        self.pos + 2 > self.bytes.len()
+    }
+
+    /// 2바이트 읽기
+    fn pair(&mut self) -> Result<(u8, u8), LibraryError> {
+        match self.bytes.get(self.pos..self.pos + 2) {
+            Some(&[a, b]) => {
+                self.pos += 2;
+                Ok((a, b))
+            }
+            _ => Err(LibraryError::Truncated(self.pos)),
+        }
+    }
+
+    /// 노드 하나 (자리 바이트, 확장까지 합친 플래그)
+    fn node(&mut self) -> Result<(u8, u32), LibraryError> {
+        let (point, flags) = self.pair()?;
+        let mut flags = u32::from(flags);
+        if flags & EXTENSION != 0 {
+            let (high, middle) = self.pair()?;
+            flags |= u32::from(high) << 16 | u32::from(middle) << 8;
+        }
+        Ok((point, flags))
+    }
+
+    /// 0으로 끝나는 문자열 (2바이트 단위로 채워짐)
+    fn string(&mut self) -> Result<String, LibraryError> {
+        let mut text = Vec::new();
+        loop {
+            let (a, b) = self.pair()?;
+            if a == 0 {
+                break;
+            }
+            text.push(a);
+            if b == 0 {
+                break;
+            }
+            text.push(b);
+        }
+        let text = String::from_utf8(text)
+            .unwrap_or_else(|err| err.into_bytes().iter().map(|&b| b as char).collect());
+        Ok(text.replace("\r\n", "\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 주어진 형식 버전의 머리 20바이트
+    fn header(major: u8, minor: u8) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([major, minor]);
+        bytes.resize(HEADER_LEN, 0);
+        bytes
+    }
+
+    /// 손으로 만든 라이브러리
+    ///
+    /// ```text
+    /// 뿌리 "Root"
+    /// ├─ h8 ─ i9 (표시) ─ j10
+    /// └─ a1 (시작, "café", 판 글자 "a") ─ 차례 넘김 ─ o15
+    /// ```
+    fn fixture() -> Vec<u8> {
+        let mut bytes = header(3, 0);
+        bytes.extend([0x00, (DOWN | COMMENT) as u8]);
+        bytes.extend(b"Root\0\0");
+        bytes.extend([0x78, (DOWN | RIGHT) as u8]);
+        bytes.extend([0x69, (DOWN | MARK) as u8]);
+        bytes.extend([0x5A, 0x00]);
+        bytes.extend([0xE1, (DOWN | START | COMMENT | EXTENSION) as u8, 0x00, 0x01]);
+        bytes.extend(b"caf\xE9\0\0");
+        bytes.extend(b"a\0");
+        bytes.extend([0x00, DOWN as u8]);
+        bytes.extend([0x0F, 0x00]);
+        bytes
+    }
+
+    fn stone(color: StoneColor, x: usize, y: usize) -> Move {
+        Move { color, x, y }
+    }
+
+    #[test]
+    fn decodes_points_from_the_top() {
+        assert_eq!(decode_point(0x78), Some((7, 7)));
+        assert_eq!(decode_point(0xE1), Some((0, 0)));
+        assert_eq!(decode_point(0x0F), Some((14, 14)));
+        assert_eq!(decode_point(0xEF), Some((14, 0)));
+        assert_eq!(decode_point(0x10), None);
+        assert_eq!(decode_point(0xF1), None);
+    }
+
+    #[test]
+    fn reads_the_variation_tree() {
+        let library = Library::parse(&fixture()).unwrap();
+        assert_eq!(library.version(), (3, 0));
+        assert_eq!(library.len(), 6);
+
+        let root = library.node(Library::ROOT);
+        assert_eq!(root.comment.as_deref(), Some("Root"));
+        assert_eq!(root.children, [1, 4]);
+
+        let (black, white) = (StoneColor::Black, StoneColor::White);
+        assert_eq!(library.node(1).stone, Some(stone(black, 7, 7)));
+        assert_eq!(library.node(2).stone, Some(stone(white, 8, 8)));
+        assert!(library.node(2).marked);
+        assert_eq!(library.node(3).stone, Some(stone(black, 9, 9)));
+        assert_eq!(library.node(3).parent, Some(2));
+
+        let a1 = library.node(4);
+        assert_eq!(a1.stone, Some(stone(black, 0, 0)));
+        assert_eq!(a1.comment.as_deref(), Some("café"));
+        assert_eq!(a1.board_text.as_deref(), Some("a"));
+        assert!(a1.start && !a1.marked);
+        assert_eq!(library.node(5).stone, None);
+        assert_eq!(library.node(5).to_move, black);
+        assert_eq!(library.node(6).stone, Some(stone(black, 14, 14)));
+
+        assert_eq!(
+            library.lines(),
+            [
+                vec![stone(black, 7, 7), stone(white, 8, 8), stone(black, 9, 9)],
+                vec![stone(black, 0, 0), stone(black, 14, 14)],
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_lines_through_passes() {
+        let library = Library::parse(&fixture()).unwrap();
+        let (black, white) = (StoneColor::Black, StoneColor::White);
+        assert_eq!(library.find(&[]), Some(Library::ROOT));
+        assert_eq!(
+            library.find(&[stone(black, 7, 7), stone(white, 8, 8)]),
+            Some(2)
+        );
+        assert_eq!(
+            library.find(&[stone(black, 0, 0), stone(black, 14, 14)]),
+            Some(6)
+        );
+        assert_eq!(library.find(&[stone(white, 7, 7)]), None);
+        assert_eq!(library.path(6), library.lines()[1]);
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        let mut bytes = fixture();
+        bytes[1] = b'r';
+        assert_eq!(Library::parse(&bytes), Err(LibraryError::MissingHeader));
+        assert_eq!(
+            Library::parse(&fixture()[..HEADER_LEN - 1]),
+            Err(LibraryError::MissingHeader)
+        );
+        assert_eq!(
+            Library::parse(&header(2, 5)),
+            Err(LibraryError::UnsupportedVersion(2, 5))
+        );
+        let empty = Library::parse(&header(3, 4)).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.version(), (3, 4));
+    }
+
+    #[test]
+    fn rejects_broken_nodes() {
+        let mut bytes = header(3, 0);
+        bytes.extend([0x00, DOWN as u8, 0x10, 0x00]);
+        assert_eq!(
+            Library::parse(&bytes),
+            Err(LibraryError::InvalidPoint(HEADER_LEN + 2, 0x10))
+        );
+
+        // 설명 중간에 끝남
+        let mut bytes = header(3, 0);
+        bytes.extend([0x78, COMMENT as u8]);
+        bytes.extend(b"abc");
+        assert_eq!(
+            Library::parse(&bytes),
+            Err(LibraryError::Truncated(HEADER_LEN + 4))
+        );
+    }
+}
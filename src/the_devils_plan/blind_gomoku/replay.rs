@@ -0,0 +1,398 @@
+//! 기보 재생 시스템
+//!
+//! 기보 파일은 Bevy에 의존하지 않는 `record` 모듈이 읽고,
+//! 이 모듈은 불러온 기보를 빈 판부터 한 수씩 보여 주는 재생 창과 그 입력을 처리합니다.
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use std::time::Duration;
+
+use super::blind::BlindGame;
+use super::engine::{Game, MoveError};
+use super::opening::Player;
+use super::record::{self, Record};
+use super::{
+    latest_record, BoardConfig, BoardPainter, FeedbackDisplay, GameSetup, GameState, Stone,
+    BUTTON_COLOR, DIALOG_COLOR, SELECTED_BUTTON_COLOR, TEXT_COLOR,
+};
+
+// ==================== 재생 상수 정의 ====================
+const REPLAY_SPEEDS: [f32; 5] = [0.5, 1.0, 2.0, 4.0, 8.0]; // 기보 자동 재생 속도 (초당 수)
+const REPLAY_DEFAULT_SPEED: usize = 1; // 처음 쓰는 자동 재생 속도 (`REPLAY_SPEEDS`의 번호)
+const REPLAY_SLIDER_SIZE: Vec2 = Vec2::new(240.0, 12.0); // 기보 재생 막대 크기
+
+// ==================== 재생 상태 리소스 ====================
+/// 재생 중인 기보를 관리하는 리소스 (I 키로 시작, 재생 중이 아니면 `None`)
+///
+/// 재생하는 동안 `GameState`는 현재 수까지 둔 국면이며, 판을 바꾸는 입력은 막힙니다.
+#[derive(Resource, Default)]
+pub(super) struct ReplayState(Option<Replay>);
+
+impl ReplayState {
+    /// 기보를 재생하고 있는지 여부
+    pub(super) fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// 기보 재생 상태
+struct Replay {
+    record: Record, // 재생 중인 기보
+    ply: usize,     // 판에 놓인 수의 개수 (0이면 빈 판)
+    autoplay: bool, // 자동 재생 중인지 여부
+    speed: usize,   // 자동 재생 속도 (`REPLAY_SPEEDS`의 번호)
+    timer: Timer,   // 자동 재생에서 다음 수까지의 시간
+}
+
+impl Replay {
+    /// 빈 판에서 시작하는 재생 상태
+    fn new(record: Record) -> Self {
+        Self {
+            record,
+            ply: 0,
+            autoplay: false,
+            speed: REPLAY_DEFAULT_SPEED,
+            timer: Timer::from_seconds(
+                1.0 / REPLAY_SPEEDS[REPLAY_DEFAULT_SPEED],
+                TimerMode::Repeating,
+            ),
+        }
+    }
+
+    /// 기보의 처음 `ply`수까지 둔 국면 (모든 돌이 공개됨, 마지막 수에서만 결과를 반영)
+    fn game_at(&self, ply: usize) -> Result<BlindGame, MoveError> {
+        let record = &self.record;
+        let partial = Record {
+            moves: record.moves[..ply].to_vec(),
+            result: if ply == record.moves.len() {
+                record.result
+            } else {
+                None
+            },
+            ..Record::new(record.width, record.height, record.rule_set)
+        };
+        let mut game = BlindGame::with_game(
+            Game::with_size(record.width, record.height).with_rule_set(record.rule_set),
+        );
+        game.play_public(|game| partial.replay(game))?;
+        Ok(game)
+    }
+}
+
+/// 기보를 재생하고 있지 않은지 확인하는 실행 조건
+pub(super) fn not_replaying(replay: Res<ReplayState>) -> bool {
+    !replay.is_active()
+}
+
+// ==================== 컴포넌트 정의 ====================
+/// 기보 재생 창을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ReplayDisplay;
+
+/// 기보 재생 창의 안내 문구를 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ReplayText;
+
+/// 기보 재생 막대(누르거나 끌어서 원하는 수로 이동)를 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ReplaySlider;
+
+/// 기보 재생 막대에서 현재 수까지 채워진 부분을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ReplaySliderFill;
+
+/// 돌 위에 표시한 수 번호를 나타내는 컴포넌트 (기보 재생 중)
+#[derive(Component)]
+pub(super) struct MoveNumber;
+
+// ==================== 기보 재생 시스템 ====================
+/// I 키로 `RECORD_DIR` 폴더에서 가장 최근에 바뀐 기보를 빈 판부터 재생하기 시작하는 시스템
+///
+/// 판 크기와 규칙은 기보를 따르며, 재생 창에는 현재 수와 재생 막대가 표시됩니다.
+pub(super) fn start_replay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut replay: ResMut<ReplayState>,
+    mut setup: GameSetup,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyI) {
+        return;
+    }
+
+    let loaded = latest_record().and_then(|(path, record)| {
+        let config = BoardConfig::new(record.width, record.height);
+        if (config.width, config.height) != (record.width, record.height) {
+            return Err(format!(
+                "{}: {}x{} boards are not supported",
+                path.display(),
+                record.width,
+                record.height
+            ));
+        }
+        // 끝까지 둘 수 있는 기보인지 미리 확인 (중간 국면은 그 앞부분이므로 항상 둘 수 있음)
+        let started = Replay::new(record);
+        let game = started
+            .game_at(started.record.moves.len())
+            .and_then(|_| started.game_at(0))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok((path, started, config, game))
+    });
+    let message = match loaded {
+        Ok((path, started, config, game)) => {
+            // 이전 게임의 엔티티를 지우고 빈 판에서 시작 (돌은 sync_replay_stones가 생성)
+            setup.load(config, game, Player::First);
+            let message = format!(
+                "Replaying {} ({} vs {})",
+                path.display(),
+                started.record.black,
+                started.record.white
+            );
+            replay.0 = Some(started);
+            spawn_replay_display(&mut setup.commands);
+            message
+        }
+        Err(err) => format!("Could not replay a game: {err}"),
+    };
+    setup.show(message);
+}
+
+/// 기보 재생 창 UI 생성 (안내 문구와 재생 막대, 내용은 sync_replay_stones에서 채움)
+fn spawn_replay_display(commands: &mut Commands) {
+    commands
+        .spawn((
+            ReplayDisplay,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(100.0),
+                left: Val::Px(50.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(DIALOG_COLOR),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                ReplayText,
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(REPLAY_SLIDER_SIZE.x),
+                        height: Val::Px(REPLAY_SLIDER_SIZE.y),
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_COLOR),
+                    Interaction::default(),
+                    RelativeCursorPosition::default(),
+                    ReplaySlider,
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(SELECTED_BUTTON_COLOR),
+                    ReplaySliderFill,
+                ));
+        });
+}
+
+/// 기보 재생 중에 키보드와 재생 막대로 현재 수를 옮기고 자동 재생을 진행하는 시스템
+///
+/// ←/→: 한 수씩, Home/End: 처음/끝, Space: 자동 재생, ↑/↓: 자동 재생 속도,
+/// 재생 막대를 누르거나 끌면 그 위치의 수로 이동합니다.
+pub(super) fn control_replay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut replay_state: ResMut<ReplayState>,
+    mut game_state: ResMut<GameState>,
+    slider: Query<(&Interaction, &RelativeCursorPosition), With<ReplaySlider>>,
+) {
+    // 타이머는 매 프레임 진행하므로 실제로 바뀐 것이 있을 때만 변경을 알림
+    let Some(replay) = replay_state.bypass_change_detection().0.as_mut() else {
+        return;
+    };
+    let last = replay.record.moves.len();
+    let mut ply = replay.ply;
+    let mut changed = false;
+
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        ply = ply.saturating_sub(1);
+        replay.autoplay = false;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        ply = (ply + 1).min(last);
+        replay.autoplay = false;
+    }
+    if keyboard.just_pressed(KeyCode::Home) {
+        ply = 0;
+        replay.autoplay = false;
+    }
+    if keyboard.just_pressed(KeyCode::End) {
+        ply = last;
+        replay.autoplay = false;
+    }
+    if let Ok((Interaction::Pressed, cursor)) = slider.single() {
+        if let Some(position) = cursor.normalized {
+            ply = (position.x.clamp(0.0, 1.0) * last as f32).round() as usize;
+            replay.autoplay = false;
+        }
+    }
+
+    // 자동 재생 (끝에서 시작하면 처음부터)
+    if keyboard.just_pressed(KeyCode::Space) {
+        replay.autoplay = !replay.autoplay;
+        if replay.autoplay && ply == last {
+            ply = 0;
+        }
+        replay.timer.reset();
+        changed = true;
+    }
+    let speed = if keyboard.just_pressed(KeyCode::ArrowUp) {
+        (replay.speed + 1).min(REPLAY_SPEEDS.len() - 1)
+    } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+        replay.speed.saturating_sub(1)
+    } else {
+        replay.speed
+    };
+    if speed != replay.speed {
+        replay.speed = speed;
+        replay
+            .timer
+            .set_duration(Duration::from_secs_f32(1.0 / REPLAY_SPEEDS[speed]));
+        changed = true;
+    }
+    if replay.autoplay && replay.timer.tick(time.delta()).just_finished() {
+        ply = (ply + 1).min(last);
+        replay.autoplay = ply < last;
+        changed = true;
+    }
+
+    if ply != replay.ply {
+        match replay.game_at(ply) {
+            Ok(game) => {
+                replay.ply = ply;
+                **game_state = game;
+            }
+            Err(_) => replay.autoplay = false, // 재생을 시작할 때 확인했으므로 일어나지 않음
+        }
+        changed = true;
+    }
+    if changed {
+        replay_state.set_changed();
+    }
+}
+
+/// 기보 재생을 끝낼 때 지워야 하는 엔티티 필터
+type ReplayEntityFilter = Or<(With<ReplayDisplay>, With<MoveNumber>)>;
+
+/// Esc 키로 기보 재생을 끝내고 현재 국면에서 이어서 두는 시스템
+pub(super) fn stop_replay(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut replay: ResMut<ReplayState>,
+    displays: Query<Entity, ReplayEntityFilter>,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    let Some(stopped) = replay.0.take() else {
+        return;
+    };
+
+    for entity in &displays {
+        commands.entity(entity).despawn();
+    }
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = format!("Playing on from move {}", stopped.ply);
+    }
+}
+
+/// 기보 재생의 현재 수에 맞춰 돌 엔티티를 만들거나 지우고, 수 번호와 재생 창을 갱신하는 시스템
+pub(super) fn sync_replay_stones(
+    mut painter: BoardPainter,
+    replay: Res<ReplayState>,
+    game_state: Res<GameState>,
+    stones: Query<(Entity, &Stone)>,
+    numbers: Query<Entity, With<MoveNumber>>,
+    mut replay_text: Query<&mut Text, With<ReplayText>>,
+    mut slider_fill: Query<&mut Node, With<ReplaySliderFill>>,
+) {
+    let Some(replay) = &replay.0 else {
+        return;
+    };
+    let board = game_state.game().board();
+
+    // 현재 국면에 없는 돌은 지우고, 아직 엔티티가 없는 돌은 생성
+    let mut shown = Vec::new();
+    for (entity, stone) in &stones {
+        let (x, y) = stone.grid_pos;
+        if board.get(x, y) == Some(stone.color) {
+            shown.push(stone.grid_pos);
+        } else {
+            painter.commands.entity(entity).despawn();
+        }
+    }
+    for (x, y, color) in board.stones() {
+        if !shown.contains(&(x, y)) {
+            painter.spawn_stone(color, (x, y));
+        }
+    }
+
+    // 수 번호 (돌과 반대 색으로)
+    for entity in &numbers {
+        painter.commands.entity(entity).despawn();
+    }
+    for (index, stone) in replay.record.moves[..replay.ply].iter().enumerate() {
+        let position = painter.config.grid_to_world(stone.x, stone.y);
+        painter.commands.spawn((
+            Text2d::new((index + 1).to_string()),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(stone.color.opposite().to_color()),
+            Transform::from_translation(position.extend(3.0)), // 마지막 수 표시보다 위
+            MoveNumber,
+        ));
+    }
+
+    // 재생 창
+    let last = replay.record.moves.len();
+    if let Ok(mut text) = replay_text.single_mut() {
+        let result = if replay.ply == last {
+            format!(" ({})", record::format_result(replay.record.result))
+        } else {
+            String::new()
+        };
+        **text = format!(
+            "Replay: {} vs {}\nMove {}/{}{}, {} moves/s{}\n\
+             Left/Right: step, Home/End, Space: play, Up/Down: speed\n\
+             Esc: play on from here",
+            replay.record.black,
+            replay.record.white,
+            replay.ply,
+            last,
+            result,
+            REPLAY_SPEEDS[replay.speed],
+            if replay.autoplay { " (playing)" } else { "" }
+        );
+    }
+    if let Ok(mut node) = slider_fill.single_mut() {
+        let fraction = if last == 0 {
+            1.0
+        } else {
+            replay.ply as f32 / last as f32
+        };
+        node.width = Val::Percent(fraction * 100.0);
+    }
+}
@@ -0,0 +1,50 @@
+//! 재현 가능한 의사 난수 생성기
+//!
+//! 같은 시드에서는 항상 같은 수열을 만들므로 AI의 동작을 테스트에서 재현할 수 있습니다.
+//! 외부 크레이트 없이 SplitMix64 알고리즘을 사용합니다.
+
+/// SplitMix64 의사 난수 생성기
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Rng {
+    state: u64, // 내부 상태 (매 호출마다 일정한 값만큼 증가)
+}
+
+impl Rng {
+    /// 시드로 생성기 생성
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// 현재 내부 상태 (이 값을 시드로 새로 만들면 같은 수열을 이어서 만듦)
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    /// 다음 64비트 난수
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        mix(self.state)
+    }
+
+    /// `0..n` 범위의 난수 (`n`이 0이면 0)
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// `percent`% 확률로 참
+    pub fn chance(&mut self, percent: u32) -> bool {
+        self.below(100) < percent as usize
+    }
+}
+
+/// 64비트 값을 골고루 섞음 (SplitMix64의 마무리 단계)
+///
+/// 난수 생성 외에도 좌표나 판 상태를 해시할 때 사용합니다.
+pub fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
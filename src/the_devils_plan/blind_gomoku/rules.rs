@@ -0,0 +1,481 @@
+//! 오목 규칙 모음 (자유룰, 표준룰, 렌주룰, 카로룰)
+//!
+//! 승리 조건과 렌주룰의 흑 금수(3-3, 4-4, 장목) 판정을 담당합니다.
+//! Bevy에 의존하지 않습니다.
+
+use std::fmt;
+
+use super::engine::{Board, StoneColor, DIRECTIONS, WIN_LENGTH};
+
+// ==================== 규칙 종류 ====================
+/// 게임마다 선택할 수 있는 오목 규칙
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum RuleSet {
+    /// 자유룰: 5목 이상이면 승리
+    #[default]
+    Freestyle,
+    /// 표준룰: 정확히 5목이어야 승리 (장목은 승리가 아님)
+    Standard,
+    /// 렌주룰: 흑은 정확히 5목, 3-3 / 4-4 / 장목 금지. 백은 5목 이상이면 승리
+    Renju,
+    /// 카로룰: 양쪽 끝이 모두 상대 돌로 막히지 않은 5목 이상이면 승리
+    Caro,
+}
+
+impl RuleSet {
+    /// 다음 규칙 반환 (규칙 선택 키에서 사용)
+    pub fn next(self) -> Self {
+        match self {
+            RuleSet::Freestyle => RuleSet::Standard,
+            RuleSet::Standard => RuleSet::Renju,
+            RuleSet::Renju => RuleSet::Caro,
+            RuleSet::Caro => RuleSet::Freestyle,
+        }
+    }
+
+    /// (x, y)에 놓인 `color` 돌이 승리를 만들었는지 확인
+    ///
+    /// 돌은 이미 판에 놓여 있어야 합니다.
+    pub fn is_win(self, board: &Board, x: usize, y: usize, color: StoneColor) -> bool {
+        DIRECTIONS
+            .iter()
+            .any(|&(dx, dy)| self.wins_in_line(board, x, y, dx, dy, color))
+    }
+
+    /// (x, y)에 놓인 `color` 돌이 만든 승리 줄의 좌표 (한쪽 끝부터 순서대로)
+    ///
+    /// 승리가 아니면 `None`을 반환합니다. 돌은 이미 판에 놓여 있어야 합니다.
+    pub fn winning_line(
+        self,
+        board: &Board,
+        x: usize,
+        y: usize,
+        color: StoneColor,
+    ) -> Option<Vec<(usize, usize)>> {
+        let &(dx, dy) = DIRECTIONS
+            .iter()
+            .find(|&&(dx, dy)| self.wins_in_line(board, x, y, dx, dy, color))?;
+        let backward = board.count_direction(x, y, -dx, -dy, color) as i32;
+        let forward = board.count_direction(x, y, dx, dy, color) as i32;
+        Some(
+            (-backward..=forward)
+                .map(|k| ((x as i32 + dx * k) as usize, (y as i32 + dy * k) as usize))
+                .collect(),
+        )
+    }
+
+    /// 한 방향의 연속된 돌이 이 규칙에서 승리 조건을 만족하는지 확인
+    fn wins_in_line(
+        self,
+        board: &Board,
+        x: usize,
+        y: usize,
+        dx: i32,
+        dy: i32,
+        color: StoneColor,
+    ) -> bool {
+        let forward = board.count_direction(x, y, dx, dy, color);
+        let backward = board.count_direction(x, y, -dx, -dy, color);
+        let length = 1 + forward + backward;
+
+        match self {
+            RuleSet::Freestyle => length >= WIN_LENGTH,
+            RuleSet::Standard => length == WIN_LENGTH,
+            RuleSet::Renju => match color {
+                StoneColor::Black => length == WIN_LENGTH,
+                StoneColor::White => length >= WIN_LENGTH,
+            },
+            RuleSet::Caro => {
+                // 연속된 돌 바로 바깥 칸이 상대 돌인지 확인 (판 끝은 막힌 것으로 보지 않음)
+                let blocked = |steps: usize, dx: i32, dy: i32| {
+                    let nx = x as i32 + dx * (steps as i32 + 1);
+                    let ny = y as i32 + dy * (steps as i32 + 1);
+                    board.in_bounds(nx, ny)
+                        && board.get(nx as usize, ny as usize) == Some(color.opposite())
+                };
+                length >= WIN_LENGTH && !(blocked(forward, dx, dy) && blocked(backward, -dx, -dy))
+            }
+        }
+    }
+
+    /// `color`가 빈 자리 (x, y)에 두는 것이 금수인지 확인
+    ///
+    /// 렌주룰의 흑에게만 금수가 있으며, 5목을 만드는 수는 항상 허용됩니다.
+    pub fn forbidden(
+        self,
+        board: &Board,
+        x: usize,
+        y: usize,
+        color: StoneColor,
+    ) -> Option<Forbidden> {
+        if self != RuleSet::Renju || color != StoneColor::Black || board.get(x, y).is_some() {
+            return None;
+        }
+        renju::forbidden_at(&mut board.clone(), x, y)
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleSet::Freestyle => write!(f, "Freestyle"),
+            RuleSet::Standard => write!(f, "Standard"),
+            RuleSet::Renju => write!(f, "Renju"),
+            RuleSet::Caro => write!(f, "Caro"),
+        }
+    }
+}
+
+impl std::str::FromStr for RuleSet {
+    type Err = String;
+
+    /// 표시 이름을 대소문자 구분 없이 읽음 (예: `renju`)
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut rule_set = RuleSet::default();
+        loop {
+            if rule_set.to_string().eq_ignore_ascii_case(text) {
+                return Ok(rule_set);
+            }
+            rule_set = rule_set.next();
+            if rule_set == RuleSet::default() {
+                return Err(format!("unknown rule set \"{text}\""));
+            }
+        }
+    }
+}
+
+// ==================== 금수 종류 ====================
+/// 렌주룰에서 흑에게 금지된 수의 종류
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Forbidden {
+    /// 열린 3이 두 개 이상 동시에 생기는 수
+    DoubleThree,
+    /// 4가 두 개 이상 동시에 생기는 수
+    DoubleFour,
+    /// 6목 이상이 되는 수
+    Overline,
+}
+
+impl fmt::Display for Forbidden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Forbidden::DoubleThree => write!(f, "double-three"),
+            Forbidden::DoubleFour => write!(f, "double-four"),
+            Forbidden::Overline => write!(f, "overline"),
+        }
+    }
+}
+
+// ==================== 렌주 금수 판정 ====================
+/// 렌주룰 흑 금수 판정
+///
+/// 판을 직접 바꿔 가며 검사하지만, 함수가 끝나면 항상 원래 상태로 되돌립니다.
+mod renju {
+    use super::{Board, Forbidden, StoneColor, DIRECTIONS, WIN_LENGTH};
+
+    const BLACK: Option<StoneColor> = Some(StoneColor::Black);
+
+    /// 빈 자리 (x, y)에 흑을 두었을 때 금수인지 판정
+    pub(super) fn forbidden_at(board: &mut Board, x: usize, y: usize) -> Option<Forbidden> {
+        board.set(x, y, BLACK);
+        let result = classify(board, x, y);
+        board.set(x, y, None);
+        result
+    }
+
+    /// 방금 (x, y)에 놓인 흑돌이 금수인지 판정
+    ///
+    /// 정확한 5목은 금수보다 우선하며, 그다음 장목, 4-4, 3-3 순서로 확인합니다.
+    fn classify(board: &mut Board, x: usize, y: usize) -> Option<Forbidden> {
+        let lengths = DIRECTIONS.map(|(dx, dy)| {
+            1 + board.count_direction(x, y, dx, dy, StoneColor::Black)
+                + board.count_direction(x, y, -dx, -dy, StoneColor::Black)
+        });
+        if lengths.contains(&WIN_LENGTH) {
+            return None;
+        }
+        if lengths.iter().any(|&length| length > WIN_LENGTH) {
+            return Some(Forbidden::Overline);
+        }
+
+        let fours: usize = DIRECTIONS
+            .iter()
+            .map(|&(dx, dy)| count_fours(board, x, y, dx, dy))
+            .sum();
+        if fours >= 2 {
+            return Some(Forbidden::DoubleFour);
+        }
+
+        let threes = DIRECTIONS
+            .iter()
+            .filter(|&&(dx, dy)| is_open_three(board, x, y, dx, dy))
+            .count();
+        if threes >= 2 {
+            return Some(Forbidden::DoubleThree);
+        }
+
+        None
+    }
+
+    /// (x, y)를 지나는 한 방향에서, 흑을 하나 더 두면 (x, y)를 포함한 정확한 5목이 되는
+    /// 빈 자리들의 위치 (x, y로부터의 거리, 오름차순)
+    fn five_points(board: &mut Board, x: usize, y: usize, dx: i32, dy: i32) -> Vec<i32> {
+        let mut points = Vec::new();
+        for k in -(WIN_LENGTH as i32 - 1)..WIN_LENGTH as i32 {
+            let (qx, qy) = (x as i32 + dx * k, y as i32 + dy * k);
+            if k == 0 || !board.in_bounds(qx, qy) {
+                continue;
+            }
+            let (qx, qy) = (qx as usize, qy as usize);
+            if board.get(qx, qy).is_some() {
+                continue;
+            }
+
+            board.set(qx, qy, BLACK);
+            let forward = board.count_direction(qx, qy, dx, dy, StoneColor::Black) as i32;
+            let backward = board.count_direction(qx, qy, -dx, -dy, StoneColor::Black) as i32;
+            board.set(qx, qy, None);
+
+            // q를 지나는 연속된 돌이 정확히 5개이고 그 안에 (x, y)가 포함되어야 함
+            let contains_origin = k - backward <= 0 && 0 <= k + forward;
+            if 1 + forward + backward == WIN_LENGTH as i32 && contains_origin {
+                points.push(k);
+            }
+        }
+        points
+    }
+
+    /// 한 방향에서 (x, y)가 만드는 4의 개수
+    ///
+    /// 열린 4(`.XXXX.`)는 5목 자리가 두 개지만 하나의 4로 셉니다.
+    /// `X.XXX.X`처럼 한 줄에 서로 다른 4가 두 개 생기면 2로 셉니다.
+    fn count_fours(board: &mut Board, x: usize, y: usize, dx: i32, dy: i32) -> usize {
+        let points = five_points(board, x, y, dx, dy);
+        match points.as_slice() {
+            [first, second] if second - first == WIN_LENGTH as i32 => 1,
+            _ => points.len(),
+        }
+    }
+
+    /// 한 방향에서 (x, y)를 포함한 열린 4(양쪽 모두 5목이 되는 4)가 있는지 확인
+    fn is_straight_four(board: &mut Board, x: usize, y: usize, dx: i32, dy: i32) -> bool {
+        five_points(board, x, y, dx, dy)
+            .windows(2)
+            .any(|pair| pair[1] - pair[0] == WIN_LENGTH as i32)
+    }
+
+    /// 한 방향에서 (x, y)가 진짜 열린 3을 만드는지 확인
+    ///
+    /// 빈 자리 하나를 더 두어 열린 4가 되고, 그 자리가 금수가 아니어야 진짜 3입니다.
+    /// 열린 4를 만드는 자리가 모두 금수이면 거짓 3으로 보아 세지 않습니다.
+    fn is_open_three(board: &mut Board, x: usize, y: usize, dx: i32, dy: i32) -> bool {
+        for k in -(WIN_LENGTH as i32 - 1)..WIN_LENGTH as i32 {
+            let (qx, qy) = (x as i32 + dx * k, y as i32 + dy * k);
+            if k == 0 || !board.in_bounds(qx, qy) {
+                continue;
+            }
+            let (qx, qy) = (qx as usize, qy as usize);
+            if board.get(qx, qy).is_some() {
+                continue;
+            }
+
+            board.set(qx, qy, BLACK);
+            let real_three =
+                is_straight_four(board, x, y, dx, dy) && classify(board, qx, qy).is_none();
+            board.set(qx, qy, None);
+
+            if real_three {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (x, y)에 놓인 돌의 색으로 승리 여부 확인
+    fn wins(rule_set: RuleSet, board: &Board, x: usize, y: usize) -> bool {
+        let color = board.get(x, y).expect("a stone at the point");
+        rule_set.is_win(board, x, y, color)
+    }
+
+    #[test]
+    fn overlines_win_only_where_the_rules_allow() {
+        let black = Board::from_rows(&["XXXXXX...", "........."]);
+        let white = Board::from_rows(&["OOOOOO...", "........."]);
+
+        assert!(wins(RuleSet::Freestyle, &black, 0, 1));
+        assert!(!wins(RuleSet::Standard, &black, 0, 1));
+        assert!(!wins(RuleSet::Standard, &white, 5, 1));
+        assert!(!wins(RuleSet::Renju, &black, 2, 1));
+        assert!(wins(RuleSet::Renju, &white, 2, 1));
+        assert!(wins(RuleSet::Caro, &black, 5, 1));
+    }
+
+    #[test]
+    fn standard_accepts_an_exact_five() {
+        let board = Board::from_rows(&[".XXXXX.O."]);
+        assert!(wins(RuleSet::Standard, &board, 3, 0));
+        assert_eq!(
+            RuleSet::Standard.winning_line(&board, 3, 0, StoneColor::Black),
+            Some(vec![(1, 0), (2, 0), (3, 0), (4, 0), (5, 0)])
+        );
+    }
+
+    #[test]
+    fn caro_rejects_a_five_blocked_at_both_ends() {
+        let blocked = Board::from_rows(&["OXXXXXO.."]);
+        let one_end = Board::from_rows(&["OXXXXX..."]);
+        let board_edge = Board::from_rows(&["XXXXXO..."]);
+
+        assert!(!wins(RuleSet::Caro, &blocked, 3, 0));
+        assert!(wins(RuleSet::Freestyle, &blocked, 3, 0));
+        assert!(wins(RuleSet::Caro, &one_end, 3, 0));
+        assert!(wins(RuleSet::Caro, &board_edge, 0, 0));
+        assert_eq!(
+            RuleSet::Caro.winning_line(&blocked, 3, 0, StoneColor::Black),
+            None
+        );
+    }
+
+    #[test]
+    fn caro_checks_the_ends_of_diagonal_lines() {
+        let board = Board::from_rows(&[
+            "......O", ".....X.", "....X..", "...X...", "..X....", ".X.....", "O......",
+        ]);
+        assert!(!wins(RuleSet::Caro, &board, 3, 3));
+        assert!(wins(RuleSet::Standard, &board, 3, 3));
+    }
+
+    #[test]
+    fn winning_line_lists_the_stones_from_one_end() {
+        let board = Board::from_rows(&["X....", ".X...", "..X..", "...X.", "....X"]);
+        let line = RuleSet::Freestyle.winning_line(&board, 2, 2, StoneColor::Black);
+        assert_eq!(line, Some(vec![(0, 4), (1, 3), (2, 2), (3, 1), (4, 0)]));
+        assert_eq!(
+            RuleSet::Freestyle.winning_line(&board, 2, 2, StoneColor::White),
+            None
+        );
+    }
+
+    #[test]
+    fn only_renju_forbids_moves_and_only_for_black() {
+        let board = Board::from_rows(&[
+            ".........",
+            ".........",
+            ".........",
+            "....X....",
+            "....X....",
+            "..XX.....",
+            ".........",
+            ".........",
+            ".........",
+        ]);
+        for rule_set in [RuleSet::Freestyle, RuleSet::Standard, RuleSet::Caro] {
+            assert_eq!(rule_set.forbidden(&board, 4, 3, StoneColor::Black), None);
+        }
+        assert_eq!(
+            RuleSet::Renju.forbidden(&board, 4, 3, StoneColor::Black),
+            Some(Forbidden::DoubleThree)
+        );
+        assert_eq!(
+            RuleSet::Renju.forbidden(&board, 4, 3, StoneColor::White),
+            None
+        );
+    }
+
+    /// 11x11 빈 판에 흑돌을 놓은 판
+    fn black_stones(stones: &[(usize, usize)]) -> Board {
+        let mut board = Board::with_size(11, 11);
+        for &(x, y) in stones {
+            board.set(x, y, Some(StoneColor::Black));
+        }
+        board
+    }
+
+    /// 렌주룰에서 흑이 (x, y)에 두는 것이 금수인지 확인
+    fn renju(board: &Board, x: usize, y: usize) -> Option<Forbidden> {
+        RuleSet::Renju.forbidden(board, x, y, StoneColor::Black)
+    }
+
+    // 가로 `X.XX`(열린 4를 만드는 자리는 (4, 5) 하나뿐)와 세로 열린 3을 동시에 만드는 (6, 5)
+    const SPLIT_THREE_AND_THREE: [(usize, usize); 4] = [(3, 5), (5, 5), (6, 6), (6, 7)];
+
+    #[test]
+    fn renju_forbids_a_real_double_three() {
+        let board = black_stones(&SPLIT_THREE_AND_THREE);
+        assert_eq!(renju(&board, 6, 5), Some(Forbidden::DoubleThree));
+    }
+
+    #[test]
+    fn renju_allows_a_double_three_whose_straight_four_would_be_forbidden() {
+        // (4, 5)에 두면 세로로 6목이 되므로 가로 3은 열린 4가 될 수 없는 거짓 3
+        let mut stones = SPLIT_THREE_AND_THREE.to_vec();
+        stones.extend([(4, 2), (4, 3), (4, 4), (4, 6), (4, 7)]);
+        let board = black_stones(&stones);
+        assert_eq!(renju(&board, 4, 5), Some(Forbidden::Overline));
+        assert_eq!(renju(&board, 6, 5), None);
+    }
+
+    #[test]
+    fn renju_forbids_a_double_four_in_one_line() {
+        // X.XXX.X: 양쪽 빈 자리가 각각 다른 5목을 만듦
+        let board = black_stones(&[(1, 5), (3, 5), (5, 5), (7, 5)]);
+        assert_eq!(renju(&board, 4, 5), Some(Forbidden::DoubleFour));
+
+        // 열린 4(.XXXX.)는 5목 자리가 둘이어도 4 하나
+        let board = black_stones(&[(3, 5), (4, 5), (5, 5)]);
+        assert_eq!(renju(&board, 6, 5), None);
+    }
+
+    #[test]
+    fn renju_allows_a_five_that_also_makes_a_double_three() {
+        let threes = [(5, 6), (5, 7), (6, 6), (7, 7)];
+        let mut stones = threes.to_vec();
+        stones.extend([(1, 5), (2, 5), (3, 5), (4, 5)]);
+        let board = black_stones(&stones);
+        assert_eq!(renju(&board, 5, 5), None);
+
+        // 5목이 아니면 같은 두 3은 금수
+        let board = black_stones(&threes);
+        assert_eq!(renju(&board, 5, 5), Some(Forbidden::DoubleThree));
+    }
+
+    #[test]
+    fn renju_prefers_a_five_over_an_overline_in_another_line() {
+        let overline = [(5, 3), (5, 4), (5, 6), (5, 7), (5, 8)];
+        let mut stones = overline.to_vec();
+        stones.extend([(1, 5), (2, 5), (3, 5), (4, 5)]);
+        let mut board = black_stones(&stones);
+        assert_eq!(renju(&board, 5, 5), None);
+        board.set(5, 5, Some(StoneColor::Black));
+        assert!(RuleSet::Renju.is_win(&board, 5, 5, StoneColor::Black));
+
+        let board = black_stones(&overline);
+        assert_eq!(renju(&board, 5, 5), Some(Forbidden::Overline));
+    }
+
+    #[test]
+    fn renju_does_not_count_a_blocked_three() {
+        let mut board = black_stones(&SPLIT_THREE_AND_THREE);
+        board.set(2, 5, Some(StoneColor::White));
+        board.set(7, 5, Some(StoneColor::White));
+        assert_eq!(renju(&board, 6, 5), None);
+    }
+
+    #[test]
+    fn rule_set_names_round_trip() {
+        let mut rule_set = RuleSet::default();
+        loop {
+            assert_eq!(rule_set.to_string().to_lowercase().parse(), Ok(rule_set));
+            rule_set = rule_set.next();
+            if rule_set == RuleSet::default() {
+                break;
+            }
+        }
+        assert!("gomoku".parse::<RuleSet>().is_err());
+    }
+}
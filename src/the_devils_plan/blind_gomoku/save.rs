@@ -0,0 +1,582 @@
+//! 진행 중인 게임 저장과 이어하기
+//!
+//! 창을 닫아도 게임을 이어서 둘 수 있도록 게임 상태 전체(판, 수순, 무른 수, 차례, 결과,
+//! 색별로 쓴 시간, 플레이어별 시야와 충돌 경고, 규칙과 AI 설정, 연속 대국 점수)를 텍스트 파일에 적습니다.
+//! Bevy에 의존하지 않습니다.
+//!
+//! # 파일 형식
+//!
+//! 한 줄에 `항목: 값` 하나씩 적으며, `#`으로 시작하는 줄은 주석입니다. 첫 항목인 `version`이
+//! 이 프로그램이 아는 버전(`SAVE_VERSION`)보다 새로우면 읽지 않습니다. 같은 버전 안에서는
+//! 모르는 항목을 무시하고, 빠진 항목은 기본값을 씁니다.
+//!
+//! ```text
+//! # Blind gomoku save
+//! version: 1
+//! saved: 2026-10-18T09:41:12Z
+//! started: 2026-10-18T09:30:00Z
+//! size: 15x15
+//! rule: Renju
+//! moves: h8 i9 h9 pass h10
+//! undone: 0
+//! to-move: w
+//! result: *
+//! clock: 312.4 287.9
+//! black-knows: i9
+//! white-knows: h8 h9
+//! ```
+//!
+//! - `moves`는 둔 수에 무르기로 취소된 수(다시 둘 순서대로)를 이어 적은 것이며, 끝의 `undone`개가
+//!   취소된 수입니다. 좌표와 `pass`는 기보의 좌표 표기와 같습니다 (`record` 모듈 참고).
+//! - `clock`은 흑과 백이 자기 차례에 쓴 시간(초)입니다. 이어서 두면 이 시간부터 다시 잽니다.
+//! - `black-knows`/`white-knows`는 각 플레이어가 알고 있는 상대 돌입니다 (자신의 돌은 항상 앎).
+//! - 오프닝 단계의 상태는 저장하지 않으므로, 오프닝이 끝난 게임만 저장합니다.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::ai::Difficulty;
+use super::belief::Belief;
+use super::blind::{BlindGame, CollisionRule};
+use super::engine::{
+    Board, Game, GameResult, MoveError, StoneColor, DEFAULT_BOARD_SIZE, MAX_BOARD_SIZE,
+};
+use super::opening::{OpeningRule, Player};
+use super::record::{self, format_point, parse_point};
+use super::rng::Rng;
+use super::rules::RuleSet;
+
+/// 이 프로그램이 쓰고 읽을 수 있는 저장 파일 형식 버전
+pub const SAVE_VERSION: u32 = 1;
+
+/// 자동 저장 파일 이름 (`data_dir` 안)
+pub const AUTOSAVE_FILE: &str = "autosave.txt";
+
+/// 사용자 데이터 폴더 안에서 이 게임이 쓰는 폴더 이름
+const APP_DIR: &str = "blind_gomoku";
+
+// ==================== 오류 ====================
+/// 저장 파일을 읽을 때의 오류
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SaveError {
+    /// `version` 항목이 없거나 지원하지 않는 버전 (적힌 값)
+    UnsupportedVersion(String),
+    /// `항목: 값` 형식이 아닌 줄 (줄 번호)
+    InvalidLine(usize),
+    /// 읽을 수 없는 값 (항목 이름)
+    InvalidValue(&'static str),
+    /// 저장된 수순을 다시 둘 수 없음
+    InvalidMove(MoveError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::UnsupportedVersion(version) => {
+                write!(f, "unsupported save version \"{version}\"")
+            }
+            SaveError::InvalidLine(line) => write!(f, "line {line}: expected \"key: value\""),
+            SaveError::InvalidValue(key) => write!(f, "invalid value for \"{key}\""),
+            SaveError::InvalidMove(err) => write!(f, "saved moves cannot be replayed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+// ==================== 저장한 게임 ====================
+/// 저장 파일 하나에 담기는 게임 상태와 설정
+#[derive(Clone, Debug)]
+pub struct SavedGame {
+    pub game: BlindGame,          // 판, 수순, 무른 수, 차례, 결과, 시야, 충돌 경고
+    pub collision: CollisionRule, // 충돌 규칙
+    pub opening: OpeningRule,     // 다음 게임에 쓸 오프닝 규칙 (저장한 게임의 오프닝은 끝남)
+    pub black: Player,            // 흑을 잡은 플레이어
+    pub allow_undo: bool,         // 무르기 허용 여부
+    pub ai_color: Option<StoneColor>, // AI가 맡은 색
+    pub ai_strategy: String,      // AI가 수를 고르는 방식의 이름 (화면 쪽에서 해석)
+    pub difficulty: Difficulty,   // AI 난이도
+    pub ai_seed: u64,             // 새 게임마다 쓰는 AI 난수 시드
+    pub rng: Rng,                 // 진행 중인 AI 난수 생성기
+    pub belief: Belief,           // AI가 피드백으로 모은 상대 돌 정보
+    pub swap_colors: bool,        // 게임마다 먼저 두는 플레이어를 바꿀지 여부
+    pub wins: [u32; 2],           // 플레이어별 승리 수 (Player 1, Player 2 순서)
+    pub draws: u32,               // 무승부 수
+    pub clocks: [Duration; 2],    // 색별로 자기 차례에 쓴 시간 (흑, 백 순서)
+    pub started: u64,             // 게임을 시작한 시각 (유닉스 시간, 초)
+    pub saved: u64,               // 저장한 시각 (유닉스 시간, 초)
+}
+
+impl SavedGame {
+    /// `game`과 기본 설정으로 저장할 상태 생성 (시작, 저장 시각은 지금)
+    pub fn new(game: BlindGame) -> Self {
+        let now = record::now();
+        Self {
+            game,
+            collision: CollisionRule::default(),
+            opening: OpeningRule::default(),
+            black: Player::First,
+            allow_undo: false,
+            ai_color: None,
+            ai_strategy: String::new(),
+            difficulty: Difficulty::default(),
+            ai_seed: 0,
+            rng: Rng::default(),
+            belief: Belief::new(),
+            swap_colors: false,
+            wins: [0; 2],
+            draws: 0,
+            clocks: [Duration::ZERO; 2],
+            started: now,
+            saved: now,
+        }
+    }
+
+    /// 저장 파일 형식으로 변환
+    pub fn to_text(&self) -> String {
+        let game = self.game.game();
+        let board = game.board();
+        let mut moves = game.history().to_vec();
+        moves.extend(game.undone());
+        let knows = |player: StoneColor| {
+            let view = self.game.view(player).board();
+            let points: Vec<String> = view
+                .stones()
+                .filter(|&(_, _, color)| color != player)
+                .map(|(x, y, _)| format_point(x, y))
+                .collect();
+            points.join(" ")
+        };
+        let points = |points: &[(usize, usize)]| {
+            let points: Vec<String> = points.iter().map(|&(x, y)| format_point(x, y)).collect();
+            points.join(" ")
+        };
+
+        let fields = [
+            ("version", SAVE_VERSION.to_string()),
+            ("saved", record::format_timestamp(self.saved)),
+            ("started", record::format_timestamp(self.started)),
+            ("size", format!("{}x{}", board.width(), board.height())),
+            ("rule", game.rule_set().to_string()),
+            ("early-draw", on_off(game.early_draw()).to_string()),
+            ("collision", self.collision.to_string()),
+            ("opening", self.opening.to_string()),
+            ("undo", on_off(self.allow_undo).to_string()),
+            ("moves", record::format_moves(&moves)),
+            ("undone", game.undone().count().to_string()),
+            ("to-move", color_letter(game.current_player()).to_string()),
+            ("result", record::format_result(game.result()).to_string()),
+            (
+                "clock",
+                format!(
+                    "{:.1} {:.1}",
+                    self.clocks[0].as_secs_f64(),
+                    self.clocks[1].as_secs_f64()
+                ),
+            ),
+            (
+                "strikes",
+                format!(
+                    "{} {}",
+                    self.game.strikes(StoneColor::Black),
+                    self.game.strikes(StoneColor::White)
+                ),
+            ),
+            ("black-knows", knows(StoneColor::Black)),
+            ("white-knows", knows(StoneColor::White)),
+            ("black-player", (self.black.index() + 1).to_string()),
+            (
+                "ai",
+                match self.ai_color {
+                    Some(color) => color_letter(color).to_string(),
+                    None => "none".to_string(),
+                },
+            ),
+            ("strategy", self.ai_strategy.clone()),
+            ("difficulty", self.difficulty.to_string()),
+            ("seed", self.ai_seed.to_string()),
+            ("rng", self.rng.state().to_string()),
+            ("belief-occupied", points(self.belief.occupied())),
+            ("belief-avoided", points(self.belief.avoided())),
+            ("swap-colors", on_off(self.swap_colors).to_string()),
+            (
+                "score",
+                format!("{} {} {}", self.wins[0], self.wins[1], self.draws),
+            ),
+        ];
+
+        let mut text = "# Blind gomoku save\n".to_string();
+        for (key, value) in fields {
+            text += format!("{key}: {value}").trim_end(); // 빈 값이면 `항목:`만 적음
+            text.push('\n');
+        }
+        text
+    }
+
+    /// 저장 파일 내용을 읽어 게임을 다시 만듦
+    pub fn parse(text: &str) -> Result<Self, SaveError> {
+        let mut fields = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .ok_or(SaveError::InvalidLine(index + 1))?;
+            fields.insert(key.trim(), value.trim());
+        }
+        let version = fields.get("version").copied().unwrap_or_default();
+        if !version
+            .parse::<u32>()
+            .is_ok_and(|version| (1..=SAVE_VERSION).contains(&version))
+        {
+            return Err(SaveError::UnsupportedVersion(version.to_string()));
+        }
+        let fields = Fields(fields);
+
+        // 판과 규칙
+        let default_size = (DEFAULT_BOARD_SIZE, DEFAULT_BOARD_SIZE);
+        let (width, height) = fields.get("size", default_size, |size| {
+            let (width, height) = size.split_once('x')?;
+            let size = (width.parse().ok()?, height.parse().ok()?);
+            let valid = |n: usize| (1..=MAX_BOARD_SIZE).contains(&n);
+            (valid(size.0) && valid(size.1)).then_some(size)
+        })?;
+        let rule_set: RuleSet = fields.parse("rule")?;
+        let early_draw = fields.get("early-draw", false, parse_on_off)?;
+        let mut game = Game::with_size(width, height)
+            .with_rule_set(rule_set)
+            .with_early_draw(early_draw);
+
+        // 수순 (무른 수까지 다시 둔 뒤 무름)
+        let moves = fields.get("moves", Vec::new(), |moves| record::parse_moves(moves).ok())?;
+        let undone = fields.get("undone", 0, |undone| undone.parse().ok())?;
+        if undone > moves.len() {
+            return Err(SaveError::InvalidValue("undone"));
+        }
+        for stone in &moves {
+            if game.current_player() != stone.color {
+                game.pass().map_err(SaveError::InvalidMove)?;
+            }
+            game.play(stone.x, stone.y)
+                .map_err(SaveError::InvalidMove)?;
+        }
+        for _ in 0..undone {
+            game.undo();
+        }
+        let to_move = fields.get("to-move", game.current_player(), parse_color)?;
+        if to_move != game.current_player() {
+            game.pass().map_err(SaveError::InvalidMove)?;
+        }
+        // 기권으로 끝난 게임은 수순만으로 결과가 나오지 않음
+        let result = fields.get("result", None, record::parse_result)?;
+        if let (Some(GameResult::Win(winner)), None) = (result, game.result()) {
+            game.resign(winner.opposite());
+        }
+
+        // 블라인드 시야와 충돌 경고
+        let known = |key: &'static str, color: StoneColor| {
+            let mut board = Board::with_size(width, height);
+            for (x, y) in fields.get(key, Vec::new(), |text| parse_points(text, width, height))? {
+                board.set(x, y, Some(color));
+            }
+            Ok(board)
+        };
+        let black_knows = known("black-knows", StoneColor::White)?;
+        let white_knows = known("white-knows", StoneColor::Black)?;
+        let strikes = fields.get("strikes", [0; 2], |strikes| {
+            let (black, white) = strikes.split_once(' ')?;
+            Some([black.trim().parse().ok()?, white.trim().parse().ok()?])
+        })?;
+        let game = BlindGame::with_known(game, [&black_knows, &white_knows]).with_strikes(strikes);
+
+        let clocks = fields.get("clock", [Duration::ZERO; 2], |clock| {
+            let (black, white) = clock.split_once(' ')?;
+            let seconds = |text: &str| {
+                let seconds: f64 = text.trim().parse().ok()?;
+                (seconds.is_finite() && seconds >= 0.0)
+                    .then(|| Duration::from_millis((seconds * 1000.0).round() as u64))
+            };
+            Some([seconds(black)?, seconds(white)?])
+        })?;
+        let score = fields.get("score", [0; 3], |score| {
+            let numbers: Option<Vec<u32>> = score
+                .split_whitespace()
+                .map(|number| number.parse().ok())
+                .collect();
+            numbers?.try_into().ok()
+        })?;
+        let now = record::now();
+        Ok(Self {
+            game,
+            collision: fields.parse("collision")?,
+            opening: fields.parse("opening")?,
+            black: fields.get("black-player", Player::First, |player| match player {
+                "1" => Some(Player::First),
+                "2" => Some(Player::Second),
+                _ => None,
+            })?,
+            allow_undo: fields.get("undo", false, parse_on_off)?,
+            ai_color: fields.get("ai", None, |color| match color {
+                "none" => Some(None),
+                _ => parse_color(color).map(Some),
+            })?,
+            ai_strategy: fields.get("strategy", String::new(), |strategy| {
+                Some(strategy.to_string())
+            })?,
+            difficulty: fields.parse("difficulty")?,
+            ai_seed: fields.get("seed", 0, |seed| seed.parse().ok())?,
+            rng: fields.get("rng", Rng::default(), |state| {
+                state.parse().ok().map(Rng::new)
+            })?,
+            belief: Belief::from_points(
+                fields.get("belief-occupied", Vec::new(), |text| {
+                    parse_points(text, width, height)
+                })?,
+                fields.get("belief-avoided", Vec::new(), |text| {
+                    parse_points(text, width, height)
+                })?,
+            ),
+            swap_colors: fields.get("swap-colors", false, parse_on_off)?,
+            wins: [score[0], score[1]],
+            draws: score[2],
+            clocks,
+            started: fields.get("started", now, record::parse_timestamp)?,
+            saved: fields.get("saved", now, record::parse_timestamp)?,
+        })
+    }
+
+    /// `path`에 저장 (폴더가 없으면 만들고, 임시 파일에 쓴 뒤 바꿔치기)
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let temporary = path.with_extension("tmp");
+        std::fs::write(&temporary, self.to_text())?;
+        std::fs::rename(&temporary, path)
+    }
+
+    /// `path`에서 읽음
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text =
+            std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        Self::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+}
+
+/// 항목 이름에서 값으로의 표
+struct Fields<'a>(HashMap<&'a str, &'a str>);
+
+impl Fields<'_> {
+    /// `key` 항목을 `parse`로 읽음 (없으면 `default`, 읽을 수 없으면 오류)
+    fn get<T>(
+        &self,
+        key: &'static str,
+        default: T,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, SaveError> {
+        match self.0.get(key) {
+            Some(value) => parse(value).ok_or(SaveError::InvalidValue(key)),
+            None => Ok(default),
+        }
+    }
+
+    /// `key` 항목을 `FromStr`로 읽음 (없으면 기본값)
+    fn parse<T: std::str::FromStr + Default>(&self, key: &'static str) -> Result<T, SaveError> {
+        self.get(key, T::default(), |value| value.parse().ok())
+    }
+}
+
+/// 사용자 데이터 폴더 안의 이 게임 폴더 (찾을 수 없으면 `None`)
+///
+/// Windows는 `%APPDATA%`, macOS는 `~/Library/Application Support`,
+/// 그 밖에는 `$XDG_DATA_HOME` 또는 `~/.local/share` 아래입니다.
+pub fn data_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| {
+        std::env::var_os(name)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = if cfg!(windows) {
+        env_dir("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME")?.join("Library/Application Support")
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| Some(env_dir("HOME")?.join(".local/share")))?
+    };
+    Some(base.join(APP_DIR))
+}
+
+/// 자동 저장 파일 경로 (사용자 데이터 폴더를 찾을 수 없으면 `None`)
+pub fn autosave_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(AUTOSAVE_FILE))
+}
+
+/// 참/거짓을 `on`/`off`로 변환
+fn on_off(value: bool) -> &'static str {
+    if value {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+/// `on`/`off`를 참/거짓으로 변환
+fn parse_on_off(text: &str) -> Option<bool> {
+    match text {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// 색을 `b`/`w`로 변환
+fn color_letter(color: StoneColor) -> char {
+    match color {
+        StoneColor::Black => 'b',
+        StoneColor::White => 'w',
+    }
+}
+
+/// `b`/`w`를 색으로 변환
+fn parse_color(text: &str) -> Option<StoneColor> {
+    match text {
+        "b" => Some(StoneColor::Black),
+        "w" => Some(StoneColor::White),
+        _ => None,
+    }
+}
+
+/// 공백으로 나눈 좌표 목록을 읽음 (판 밖의 좌표가 있으면 `None`)
+fn parse_points(text: &str, width: usize, height: usize) -> Option<Vec<(usize, usize)>> {
+    text.split_whitespace()
+        .map(|token| parse_point(token).filter(|&(x, y)| x < width && y < height))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 충돌, 무르기, AI 설정이 모두 들어 있는 저장 상태
+    fn saved_game() -> SavedGame {
+        let mut game = BlindGame::with_game(
+            Game::with_size(13, 11)
+                .with_rule_set(RuleSet::Renju)
+                .with_early_draw(true),
+        );
+        for (x, y) in [(6, 5), (7, 5), (7, 5), (5, 5), (6, 6), (8, 8)] {
+            game.play(x, y, CollisionRule::RevealToBoth).unwrap();
+        }
+        game.undo();
+        let mut saved = SavedGame::new(game);
+        saved.collision = CollisionRule::RevealToBoth;
+        saved.black = Player::Second;
+        saved.allow_undo = true;
+        saved.ai_color = Some(StoneColor::White);
+        saved.ai_strategy = "belief".to_string();
+        saved.difficulty = Difficulty::Hard;
+        saved.ai_seed = 42;
+        saved.rng = Rng::new(1_234_567);
+        saved.belief = Belief::from_points(vec![(6, 5)], vec![(0, 0)]);
+        saved.swap_colors = true;
+        saved.wins = [3, 1];
+        saved.draws = 2;
+        saved.clocks = [Duration::from_millis(312_400), Duration::from_secs(288)];
+        saved.started = 1_792_315_800;
+        saved.saved = 1_792_316_472;
+        saved
+    }
+
+    #[test]
+    fn round_trips_a_game() {
+        let saved = saved_game();
+        let text = saved.to_text();
+        let loaded = SavedGame::parse(&text).unwrap();
+        assert_eq!(loaded.to_text(), text);
+
+        let (game, original) = (loaded.game.game(), saved.game.game());
+        assert_eq!(game.board(), original.board());
+        assert_eq!(game.history(), original.history());
+        assert_eq!(game.undone().count(), 1);
+        assert_eq!(game.current_player(), original.current_player());
+        assert_eq!(game.rule_set(), RuleSet::Renju);
+        assert!(game.early_draw());
+        for player in [StoneColor::Black, StoneColor::White] {
+            assert_eq!(
+                loaded.game.view(player).board(),
+                saved.game.view(player).board()
+            );
+        }
+        assert_eq!(loaded.collision, CollisionRule::RevealToBoth);
+        assert_eq!(loaded.ai_color, Some(StoneColor::White));
+        assert_eq!(loaded.ai_strategy, "belief");
+        assert_eq!(loaded.difficulty, Difficulty::Hard);
+        assert_eq!(loaded.rng.state(), saved.rng.state());
+        assert_eq!(loaded.belief.occupied(), &[(6, 5)]);
+        assert_eq!((loaded.wins, loaded.draws), ([3, 1], 2));
+        assert_eq!(loaded.clocks, saved.clocks);
+        assert!(text.contains("clock: 312.4 288.0\n"));
+        assert_eq!((loaded.started, loaded.saved), (saved.started, saved.saved));
+    }
+
+    #[test]
+    fn round_trips_a_resignation() {
+        let mut game = BlindGame::new();
+        game.play(7, 7, CollisionRule::LoseTurn).unwrap();
+        game.resign(StoneColor::White);
+        let loaded = SavedGame::parse(&SavedGame::new(game).to_text()).unwrap();
+        assert_eq!(
+            loaded.game.game().result(),
+            Some(GameResult::Win(StoneColor::Black))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        let text = saved_game().to_text();
+        for version in ["0", "2", "one"] {
+            let text = text.replace("version: 1", &format!("version: {version}"));
+            assert_eq!(
+                SavedGame::parse(&text).unwrap_err(),
+                SaveError::UnsupportedVersion(version.to_string())
+            );
+        }
+        assert_eq!(
+            SavedGame::parse("size: 15x15\n").unwrap_err(),
+            SaveError::UnsupportedVersion(String::new())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_fields() {
+        assert_eq!(
+            SavedGame::parse("version: 1\nnot a field\n").unwrap_err(),
+            SaveError::InvalidLine(2)
+        );
+        assert_eq!(
+            SavedGame::parse("version: 1\nmoves: h8\nundone: 2\n").unwrap_err(),
+            SaveError::InvalidValue("undone")
+        );
+        assert_eq!(
+            SavedGame::parse("version: 1\nsize: 9x9\nblack-knows: k10\n").unwrap_err(),
+            SaveError::InvalidValue("black-knows")
+        );
+        assert_eq!(
+            SavedGame::parse("version: 1\nmoves: h8 h8\n").unwrap_err(),
+            SaveError::InvalidMove(MoveError::Occupied(StoneColor::Black))
+        );
+        for clock in ["5:00", "10", "-1 3", "1 NaN"] {
+            assert_eq!(
+                SavedGame::parse(&format!("version: 1\nclock: {clock}\n")).unwrap_err(),
+                SaveError::InvalidValue("clock")
+            );
+        }
+        // 모르는 항목은 무시하고, 시계가 없던 파일은 0초부터 잼
+        let loaded = SavedGame::parse("version: 1\nkomi: 6.5\n").unwrap();
+        assert_eq!(loaded.clocks, [Duration::ZERO; 2]);
+    }
+}
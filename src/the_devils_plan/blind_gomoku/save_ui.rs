@@ -0,0 +1,217 @@
+//! 대국 시계와 자동 저장, 이어하기 시스템
+//!
+//! 저장 파일 형식과 읽고 쓰기는 Bevy에 의존하지 않는 `save` 모듈이 맡고,
+//! 이 모듈은 게임 상태를 저장 파일로 옮기거나 저장 파일에서 되살리는 Bevy 시스템만 담습니다.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use super::record;
+use super::save::{self, SavedGame};
+use super::{
+    spawn_button, AiPlayer, BlindRules, BoardConfig, FeedbackDisplay, GameConfig, GameSetup,
+    GameState, MatchState, OpeningState, DIALOG_COLOR, TEXT_COLOR,
+};
+
+// ==================== 컴포넌트 정의 ====================
+/// 이어하기 대화상자를 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ContinueDisplay;
+
+/// 이어하기 버튼을 나타내는 컴포넌트
+#[derive(Component)]
+pub(super) struct ContinueButton;
+
+// ==================== 대국 시계 시스템 ====================
+/// 오프닝이 끝난 진행 중인 게임에서 둘 차례인 색이 쓴 시간을 더하는 시스템
+///
+/// 매 프레임 바뀌는 값이므로 점수 표시가 매번 다시 그려지지 않도록 변경 감지를 거치지 않습니다.
+pub(super) fn tick_clock(
+    time: Res<Time>,
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    mut match_state: ResMut<MatchState>,
+) {
+    let game = game_state.game();
+    if game.is_over() || !opening.is_done() {
+        return;
+    }
+    match_state.bypass_change_detection().clocks[game.current_player().index()] += time.delta();
+}
+
+/// 시계에 보여 줄 `분:초` 문자열
+pub(super) fn format_clock(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+// ==================== 자동 저장 / 이어하기 시스템 ====================
+/// 수를 둘 때마다 현재 게임을 사용자 데이터 폴더의 자동 저장 파일에 적는 시스템
+///
+/// 오프닝 단계는 저장할 수 없으므로 오프닝이 끝나고 돌이 하나라도 놓인 게임만 저장하며,
+/// 새 게임의 첫 수를 두면 이전 게임의 자동 저장을 덮어씁니다.
+pub(super) fn autosave(
+    game_state: Res<GameState>,
+    opening: Res<OpeningState>,
+    game_config: Res<GameConfig>,
+    blind_rules: Res<BlindRules>,
+    match_state: Res<MatchState>,
+    ai: Res<AiPlayer>,
+) {
+    let Some(path) = save::autosave_path() else {
+        return;
+    };
+    if !opening.is_done() || game_state.game().history().is_empty() {
+        return;
+    }
+
+    let saved = SavedGame {
+        collision: blind_rules.collision,
+        opening: game_config.opening,
+        black: opening.black_player(),
+        allow_undo: game_config.allow_undo,
+        ai_color: ai.color,
+        ai_strategy: ai.strategy.to_string(),
+        difficulty: game_config.difficulty,
+        ai_seed: game_config.ai_seed,
+        rng: ai.rng,
+        belief: ai.belief.clone(),
+        swap_colors: match_state.swap_colors,
+        wins: match_state.wins,
+        draws: match_state.draws,
+        clocks: match_state.clocks,
+        started: match_state.game_started,
+        ..SavedGame::new(game_state.0.clone())
+    };
+    if let Err(err) = saved.save(&path) {
+        warn!("autosave to {} failed: {err}", path.display());
+    }
+}
+
+/// 시작할 때 끝나지 않은 자동 저장 게임이 있으면 이어하기 대화상자를 띄우는 시스템
+pub(super) fn offer_continue(
+    mut commands: Commands,
+    mut feedback_display: Query<&mut Text, With<FeedbackDisplay>>,
+) {
+    let Some(path) = save::autosave_path().filter(|path| path.exists()) else {
+        return;
+    };
+    let message = match SavedGame::load(&path) {
+        Ok(saved) if !saved.game.game().is_over() => {
+            commands
+                .spawn((
+                    ContinueDisplay,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(100.0),
+                        left: Val::Px(50.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(12.0),
+                        padding: UiRect::all(Val::Px(16.0)),
+                        ..default()
+                    },
+                    BackgroundColor(DIALOG_COLOR),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(format!(
+                            "Saved game ({} moves)\n{}",
+                            saved.game.game().history().len(),
+                            record::format_timestamp(saved.saved)
+                        )),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                    ));
+                    spawn_button(parent, "Continue", ContinueButton);
+                });
+            "N: continue the last game (a new move starts over)".to_string()
+        }
+        Ok(_) => return,
+        Err(err) => format!("Could not read the autosave: {err}"),
+    };
+    if let Ok(mut text) = feedback_display.single_mut() {
+        **text = message;
+    }
+}
+
+/// N 키(또는 Continue 버튼)로 자동 저장한 게임을 이어서 두는 시스템
+///
+/// 시작할 때 띄운 이어하기 대화상자가 남아 있을 때만 동작합니다.
+/// 판 크기와 규칙, AI 설정, 연속 대국 점수도 저장한 값으로 돌아갑니다.
+pub(super) fn continue_game(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ContinueButton>)>,
+    dialog: Query<(), With<ContinueDisplay>>,
+    mut blind_rules: ResMut<BlindRules>,
+    mut setup: GameSetup,
+    mut match_state: ResMut<MatchState>,
+    mut ai: ResMut<AiPlayer>,
+) {
+    let pressed = keyboard.just_pressed(KeyCode::KeyN)
+        || buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed);
+    if dialog.is_empty() || !pressed {
+        return;
+    }
+
+    let loaded = save::autosave_path()
+        .ok_or_else(|| "no user data directory".to_string())
+        .and_then(|path| SavedGame::load(&path))
+        .and_then(|saved| {
+            let board = saved.game.game().board();
+            let config = BoardConfig::new(board.width(), board.height());
+            if (config.width, config.height) != (board.width(), board.height()) {
+                return Err(format!(
+                    "{}x{} boards are not supported",
+                    board.width(),
+                    board.height()
+                ));
+            }
+            Ok((saved, config))
+        });
+    let message = match loaded {
+        Ok((saved, config)) => {
+            // 대화상자와 이전 게임의 엔티티를 지우고 판을 다시 만듦 (돌은 rebuild_board가 생성)
+            let message = format!(
+                "Continued the game saved at {} ({} moves)",
+                record::format_timestamp(saved.saved),
+                saved.game.game().history().len()
+            );
+            let early_draw = saved.game.game().early_draw();
+            setup.load(config, saved.game, saved.black);
+            setup.game_config.early_draw = early_draw;
+            setup.game_config.opening = saved.opening;
+            setup.game_config.allow_undo = saved.allow_undo;
+            setup.game_config.difficulty = saved.difficulty;
+            setup.game_config.ai_seed = saved.ai_seed;
+            blind_rules.collision = saved.collision;
+            match_state.swap_colors = saved.swap_colors;
+            match_state.wins = saved.wins;
+            match_state.draws = saved.draws;
+            match_state.game_started = saved.started;
+            match_state.clocks = saved.clocks;
+            ai.color = saved.ai_color;
+            ai.strategy = saved.ai_strategy.parse().unwrap_or_default();
+            ai.rng = saved.rng;
+            ai.belief = saved.belief;
+            message
+        }
+        Err(err) => format!("Could not continue: {err}"),
+    };
+    setup.show(message);
+}
+
+/// 새 게임에 돌이 놓이면 이어하기 대화상자를 닫는 시스템 (자동 저장이 새 게임으로 바뀜)
+pub(super) fn dismiss_continue(
+    mut commands: Commands,
+    dialog: Query<Entity, With<ContinueDisplay>>,
+) {
+    for entity in &dialog {
+        commands.entity(entity).despawn();
+    }
+}
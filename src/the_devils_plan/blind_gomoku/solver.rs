@@ -0,0 +1,546 @@
+//! 위협 공간 탐색 (VCF / VCT 풀이)
+//!
+//! 연속된 4(VCF) 또는 4와 열린 3(VCT)으로 상대의 응수를 강제하면서 이기는 수순을 찾습니다.
+//! 공격자의 모든 수가 위협이므로 수비자는 위협을 막는 수와 자신의 4로 맞서는 수만 보면 되고,
+//! 덕분에 일반 탐색보다 훨씬 깊은 수순을 읽을 수 있습니다.
+//! 찾은 수순은 항상 실제로 이기는 수순이지만, 한도 안에서 찾지 못했다고 해서
+//! 강제승이 없다는 뜻은 아닙니다. Bevy에 의존하지 않습니다.
+
+use std::cmp::Reverse;
+use std::fmt;
+
+use super::engine::{Board, Game, Move, StoneColor, DIRECTIONS, WIN_LENGTH};
+use super::rules::RuleSet;
+
+// ==================== 풀이 설정 ====================
+/// 찾을 강제승의 종류
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SolveMode {
+    /// 4만 연속으로 두어 이기는 수순 (Victory by Continuous Fours)
+    #[default]
+    Vcf,
+    /// 4와 열린 3을 섞어 이기는 수순 (Victory by Continuous Threats)
+    Vct,
+}
+
+impl fmt::Display for SolveMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveMode::Vcf => write!(f, "VCF"),
+            SolveMode::Vct => write!(f, "VCT"),
+        }
+    }
+}
+
+/// 풀이 한도
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SolverLimits {
+    pub max_depth: u32, // 마지막 5목을 뺀 공격자의 최대 수
+    pub max_nodes: u64, // 방문할 최대 노드 수
+}
+
+impl Default for SolverLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 12,
+            max_nodes: 20_000,
+        }
+    }
+}
+
+// ==================== 수순 ====================
+/// 강제승 수순의 한 수
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Step {
+    /// 돌을 놓음
+    Stone(Move),
+    /// 돌을 놓지 않고 차례를 넘김 (막는 자리가 모두 금수라 수비자가 막을 수 없음)
+    Pass(StoneColor),
+}
+
+impl Step {
+    /// 이 수를 두는 색
+    pub fn color(&self) -> StoneColor {
+        match *self {
+            Step::Stone(stone) => stone.color,
+            Step::Pass(color) => color,
+        }
+    }
+}
+
+// ==================== 풀이 ====================
+/// `color`가 둘 차례인 `board`에서 강제승 수순을 찾음
+///
+/// 반복 심화로 가장 짧은 수순부터 찾으며, 수순은 `color`의 첫 수부터 5목을 만드는 수까지
+/// 양쪽의 수를 번갈아 담습니다. 수비자의 응수는 가장 오래 버티는 것을 고르고,
+/// 막는 자리가 모두 금수이면 `Step::Pass`로 수비자의 차례를 채웁니다.
+pub fn solve(
+    board: &Board,
+    rule_set: RuleSet,
+    color: StoneColor,
+    mode: SolveMode,
+    limits: &SolverLimits,
+) -> Option<Vec<Step>> {
+    let mut solver = Solver {
+        board: board.clone(),
+        rule_set,
+        attacker: color,
+        mode,
+        nodes: 0,
+        max_nodes: limits.max_nodes,
+    };
+    for depth in 0..=limits.max_depth {
+        if let Some(line) = solver.attack(depth) {
+            return Some(line);
+        }
+        if solver.nodes >= solver.max_nodes {
+            break; // 노드 한도 초과
+        }
+    }
+    None
+}
+
+/// 진행 중인 게임에서 현재 플레이어의 강제승 수순을 찾음 (끝난 게임이면 `None`)
+pub fn solve_game(game: &Game, mode: SolveMode, limits: &SolverLimits) -> Option<Vec<Step>> {
+    if game.is_over() {
+        return None;
+    }
+    solve(
+        game.board(),
+        game.rule_set(),
+        game.current_player(),
+        mode,
+        limits,
+    )
+}
+
+/// 위협 공간 탐색 상태
+struct Solver {
+    board: Board,         // 탐색 중인 판 (수를 두고 물리며 사용)
+    rule_set: RuleSet,    // 승리 조건과 금수 규칙
+    attacker: StoneColor, // 강제승을 찾는 쪽
+    mode: SolveMode,      // 공격자가 쓸 수 있는 위협의 종류
+    nodes: u64,           // 방문한 노드 수
+    max_nodes: u64,       // 방문할 최대 노드 수
+}
+
+impl Solver {
+    /// 공격자 차례: `depth`수 안에 이기는 수순 반환
+    fn attack(&mut self, depth: u32) -> Option<Vec<Step>> {
+        self.nodes += 1;
+        if self.nodes > self.max_nodes {
+            return None;
+        }
+        let (attacker, defender) = (self.attacker, self.attacker.opposite());
+
+        // 바로 5목을 만들 수 있으면 승리
+        if let Some(&(x, y)) = self.fives(attacker).first() {
+            return Some(vec![stone(attacker, x, y)]);
+        }
+        if depth == 0 {
+            return None;
+        }
+
+        // 상대의 4가 있으면 막는 수만 둘 수 있음 (막으면서 위협을 만들어야 함)
+        let threats = self.fives(defender);
+        let moves = match threats.len() {
+            0 => self.threat_moves(),
+            1 => threats,
+            _ => return None,
+        };
+
+        for (x, y) in moves {
+            if self
+                .rule_set
+                .forbidden(&self.board, x, y, attacker)
+                .is_some()
+            {
+                continue;
+            }
+            self.board.set(x, y, Some(attacker));
+            let line = self.defend(depth - 1);
+            self.board.set(x, y, None);
+            if let Some(mut line) = line {
+                line.insert(0, stone(attacker, x, y));
+                return Some(line);
+            }
+        }
+        None
+    }
+
+    /// 수비자 차례: 모든 응수에 대해 공격자가 이기면 가장 긴 수순 반환
+    fn defend(&mut self, depth: u32) -> Option<Vec<Step>> {
+        let (attacker, defender) = (self.attacker, self.attacker.opposite());
+
+        // 수비자가 먼저 5목을 만들 수 있으면 실패
+        if !self.fives(defender).is_empty() {
+            return None;
+        }
+
+        let fours = self.fives(attacker);
+        let mut defenses = if !fours.is_empty() {
+            // 4는 그 자리를 막는 수밖에 없음
+            fours
+        } else if self.mode == SolveMode::Vct {
+            // 열린 3은 막는 수와 자신의 4로 맞서는 수를 모두 살펴봄
+            let wins = self.open_four_moves(attacker);
+            if wins.is_empty() {
+                return None; // 위협이 없으면 수비자가 자유롭게 둠
+            }
+            let mut defenses = self.three_defenses(&wins);
+            for pos in self.four_moves(defender) {
+                if !defenses.contains(&pos) {
+                    defenses.push(pos);
+                }
+            }
+            if defenses.is_empty() {
+                // 막을 방법이 없으면 자연스러운 응수 하나만 보여 줌
+                defenses.push(wins[0]);
+            }
+            defenses
+        } else {
+            return None;
+        };
+        defenses.retain(|&(x, y)| {
+            self.rule_set
+                .forbidden(&self.board, x, y, defender)
+                .is_none()
+        });
+        if defenses.is_empty() {
+            // 막는 자리가 모두 금수이면 수비자는 막을 수 없으므로 넘기고 공격자가 이어서 둠
+            let mut line = self.attack(depth)?;
+            line.insert(0, Step::Pass(defender));
+            return Some(line);
+        }
+
+        let mut longest: Option<Vec<Step>> = None;
+        for (x, y) in defenses {
+            self.board.set(x, y, Some(defender));
+            let line = self.attack(depth);
+            self.board.set(x, y, None);
+            let mut line = line?;
+            line.insert(0, stone(defender, x, y));
+            if longest
+                .as_ref()
+                .is_none_or(|longest| line.len() > longest.len())
+            {
+                longest = Some(line);
+            }
+        }
+        longest
+    }
+
+    /// 공격자가 둘 수 있는 위협 수 (5목 자리가 둘 생기는 수, 4, 열린 3 순서)
+    fn threat_moves(&mut self) -> Vec<(usize, usize)> {
+        let attacker = self.attacker;
+        let mut scored = Vec::new();
+        for (x, y) in self.empty_near(attacker, 2) {
+            if self
+                .rule_set
+                .forbidden(&self.board, x, y, attacker)
+                .is_some()
+            {
+                continue;
+            }
+            self.board.set(x, y, Some(attacker));
+            let fives = self.fives_through(x, y, attacker).len();
+            let priority = if fives > 0 {
+                fives + 1
+            } else if self.mode == SolveMode::Vct && self.makes_open_four_near(x, y) {
+                1
+            } else {
+                0
+            };
+            self.board.set(x, y, None);
+            if priority > 0 {
+                scored.push(((x, y), priority));
+            }
+        }
+        scored.sort_by_key(|&(_, priority)| Reverse(priority));
+        scored.into_iter().map(|(pos, _)| pos).collect()
+    }
+
+    /// 열린 3을 막는 수비 자리 (둔 뒤에 `wins`의 어느 자리도 열린 4를 만들지 못하게 되는 자리)
+    fn three_defenses(&mut self, wins: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let (attacker, defender) = (self.attacker, self.attacker.opposite());
+        let mut cells = wins.to_vec();
+        for &(x, y) in wins {
+            for pos in self.line_cells(x, y) {
+                if !cells.contains(&pos) {
+                    cells.push(pos);
+                }
+            }
+        }
+
+        cells
+            .into_iter()
+            .filter(|&(x, y)| {
+                self.board.set(x, y, Some(defender));
+                let stopped = wins.iter().all(|&(wx, wy)| {
+                    if self.board.get(wx, wy).is_some() {
+                        return true;
+                    }
+                    self.board.set(wx, wy, Some(attacker));
+                    let fives = self.fives_through(wx, wy, attacker).len();
+                    self.board.set(wx, wy, None);
+                    fives < 2
+                });
+                self.board.set(x, y, None);
+                stopped
+            })
+            .collect()
+    }
+
+    /// `color`가 두면 5목 자리가 둘 이상 생기는 자리 (열린 4, 4-4)
+    fn open_four_moves(&mut self, color: StoneColor) -> Vec<(usize, usize)> {
+        self.empty_near(color, 2)
+            .into_iter()
+            .filter(|&(x, y)| {
+                if self.rule_set.forbidden(&self.board, x, y, color).is_some() {
+                    return false;
+                }
+                self.board.set(x, y, Some(color));
+                let fives = self.fives_through(x, y, color).len();
+                self.board.set(x, y, None);
+                fives >= 2
+            })
+            .collect()
+    }
+
+    /// 방금 (x, y)에 둔 공격자 돌을 지나는 줄에서 다음 수로 열린 4를 만들 수 있는지 확인
+    fn makes_open_four_near(&mut self, x: usize, y: usize) -> bool {
+        let attacker = self.attacker;
+        self.line_cells(x, y).into_iter().any(|(cx, cy)| {
+            if self
+                .rule_set
+                .forbidden(&self.board, cx, cy, attacker)
+                .is_some()
+            {
+                return false;
+            }
+            self.board.set(cx, cy, Some(attacker));
+            let fives = self.fives_through(cx, cy, attacker).len();
+            self.board.set(cx, cy, None);
+            fives >= 2
+        })
+    }
+
+    /// `color`가 두면 4가 되는 자리 (5목 자리가 하나 이상 생기는 자리)
+    fn four_moves(&mut self, color: StoneColor) -> Vec<(usize, usize)> {
+        self.empty_near(color, 2)
+            .into_iter()
+            .filter(|&(x, y)| {
+                if self.rule_set.forbidden(&self.board, x, y, color).is_some() {
+                    return false;
+                }
+                self.board.set(x, y, Some(color));
+                let fours = !self.fives_through(x, y, color).is_empty();
+                self.board.set(x, y, None);
+                fours
+            })
+            .collect()
+    }
+
+    /// `color`가 두면 바로 승리하는 모든 자리
+    fn fives(&mut self, color: StoneColor) -> Vec<(usize, usize)> {
+        // 승리하는 자리 옆에는 반드시 같은 색 돌이 있음
+        self.empty_near(color, 1)
+            .into_iter()
+            .filter(|&(x, y)| self.wins_at(x, y, color))
+            .collect()
+    }
+
+    /// (x, y)를 지나는 네 방향의 줄에서 `color`가 두면 바로 승리하는 자리
+    fn fives_through(&mut self, x: usize, y: usize, color: StoneColor) -> Vec<(usize, usize)> {
+        self.line_cells(x, y)
+            .into_iter()
+            .filter(|&(cx, cy)| self.wins_at(cx, cy, color))
+            .collect()
+    }
+
+    /// 빈 자리 (x, y)에 `color`가 두면 승리하는지 확인
+    fn wins_at(&mut self, x: usize, y: usize, color: StoneColor) -> bool {
+        self.board.set(x, y, Some(color));
+        let wins = self.rule_set.is_win(&self.board, x, y, color);
+        self.board.set(x, y, None);
+        wins
+    }
+
+    /// (x, y)를 지나는 네 방향의 줄에서 4칸 이내의 빈 자리
+    fn line_cells(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        let reach = WIN_LENGTH as i32 - 1;
+        let mut cells = Vec::new();
+        for &(dx, dy) in &DIRECTIONS {
+            for k in (-reach..=reach).filter(|&k| k != 0) {
+                let (cx, cy) = (x as i32 + dx * k, y as i32 + dy * k);
+                if self.board.in_bounds(cx, cy)
+                    && self.board.get(cx as usize, cy as usize).is_none()
+                {
+                    cells.push((cx as usize, cy as usize));
+                }
+            }
+        }
+        cells
+    }
+
+    /// `color` 돌에서 `radius`칸 이내의 빈 자리
+    fn empty_near(&self, color: StoneColor, radius: i32) -> Vec<(usize, usize)> {
+        self.board
+            .points()
+            .filter(|&(x, y)| {
+                self.board.get(x, y).is_none()
+                    && (-radius..=radius).any(|dy| {
+                        (-radius..=radius).any(|dx| {
+                            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                            self.board.in_bounds(nx, ny)
+                                && self.board.get(nx as usize, ny as usize) == Some(color)
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+/// 수순에 담을 돌 하나
+fn stone(color: StoneColor, x: usize, y: usize) -> Step {
+    Step::Stone(Move { color, x, y })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::the_devils_plan::blind_gomoku::engine::MoveOutcome;
+
+    /// 11x11 판에 흑돌과 백돌을 놓은 판
+    fn board(black: &[(usize, usize)], white: &[(usize, usize)]) -> Board {
+        let mut board = Board::with_size(11, 11);
+        for &(x, y) in black {
+            board.set(x, y, Some(StoneColor::Black));
+        }
+        for &(x, y) in white {
+            board.set(x, y, Some(StoneColor::White));
+        }
+        board
+    }
+
+    /// 수순의 각 수를 실제 규칙으로 두어 보고 양쪽이 번갈아 두어 마지막 수로 `color`가 이기는지 확인
+    fn assert_wins(board: &Board, rule_set: RuleSet, color: StoneColor, line: &[Step]) {
+        let mut game = Game::from_position(board.clone(), rule_set, color);
+        let mut outcome = MoveOutcome::Continue;
+        for step in line {
+            assert_eq!(step.color(), game.current_player(), "{line:?}");
+            match *step {
+                Step::Stone(stone) => outcome = game.play(stone.x, stone.y).unwrap(),
+                Step::Pass(_) => game.pass().unwrap(),
+            }
+        }
+        assert_eq!(outcome, MoveOutcome::Win(color));
+    }
+
+    #[test]
+    fn vcf_finds_a_double_four() {
+        // (6, 5)에 두면 가로와 세로에 동시에 4가 생김
+        let board = board(
+            &[(2, 5), (3, 5), (4, 5), (6, 6), (6, 7), (6, 8)],
+            &[(1, 5), (6, 9)],
+        );
+        let line = solve(
+            &board,
+            RuleSet::Freestyle,
+            StoneColor::Black,
+            SolveMode::Vcf,
+            &SolverLimits::default(),
+        )
+        .expect("a VCF");
+        assert_eq!(
+            line,
+            [
+                stone(StoneColor::Black, 6, 5),
+                stone(StoneColor::White, 6, 4),
+                stone(StoneColor::Black, 5, 5),
+            ]
+        );
+        assert_wins(&board, RuleSet::Freestyle, StoneColor::Black, &line);
+    }
+
+    #[test]
+    fn vct_finds_a_double_three_when_there_is_no_vcf() {
+        // (7, 5)에 두면 열린 3이 두 개 생겨 하나만 막을 수 있음
+        let board = board(&[(5, 5), (6, 5), (7, 6), (7, 7)], &[]);
+        let limits = SolverLimits::default();
+        let solve = |mode| solve(&board, RuleSet::Freestyle, StoneColor::Black, mode, &limits);
+
+        assert_eq!(solve(SolveMode::Vcf), None);
+        let line = solve(SolveMode::Vct).expect("a VCT");
+        assert_eq!(line[0], stone(StoneColor::Black, 7, 5));
+        assert_eq!(line.len(), 5);
+        assert_wins(&board, RuleSet::Freestyle, StoneColor::Black, &line);
+    }
+
+    #[test]
+    fn no_forced_win_returns_none() {
+        let board = board(&[(5, 5), (6, 6)], &[(5, 6), (6, 5)]);
+        let limits = SolverLimits::default();
+        for mode in [SolveMode::Vcf, SolveMode::Vct] {
+            let line = solve(&board, RuleSet::Freestyle, StoneColor::Black, mode, &limits);
+            assert_eq!(line, None);
+        }
+    }
+
+    #[test]
+    fn vcf_wins_when_the_only_defense_is_forbidden_for_black() {
+        // 백이 (5, 1)에 두면 4가 되고, 막을 자리 (6, 1)은 흑에게 3-3 금수
+        let board = board(
+            &[(1, 1), (6, 2), (6, 3), (7, 2), (8, 3)],
+            &[(2, 1), (3, 1), (4, 1), (0, 9), (1, 9)],
+        );
+        assert!(RuleSet::Renju
+            .forbidden(&board, 6, 1, StoneColor::Black)
+            .is_some());
+        let limits = SolverLimits::default();
+
+        let line = solve(
+            &board,
+            RuleSet::Renju,
+            StoneColor::White,
+            SolveMode::Vcf,
+            &limits,
+        )
+        .expect("a VCF");
+        assert_eq!(
+            line,
+            [
+                stone(StoneColor::White, 5, 1),
+                Step::Pass(StoneColor::Black),
+                stone(StoneColor::White, 6, 1),
+            ]
+        );
+        assert_wins(&board, RuleSet::Renju, StoneColor::White, &line);
+
+        // 자유룰에서는 흑이 막을 수 있음
+        let line = solve(
+            &board,
+            RuleSet::Freestyle,
+            StoneColor::White,
+            SolveMode::Vcf,
+            &limits,
+        );
+        assert_eq!(line, None);
+    }
+
+    #[test]
+    fn solve_game_returns_none_for_a_finished_game() {
+        let mut game = Game::with_size(11, 11);
+        for x in 0..4 {
+            game.play(x, 0).unwrap();
+            game.play(x, 10).unwrap();
+        }
+        let limits = SolverLimits::default();
+        assert_eq!(
+            solve_game(&game, SolveMode::Vcf, &limits),
+            Some(vec![stone(StoneColor::Black, 4, 0)])
+        );
+        game.play(4, 0).unwrap();
+        assert_eq!(solve_game(&game, SolveMode::Vcf, &limits), None);
+    }
+}
@@ -0,0 +1,700 @@
+//! 화면 없는 자체 대국 (토너먼트)
+//!
+//! AI 설정과 외부 엔진을 참가자로 받아 리그전(모두 서로 대국) 또는 건틀릿(첫 참가자가 나머지와
+//! 대국)으로 대국을 진행하고, 대국 기록과 순위표, Elo 추정치를 계산합니다.
+//! 같은 짝끼리는 게임마다 먼저 두는 쪽을 바꾸며, 규칙과 오프닝 규칙을 그대로 적용합니다.
+//! 오프닝의 돌 놓기와 색 선택은 참가자 대신 진행기가 같은 방식으로 정하므로 공정합니다
+//! (Gomocup 프로토콜에는 오프닝 명령이 없음). Bevy에 의존하지 않습니다.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::ai::{self, AiProfile, Difficulty};
+use super::belief::{self, Belief};
+use super::blind::{BlindGame, CollisionRule, Feedback};
+use super::engine::{Board, Game, GameResult, Move, StoneColor, DEFAULT_BOARD_SIZE};
+use super::external::{EngineLog, ExternalEngine};
+use super::opening::{self, Opening, OpeningRule, Phase, Player};
+use super::rng::{self, Rng};
+use super::rules::RuleSet;
+
+/// 한 차례에 다시 두어야 하는 수(금수, 이미 아는 돌)를 허용하는 최대 횟수 (넘으면 기권패)
+const MAX_RETRIES: u32 = 20;
+
+/// 제한 구역이 없는 오프닝 수를 두는 중앙 구역의 크기
+const OPENING_AREA: usize = 7;
+
+/// Swap2에서 이 점수 차이 이내로 비슷하면 색을 고르지 않고 2수를 더 둠
+const SWAP2_BALANCE: i32 = 50;
+
+/// Soosõrv에서 선언하는 5수 후보의 개수
+const SOOSORV_DECLARED: usize = 2;
+
+/// Elo 추정을 반복하는 횟수
+const ELO_ITERATIONS: usize = 500;
+
+/// 기대 승률이 약 91%가 되는 Elo 차이
+const ELO_SCALE: f64 = 400.0;
+
+// ==================== 대진 방식 ====================
+/// 대진 방식
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Format {
+    /// 모든 참가자가 서로 대국
+    #[default]
+    RoundRobin,
+    /// 첫 번째 참가자가 나머지 참가자와 한 번씩 대국
+    Gauntlet,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::RoundRobin => write!(f, "round-robin"),
+            Format::Gauntlet => write!(f, "gauntlet"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "round-robin" => Ok(Format::RoundRobin),
+            "gauntlet" => Ok(Format::Gauntlet),
+            _ => Err(format!("unknown format \"{text}\"")),
+        }
+    }
+}
+
+/// 대국할 (먼저 두는 참가자, 나중에 두는 참가자) 목록
+///
+/// 짝마다 `games`판을 두며 게임마다 먼저 두는 쪽을 바꿉니다.
+pub fn schedule(format: Format, players: usize, games: usize) -> Vec<(usize, usize)> {
+    let pairs: Vec<(usize, usize)> = match format {
+        Format::RoundRobin => (0..players)
+            .flat_map(|a| (a + 1..players).map(move |b| (a, b)))
+            .collect(),
+        Format::Gauntlet => (1..players).map(|b| (0, b)).collect(),
+    };
+    pairs
+        .into_iter()
+        .flat_map(|(a, b)| (0..games).map(move |game| if game % 2 == 0 { (a, b) } else { (b, a) }))
+        .collect()
+}
+
+// ==================== 참가자 ====================
+/// 참가자 설정
+///
+/// 문자열로는 `ai:<난이도>`, `ai:<난이도>:belief`, `engine:<실행 파일>`로 적습니다.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PlayerSpec {
+    /// 이 프로젝트의 AI (`belief`가 참이면 믿음 상태 AI, 아니면 알파-베타 탐색)
+    Ai {
+        difficulty: Difficulty,
+        belief: bool,
+    },
+    /// Gomocup 프로토콜을 쓰는 외부 엔진
+    Engine { program: PathBuf },
+}
+
+impl fmt::Display for PlayerSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayerSpec::Ai {
+                difficulty,
+                belief: false,
+            } => write!(f, "ai:{}", difficulty.to_string().to_ascii_lowercase()),
+            PlayerSpec::Ai {
+                difficulty,
+                belief: true,
+            } => write!(
+                f,
+                "ai:{}:belief",
+                difficulty.to_string().to_ascii_lowercase()
+            ),
+            PlayerSpec::Engine { program } => write!(f, "engine:{}", program.display()),
+        }
+    }
+}
+
+impl FromStr for PlayerSpec {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.split_once(':') {
+            Some(("ai", rest)) => {
+                let (difficulty, belief) = match rest.split_once(':') {
+                    Some((difficulty, "belief")) => (difficulty, true),
+                    Some((difficulty, "search")) => (difficulty, false),
+                    Some(_) => return Err(format!("unknown AI strategy in \"{text}\"")),
+                    None => (rest, false),
+                };
+                Ok(PlayerSpec::Ai {
+                    difficulty: difficulty.parse()?,
+                    belief,
+                })
+            }
+            Some(("engine", program)) if !program.is_empty() => Ok(PlayerSpec::Engine {
+                program: PathBuf::from(program),
+            }),
+            _ => Err(format!(
+                "invalid player \"{text}\" (expected ai:<difficulty>[:belief] or engine:<path>)"
+            )),
+        }
+    }
+}
+
+/// 한 대국 동안의 참가자 상태
+enum Contestant {
+    /// 이 프로젝트의 AI
+    Ai {
+        profile: AiProfile,
+        belief_search: bool,
+        belief: Belief,
+        rng: Rng,
+    },
+    /// 외부 엔진 (첫 수를 둘 때 실행)
+    Engine {
+        program: PathBuf,
+        engine: Option<ExternalEngine>,
+        belief: Belief,
+        log: EngineLog,
+    },
+}
+
+impl Contestant {
+    /// 설정으로 새 대국용 참가자 생성
+    fn new(spec: &PlayerSpec, seed: u64, log: &EngineLog) -> Self {
+        match spec {
+            PlayerSpec::Ai { difficulty, belief } => Contestant::Ai {
+                profile: difficulty.profile(),
+                belief_search: *belief,
+                belief: Belief::new(),
+                rng: Rng::new(seed),
+            },
+            PlayerSpec::Engine { program } => Contestant::Engine {
+                program: program.clone(),
+                engine: None,
+                belief: Belief::new(),
+                log: log.clone(),
+            },
+        }
+    }
+
+    /// `color`로 둘 수를 고름 (실패하면 기권 사유)
+    fn choose(
+        &mut self,
+        game: &BlindGame,
+        color: StoneColor,
+        settings: &Settings,
+    ) -> Result<(usize, usize), String> {
+        let view = if settings.blind {
+            game.view(color).board()
+        } else {
+            game.game().board()
+        };
+        let rule_set = settings.rule_set;
+        match self {
+            Contestant::Ai {
+                profile,
+                belief_search: true,
+                belief,
+                rng,
+            } => {
+                let hidden = if settings.blind {
+                    game.hidden_stones(color)
+                } else {
+                    0
+                };
+                let seed = rng.next_u64();
+                let result =
+                    belief::choose_move(view, belief, color, hidden, rule_set, profile, seed);
+                result
+                    .map(|result| (result.x, result.y))
+                    .ok_or_else(|| "no move found".to_string())
+            }
+            Contestant::Ai {
+                profile,
+                belief_search: false,
+                belief,
+                rng,
+            } => {
+                let board = belief.assumed_board(view, color);
+                ai::choose_move(&board, rule_set, color, profile, rng.next_u64())
+                    .map(|result| (result.x, result.y))
+                    .ok_or_else(|| "no move found".to_string())
+            }
+            Contestant::Engine {
+                program,
+                engine,
+                belief,
+                log,
+            } => {
+                let board = belief.assumed_board(view, color);
+                let mut running = match engine.take() {
+                    Some(running) => running,
+                    None => ExternalEngine::spawn(
+                        program,
+                        &[],
+                        board.width(),
+                        board.height(),
+                        rule_set,
+                        settings.engine_timeout,
+                        log.clone(),
+                    )
+                    .map_err(|err| err.to_string())?,
+                };
+                let (x, y) = if board.is_empty() {
+                    running.begin()
+                } else {
+                    running.board(&board, color)
+                }
+                .map_err(|err| err.to_string())?;
+                *engine = Some(running); // 통신에 실패한 엔진은 버림
+                Ok((x, y))
+            }
+        }
+    }
+
+    /// 이 참가자가 받은 피드백을 반영
+    fn observe(&mut self, feedback: &Feedback) {
+        match self {
+            Contestant::Ai { belief, .. } | Contestant::Engine { belief, .. } => {
+                belief.observe(feedback)
+            }
+        }
+    }
+}
+
+// ==================== 대국 설정 ====================
+/// 모든 대국에 적용하는 설정
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Settings {
+    pub width: usize,             // 판 가로 칸 수
+    pub height: usize,            // 판 세로 칸 수
+    pub rule_set: RuleSet,        // 승리 조건과 금수 규칙
+    pub opening: OpeningRule,     // 오프닝 규칙
+    pub blind: bool,              // 블라인드 규칙 (참가자는 자신의 시야만 봄)
+    pub collision: CollisionRule, // 블라인드 규칙의 충돌 처리
+    pub engine_timeout: Duration, // 외부 엔진의 한 수 시간 제한
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_BOARD_SIZE,
+            height: DEFAULT_BOARD_SIZE,
+            rule_set: RuleSet::default(),
+            opening: OpeningRule::default(),
+            blind: false,
+            collision: CollisionRule::default(),
+            engine_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+// ==================== 대국 기록 ====================
+/// 한 대국의 기록
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GameRecord {
+    pub black: usize,               // 흑을 잡은 참가자 번호
+    pub white: usize,               // 백을 잡은 참가자 번호
+    pub moves: Vec<Move>,           // 판에 놓인 수 (오프닝 포함)
+    pub result: Option<GameResult>, // 결과 (수를 다 두지 못하고 끝나면 없음)
+    pub reason: String,             // 끝난 이유 (예: "five", "forfeit: ...")
+}
+
+impl GameRecord {
+    /// 이긴 참가자 번호
+    pub fn winner(&self) -> Option<usize> {
+        match self.result? {
+            GameResult::Win(StoneColor::Black) => Some(self.black),
+            GameResult::Win(StoneColor::White) => Some(self.white),
+            GameResult::Draw => None,
+        }
+    }
+
+    /// `player`의 점수 (승 1, 무 0.5, 패 0)
+    pub fn score(&self, player: usize) -> f64 {
+        match self.winner() {
+            Some(winner) if winner == player => 1.0,
+            Some(_) => 0.0,
+            None => 0.5,
+        }
+    }
+}
+
+/// 한 대국을 진행
+///
+/// `first`가 오프닝을 시작하는 (잠정 흑) 참가자이며, 오프닝의 색 선택에 따라 흑백이 바뀔 수 있습니다.
+/// 외부 엔진의 MESSAGE 등은 `log`에 남습니다.
+pub fn play_game(
+    specs: &[PlayerSpec],
+    first: usize,
+    second: usize,
+    settings: &Settings,
+    seed: u64,
+    log: &EngineLog,
+) -> GameRecord {
+    let mut rng = Rng::new(seed);
+    let mut contestants = [
+        Contestant::new(&specs[first], rng::mix(seed ^ 1), log),
+        Contestant::new(&specs[second], rng::mix(seed ^ 2), log),
+    ];
+    let mut game = BlindGame::with_game(
+        Game::with_size(settings.width, settings.height).with_rule_set(settings.rule_set),
+    );
+    let mut opening = Opening::with_first_player(settings.opening, Player::First);
+    play_opening(&mut opening, &mut game, &mut rng);
+
+    let index = |player: Player| match player {
+        Player::First => first,
+        Player::Second => second,
+    };
+    let mut reason = String::new();
+    let mut retries = 0;
+    while !game.game().is_over() {
+        let color = game.game().current_player();
+        let player = opening.player_of(color);
+        let contestant = &mut contestants[player.index()];
+
+        let failure = match contestant.choose(&game, color, settings) {
+            Ok((x, y)) => match game.play(x, y, settings.collision) {
+                Ok(event) => {
+                    contestant.observe(&event);
+                    match event {
+                        Feedback::Occupied { .. } | Feedback::Forbidden { .. } => {
+                            retries += 1;
+                            (retries > MAX_RETRIES).then(|| "too many retries".to_string())
+                        }
+                        Feedback::Forfeit { .. } => {
+                            reason = "forfeit: strikes".to_string();
+                            None
+                        }
+                        _ => {
+                            retries = 0;
+                            None
+                        }
+                    }
+                }
+                Err(err) => Some(format!("illegal move {x},{y} ({err})")),
+            },
+            Err(err) => Some(err),
+        };
+        if let Some(failure) = failure {
+            reason = format!("forfeit: {failure}");
+            game.resign(color);
+        }
+    }
+
+    let result = game.game().result();
+    if reason.is_empty() {
+        reason = match result {
+            Some(GameResult::Win(_)) => "five".to_string(),
+            _ => "draw".to_string(),
+        };
+    }
+    GameRecord {
+        black: index(opening.player_of(StoneColor::Black)),
+        white: index(opening.player_of(StoneColor::White)),
+        moves: game.game().history().to_vec(),
+        result,
+        reason,
+    }
+}
+
+// ==================== 오프닝 진행 ====================
+/// 오프닝을 끝까지 진행 (두 참가자 모두에게 같은 방식 적용)
+///
+/// 돌은 허용된 중앙 구역 안의 빈 자리에 무작위로 두고, 색은 평가 점수가 유리한 쪽을 고릅니다.
+/// 오프닝의 돌은 양쪽에게 공개됩니다. 둘 곳이 없어 진행할 수 없으면 그대로 본 게임을 시작합니다.
+fn play_opening(opening: &mut Opening, game: &mut BlindGame, rng: &mut Rng) {
+    let mut failures = 0;
+    while !opening.is_done() {
+        let board = game.game().board().clone();
+        let score = ai::evaluate(&board);
+        let better = if score >= 0 {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        };
+        let done = match opening.phase() {
+            Phase::Place { area, .. } => {
+                let point = random_point(&board, area.unwrap_or(OPENING_AREA), &[], rng);
+                point.is_some_and(|(x, y)| {
+                    game.play_public(|game| opening.place(game, x, y)).is_ok()
+                })
+            }
+            Phase::ChooseColor { .. } => opening.choose_color(game.game(), better).is_ok(),
+            Phase::Swap2Choice { .. } if score.abs() <= SWAP2_BALANCE => {
+                opening.place_two_more().is_ok()
+            }
+            Phase::Swap2Choice { .. } => opening.choose_color(game.game(), better).is_ok(),
+            Phase::DeclareCount { .. } => opening.declare_count(SOOSORV_DECLARED).is_ok(),
+            Phase::PlaceCandidates { .. } => {
+                let placed = opening.candidates().to_vec();
+                let point = random_point(&board, OPENING_AREA, &placed, rng);
+                point.is_some_and(|(x, y)| {
+                    game.play_public(|game| opening.place(game, x, y)).is_ok()
+                })
+            }
+            // 백을 잡은 쪽이 흑에게 가장 불리한 후보를 고름
+            Phase::ChooseCandidate { .. } => {
+                let color = game.game().current_player();
+                let chosen = opening.candidates().iter().copied().min_by_key(|&(x, y)| {
+                    let mut after = board.clone();
+                    after.set(x, y, Some(color));
+                    ai::evaluate(&after)
+                });
+                chosen.is_some_and(|(x, y)| {
+                    game.play_public(|game| opening.place(game, x, y)).is_ok()
+                })
+            }
+            Phase::TaraguchiFifth { .. } => opening.choose_fifth(false).is_ok(),
+            Phase::Done => true,
+        };
+        if done {
+            failures = 0;
+        } else {
+            failures += 1;
+            if failures > MAX_RETRIES {
+                break; // 구역 안에 둘 곳이 없음
+            }
+        }
+    }
+}
+
+/// 판 중앙 `area`x`area` 구역 안의 빈 자리 중 하나를 무작위로 고름 (`exclude`는 제외)
+fn random_point(
+    board: &Board,
+    area: usize,
+    exclude: &[(usize, usize)],
+    rng: &mut Rng,
+) -> Option<(usize, usize)> {
+    let (min_x, min_y, max_x, max_y) = opening::area_bounds(board, area);
+    let points: Vec<(usize, usize)> = (min_y..=max_y)
+        .flat_map(|y| (min_x..=max_x).map(move |x| (x, y)))
+        .filter(|&(x, y)| board.get(x, y).is_none() && !exclude.contains(&(x, y)))
+        .collect();
+    match points.len() {
+        0 => None,
+        len => Some(points[rng.below(len)]),
+    }
+}
+
+// ==================== 순위표 ====================
+/// 참가자 한 명의 성적
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Standing {
+    pub games: u32,  // 대국 수
+    pub wins: u32,   // 승
+    pub draws: u32,  // 무
+    pub losses: u32, // 패
+    pub elo: f64,    // Elo 추정치 (참가자 평균이 0)
+}
+
+impl Standing {
+    /// 점수 비율 (승 1, 무 0.5)
+    pub fn score_rate(&self) -> f64 {
+        match self.games {
+            0 => 0.0,
+            games => (self.wins as f64 + self.draws as f64 / 2.0) / games as f64,
+        }
+    }
+}
+
+/// 대국 기록으로 참가자별 성적과 Elo 추정치를 계산
+pub fn standings(players: usize, records: &[GameRecord]) -> Vec<Standing> {
+    let mut standings = vec![Standing::default(); players];
+    for record in records {
+        for player in [record.black, record.white] {
+            let standing = &mut standings[player];
+            standing.games += 1;
+            match record.winner() {
+                Some(winner) if winner == player => standing.wins += 1,
+                Some(_) => standing.losses += 1,
+                None => standing.draws += 1,
+            }
+        }
+    }
+
+    let games: Vec<(usize, usize, f64)> = records
+        .iter()
+        .map(|record| (record.black, record.white, record.score(record.black)))
+        .collect();
+    for (standing, elo) in standings.iter_mut().zip(elo_ratings(players, &games)) {
+        standing.elo = elo;
+    }
+    standings
+}
+
+/// (참가자 a, 참가자 b, a의 점수) 대국 목록으로 Elo를 추정 (참가자 평균이 0)
+///
+/// 기대 점수와 실제 점수의 차이만큼 조금씩 옮기는 방식으로 최대 가능도 추정치에 가깝게 맞춥니다.
+/// 전승이나 전패여도 값이 무한히 커지지 않도록 참가자마다 평균 상대와의 무승부 한 판을 더합니다.
+pub fn elo_ratings(players: usize, games: &[(usize, usize, f64)]) -> Vec<f64> {
+    let expected =
+        |rating: f64, opponent: f64| 1.0 / (1.0 + 10f64.powf((opponent - rating) / ELO_SCALE));
+    // 한 판의 기울기가 가장 클 때(기대 점수 0.5)를 기준으로 한 이동 크기라 발산하지 않음
+    let step = 4.0 * ELO_SCALE / std::f64::consts::LN_10;
+    let mut ratings = vec![0.0; players];
+    for _ in 0..ELO_ITERATIONS {
+        let mut gradient = vec![0.0; players];
+        let mut counts = vec![1.0; players];
+        for (player, rating) in ratings.iter().enumerate() {
+            gradient[player] += 0.5 - expected(*rating, 0.0);
+        }
+        for &(a, b, score) in games {
+            let predicted = expected(ratings[a], ratings[b]);
+            gradient[a] += score - predicted;
+            gradient[b] -= score - predicted;
+            counts[a] += 1.0;
+            counts[b] += 1.0;
+        }
+        for (player, rating) in ratings.iter_mut().enumerate() {
+            *rating += step * gradient[player] / counts[player];
+        }
+        let mean = ratings.iter().sum::<f64>() / players.max(1) as f64;
+        ratings.iter_mut().for_each(|rating| *rating -= mean);
+    }
+    ratings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `black`가 흑, `white`가 백으로 둔 대국 기록 (`result`만 채움)
+    fn record(black: usize, white: usize, result: GameResult) -> GameRecord {
+        GameRecord {
+            black,
+            white,
+            moves: Vec::new(),
+            result: Some(result),
+            reason: "five".to_string(),
+        }
+    }
+
+    #[test]
+    fn schedules_alternate_who_moves_first() {
+        assert_eq!(
+            schedule(Format::Gauntlet, 3, 2),
+            vec![(0, 1), (1, 0), (0, 2), (2, 0)]
+        );
+        assert_eq!(
+            schedule(Format::RoundRobin, 3, 2),
+            vec![(0, 1), (1, 0), (0, 2), (2, 0), (1, 2), (2, 1)]
+        );
+        assert_eq!(
+            schedule(Format::RoundRobin, 3, 3),
+            vec![
+                (0, 1),
+                (1, 0),
+                (0, 1),
+                (0, 2),
+                (2, 0),
+                (0, 2),
+                (1, 2),
+                (2, 1),
+                (1, 2)
+            ]
+        );
+        assert!(schedule(Format::Gauntlet, 1, 2).is_empty());
+        assert_eq!(
+            "gauntlet".parse::<Format>().unwrap().to_string(),
+            "gauntlet"
+        );
+        assert!("swiss".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn standings_count_wins_draws_and_losses() {
+        let records = [
+            record(0, 1, GameResult::Win(StoneColor::Black)),
+            record(1, 0, GameResult::Win(StoneColor::White)),
+            record(0, 2, GameResult::Draw),
+            record(2, 0, GameResult::Win(StoneColor::Black)),
+        ];
+        let standings = standings(3, &records);
+        let counts: Vec<(u32, u32, u32, u32)> = standings
+            .iter()
+            .map(|s| (s.games, s.wins, s.draws, s.losses))
+            .collect();
+        assert_eq!(counts, vec![(4, 2, 1, 1), (2, 0, 0, 2), (2, 1, 1, 0)]);
+        assert_eq!(standings[0].score_rate(), 2.5 / 4.0);
+        assert_eq!(Standing::default().score_rate(), 0.0);
+        assert!(standings[1].elo < standings[0].elo);
+        assert!(standings[1].elo < standings[2].elo);
+    }
+
+    #[test]
+    fn equal_results_give_equal_ratings() {
+        let games = [
+            (0, 1, 1.0),
+            (1, 0, 1.0),
+            (0, 1, 0.5),
+            (1, 2, 0.5),
+            (2, 0, 0.5),
+        ];
+        let ratings = elo_ratings(3, &games);
+        for rating in ratings {
+            assert!(rating.abs() < 1.0, "{rating}");
+        }
+        assert!(elo_ratings(0, &[]).is_empty());
+    }
+
+    #[test]
+    fn winning_everything_gives_a_higher_but_finite_rating() {
+        let games: Vec<(usize, usize, f64)> = (0..10)
+            .map(|game| {
+                if game % 2 == 0 {
+                    (0, 1, 1.0)
+                } else {
+                    (1, 0, 0.0)
+                }
+            })
+            .collect();
+        let ratings = elo_ratings(2, &games);
+        assert!(ratings[0] > ratings[1]);
+        assert!(ratings.iter().all(|rating| rating.is_finite()));
+        assert!((ratings[0] + ratings[1]).abs() < 1e-6);
+        // 이긴 판이 많을수록 차이가 커짐
+        let fewer = elo_ratings(2, &games[..2]);
+        assert!(fewer[0] < ratings[0]);
+    }
+
+    #[test]
+    fn player_specs_round_trip() {
+        for text in ["ai:hard", "ai:hard:belief", "ai:beginner", "engine:path"] {
+            let spec: PlayerSpec = text.parse().unwrap();
+            assert_eq!(spec.to_string(), text);
+        }
+        assert_eq!(
+            "ai:Expert:belief".parse::<PlayerSpec>(),
+            Ok(PlayerSpec::Ai {
+                difficulty: Difficulty::Expert,
+                belief: true
+            })
+        );
+        assert_eq!(
+            "ai:normal:search"
+                .parse::<PlayerSpec>()
+                .unwrap()
+                .to_string(),
+            "ai:normal"
+        );
+        assert_eq!(
+            "engine:bin/pbrain".parse::<PlayerSpec>(),
+            Ok(PlayerSpec::Engine {
+                program: PathBuf::from("bin/pbrain")
+            })
+        );
+        for text in ["ai:hard:mcts", "ai:godlike", "engine:", "human", "pbrain:x"] {
+            assert!(text.parse::<PlayerSpec>().is_err(), "{text}");
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! 조브리스트 해시와 치환표
+//!
+//! 판의 각 칸과 돌 색마다 고정된 64비트 난수를 정해 두고, 놓인 돌의 난수를 XOR 하여
+//! 판 전체를 하나의 값으로 나타냅니다. 돌을 놓거나 치울 때 그 자리의 난수만 XOR 하면 되므로
+//! 판을 다시 훑지 않고도 해시를 갱신할 수 있습니다.
+//! 회전과 뒤집기로 같아지는 국면을 하나로 모으는 정규화와 크기가 정해진 치환표도 제공하여
+//! AI 탐색과 오프닝 정석 조회가 같은 국면 키를 사용합니다. Bevy에 의존하지 않습니다.
+
+use std::sync::OnceLock;
+
+use super::engine::{Board, StoneColor, MAX_BOARD_SIZE};
+use super::rng::Rng;
+
+// ==================== 조브리스트 키 ====================
+/// 키 표를 만들 때 쓰는 고정 시드 (바꾸면 저장된 해시와 호환되지 않음)
+const ZOBRIST_SEED: u64 = 0x9E6D_5EED_0F60_4D0C;
+
+/// 백 차례를 나타내는 키 (흑 차례는 0)
+const WHITE_TO_MOVE_KEY: u64 = 0xA5A5_5A5A_C3C3_3C3C;
+
+/// (x, y)에 놓인 `color` 돌의 키
+///
+/// 키는 판 크기와 관계없이 좌표와 색으로만 정해집니다.
+pub fn stone_key(x: usize, y: usize, color: StoneColor) -> u64 {
+    static KEYS: OnceLock<Vec<u64>> = OnceLock::new();
+    let keys = KEYS.get_or_init(|| {
+        let mut rng = Rng::new(ZOBRIST_SEED);
+        (0..MAX_BOARD_SIZE * MAX_BOARD_SIZE * 2)
+            .map(|_| rng.next_u64())
+            .collect()
+    });
+    keys[(y * MAX_BOARD_SIZE + x) * 2 + color.index()]
+}
+
+/// 둘 차례인 색의 키 (같은 판이라도 차례가 다르면 다른 국면으로 구분할 때 사용)
+pub fn side_key(color: StoneColor) -> u64 {
+    match color {
+        StoneColor::Black => 0,
+        StoneColor::White => WHITE_TO_MOVE_KEY,
+    }
+}
+
+// ==================== 대칭 변환 ====================
+/// 판의 8가지 대칭 변환 (회전 4가지와 뒤집기 4가지)
+///
+/// 가로와 세로가 다른 판에서는 가로세로가 바뀌지 않는 4가지만 쓸 수 있습니다.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Symmetry {
+    /// 그대로
+    #[default]
+    Identity,
+    /// 시계 방향 90도 회전
+    Rotate90,
+    /// 180도 회전
+    Rotate180,
+    /// 시계 방향 270도 회전
+    Rotate270,
+    /// 좌우 뒤집기
+    FlipHorizontal,
+    /// 상하 뒤집기
+    FlipVertical,
+    /// 주대각선 기준 뒤집기 (x와 y를 맞바꿈)
+    Transpose,
+    /// 반대 대각선 기준 뒤집기
+    AntiTranspose,
+}
+
+impl Symmetry {
+    /// 모든 대칭 변환
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::Transpose,
+        Symmetry::AntiTranspose,
+    ];
+
+    /// 가로와 세로를 맞바꾸는 변환인지 확인
+    fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Symmetry::Rotate90
+                | Symmetry::Rotate270
+                | Symmetry::Transpose
+                | Symmetry::AntiTranspose
+        )
+    }
+
+    /// `width`x`height` 판에 쓸 수 있는 변환인지 확인 (정사각형이 아니면 가로세로를 바꿀 수 없음)
+    pub fn fits(self, width: usize, height: usize) -> bool {
+        width == height || !self.swaps_axes()
+    }
+
+    /// `width`x`height` 판의 (x, y)를 변환한 좌표
+    pub fn apply(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let (right, bottom) = (width - 1, height - 1);
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (bottom - y, x),
+            Symmetry::Rotate180 => (right - x, bottom - y),
+            Symmetry::Rotate270 => (y, right - x),
+            Symmetry::FlipHorizontal => (right - x, y),
+            Symmetry::FlipVertical => (x, bottom - y),
+            Symmetry::Transpose => (y, x),
+            Symmetry::AntiTranspose => (bottom - y, right - x),
+        }
+    }
+
+    /// 되돌리는 변환
+    pub fn inverse(self) -> Self {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            other => other,
+        }
+    }
+
+    /// 판 전체를 변환한 새 판
+    pub fn transform(self, board: &Board) -> Board {
+        let (width, height) = (board.width(), board.height());
+        let mut transformed = if self.swaps_axes() {
+            Board::with_size(height, width)
+        } else {
+            Board::with_size(width, height)
+        };
+        for (x, y, color) in board.stones() {
+            let (tx, ty) = self.apply(x, y, width, height);
+            transformed.set(tx, ty, Some(color));
+        }
+        transformed
+    }
+
+    /// 판을 변환했을 때의 해시 (판을 새로 만들지 않고 계산)
+    pub fn hash(self, board: &Board) -> u64 {
+        let (width, height) = (board.width(), board.height());
+        board.stones().fold(0, |hash, (x, y, color)| {
+            let (tx, ty) = self.apply(x, y, width, height);
+            hash ^ stone_key(tx, ty, color)
+        })
+    }
+}
+
+/// 대칭으로 같아지는 국면들 중 대표 국면의 해시와, 원래 판을 대표 국면으로 옮기는 변환
+///
+/// 쓸 수 있는 변환 중 해시가 가장 작은 것을 대표로 고릅니다.
+/// 대표 국면의 좌표를 원래 판으로 되돌리려면 변환의 `inverse`를 적용합니다.
+pub fn canonical(board: &Board) -> (u64, Symmetry) {
+    Symmetry::ALL
+        .into_iter()
+        .filter(|symmetry| symmetry.fits(board.width(), board.height()))
+        .map(|symmetry| (symmetry.hash(board), symmetry))
+        .min_by_key(|&(hash, _)| hash)
+        .unwrap_or((board.hash(), Symmetry::Identity))
+}
+
+// ==================== 치환표 ====================
+/// 해시로 찾는 크기가 정해진 국면 정보 표
+///
+/// 해시의 아래쪽 비트로 칸을 정하고, 같은 칸에 다른 국면이 들어오면 새 국면으로 덮어씁니다.
+/// 칸에는 해시 전체를 함께 저장하므로 다른 국면의 정보를 잘못 돌려주지 않습니다.
+#[derive(Clone, Debug)]
+pub struct TranspositionTable<V> {
+    slots: Vec<Option<(u64, V)>>, // (해시, 값)
+}
+
+impl<V> Default for TranspositionTable<V> {
+    /// 아무것도 저장하지 않는 빈 표
+    fn default() -> Self {
+        Self { slots: Vec::new() }
+    }
+}
+
+impl<V> TranspositionTable<V> {
+    /// 최대 `capacity`개(2의 거듭제곱으로 내림)를 저장하는 표 생성
+    ///
+    /// `capacity`가 0이면 아무것도 저장하지 않습니다.
+    pub fn new(capacity: usize) -> Self {
+        let size = match capacity {
+            0 => 0,
+            capacity => 1 << capacity.ilog2(),
+        };
+        Self {
+            slots: (0..size).map(|_| None).collect(),
+        }
+    }
+
+    /// 표에 저장할 수 있는 최대 개수
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// 해시에 해당하는 칸 번호
+    fn slot(&self, key: u64) -> Option<usize> {
+        match self.slots.len() {
+            0 => None,
+            size => Some(key as usize & (size - 1)),
+        }
+    }
+
+    /// 저장된 국면 정보 반환
+    pub fn get(&self, key: u64) -> Option<&V> {
+        match &self.slots[self.slot(key)?] {
+            Some((stored, value)) if *stored == key => Some(value),
+            _ => None,
+        }
+    }
+
+    /// 국면 정보를 저장 (같은 칸의 이전 정보는 덮어씀)
+    pub fn insert(&mut self, key: u64, value: V) {
+        if let Some(slot) = self.slot(key) {
+            self.slots[slot] = Some((key, value));
+        }
+    }
+
+    /// 저장된 모든 정보 삭제
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 어느 대칭으로도 자기 자신이 되지 않는 판
+    fn asymmetric(width: usize, height: usize) -> Board {
+        let mut board = Board::with_size(width, height);
+        board.set(1, 0, Some(StoneColor::Black));
+        board.set(2, 0, Some(StoneColor::Black));
+        board.set(0, 1, Some(StoneColor::White));
+        board.set(width - 1, height - 2, Some(StoneColor::White));
+        board
+    }
+
+    #[test]
+    fn every_symmetry_is_undone_by_its_inverse() {
+        for (width, height) in [(15, 15), (9, 7), (4, 11)] {
+            let board = asymmetric(width, height);
+            for symmetry in Symmetry::ALL {
+                if !symmetry.fits(width, height) {
+                    assert!(width != height);
+                    continue;
+                }
+                for (x, y) in board.points() {
+                    let (tx, ty) = symmetry.apply(x, y, width, height);
+                    assert!(tx < width && ty < height, "{symmetry:?}");
+                    assert_eq!(
+                        symmetry.inverse().apply(tx, ty, width, height),
+                        (x, y),
+                        "{symmetry:?} on {width}x{height}"
+                    );
+                }
+                let transformed = symmetry.transform(&board);
+                assert_eq!(symmetry.inverse().transform(&transformed), board);
+                assert_eq!(symmetry.hash(&board), transformed.hash());
+            }
+        }
+    }
+
+    #[test]
+    fn transformed_boards_share_a_canonical_hash() {
+        for (width, height) in [(15, 15), (9, 7)] {
+            let board = asymmetric(width, height);
+            let (hash, symmetry) = canonical(&board);
+            assert_eq!(symmetry.transform(&board).hash(), hash);
+
+            let fitting: Vec<Symmetry> = Symmetry::ALL
+                .into_iter()
+                .filter(|symmetry| symmetry.fits(width, height))
+                .collect();
+            assert_eq!(fitting.len(), if width == height { 8 } else { 4 });
+            let mut hashes = Vec::new();
+            for symmetry in fitting {
+                let transformed = symmetry.transform(&board);
+                assert_eq!(canonical(&transformed).0, hash, "{symmetry:?}");
+                hashes.push(transformed.hash());
+            }
+            // 변환한 판들은 서로 다르지만 대표는 하나
+            hashes.sort_unstable();
+            hashes.dedup();
+            assert_eq!(hashes.len(), if width == height { 8 } else { 4 });
+        }
+    }
+
+    #[test]
+    fn the_table_stays_within_its_capacity() {
+        assert_eq!(TranspositionTable::<u32>::new(100).capacity(), 64);
+        let mut table = TranspositionTable::new(64);
+        for key in 0..1_000u64 {
+            let key = key.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            table.insert(key, key);
+            assert_eq!(table.get(key), Some(&key));
+        }
+        assert_eq!(table.capacity(), 64);
+        let stored = (0..1_000u64)
+            .map(|key| key.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .filter(|&key| table.get(key).is_some())
+            .count();
+        assert!(0 < stored && stored <= 64, "{stored}");
+
+        table.clear();
+        assert_eq!(table.get(999u64.wrapping_mul(0x9E37_79B9_7F4A_7C15)), None);
+
+        // 크기가 0이면 아무것도 저장하지 않음
+        let mut empty = TranspositionTable::new(0);
+        empty.insert(1, "one");
+        assert_eq!(empty.get(1), None);
+    }
+
+    #[test]
+    fn the_table_replaces_entries_that_share_a_slot() {
+        let mut table = TranspositionTable::new(16);
+        table.insert(3, "first");
+        table.insert(4, "other slot");
+        assert_eq!(table.get(3), Some(&"first"));
+
+        // 같은 칸에 들어가는 다른 국면이 이전 정보를 덮어쓰며, 이전 국면은 찾지 못함
+        table.insert(3 + 16, "second");
+        assert_eq!(table.get(3 + 16), Some(&"second"));
+        assert_eq!(table.get(3), None);
+        assert_eq!(table.get(4), Some(&"other slot"));
+
+        // 같은 국면을 다시 저장하면 값만 바뀜
+        table.insert(3 + 16, "third");
+        assert_eq!(table.get(3 + 16), Some(&"third"));
+    }
+}
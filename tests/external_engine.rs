@@ -0,0 +1,122 @@
+//! 외부 엔진 관리 시험
+//!
+//! 함께 빌드되는 `stand_in_engine`을 자식 프로세스로 띄워 `external` 모듈을 실제 파이프로 시험합니다.
+//! 이 엔진은 판 가운데에 가장 가까운 빈 자리(같으면 y, x가 작은 쪽)에 둡니다.
+
+use std::path::Path;
+use std::time::Duration;
+
+use bevy_examples::the_devils_plan::blind_gomoku::engine::{Board, StoneColor};
+use bevy_examples::the_devils_plan::blind_gomoku::external::{
+    EngineError, EngineLog, ExternalEngine, TIMEOUT_GRACE,
+};
+use bevy_examples::the_devils_plan::blind_gomoku::rules::RuleSet;
+
+/// 한 수의 시간 제한
+const TIMEOUT_TURN: Duration = Duration::from_millis(1_000);
+
+/// `args`를 준 `stand_in_engine`을 `width`x`height` 판으로 띄움
+fn spawn(
+    args: &[&str],
+    width: usize,
+    height: usize,
+    timeout_turn: Duration,
+) -> Result<ExternalEngine, EngineError> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    ExternalEngine::spawn(
+        Path::new(env!("CARGO_BIN_EXE_stand_in_engine")),
+        &args,
+        width,
+        height,
+        RuleSet::Renju,
+        timeout_turn,
+        EngineLog::default(),
+    )
+}
+
+#[test]
+fn exchanges_moves() {
+    let mut engine = spawn(&[], 15, 15, TIMEOUT_TURN).unwrap();
+    assert_eq!(engine.begin(), Ok((7, 7)));
+    assert_eq!(engine.turn(7, 6), Ok((6, 7)));
+
+    let mut board = Board::with_size(15, 15);
+    for (x, y, color) in [
+        (7, 7, StoneColor::Black),
+        (6, 7, StoneColor::Black),
+        (7, 6, StoneColor::White),
+        (8, 7, StoneColor::White),
+    ] {
+        board.set(x, y, Some(color));
+    }
+    assert_eq!(engine.board(&board, StoneColor::White), Ok((7, 8)));
+
+    let log = engine.log().recent(usize::MAX);
+    for line in [
+        "> START 15",
+        "< OK",
+        "> INFO timeout_turn 1000",
+        "> INFO rule 4",
+        "> BEGIN",
+        "< MESSAGE move 1 closest to the center",
+        "< 7,7",
+        "> TURN 7,6",
+        "> BOARD (4 stones) DONE",
+        "< 7,8",
+    ] {
+        assert!(log.iter().any(|logged| logged == line), "{line}: {log:?}");
+    }
+}
+
+#[test]
+fn starts_rectangular_boards() {
+    let mut engine = spawn(&[], 9, 7, TIMEOUT_TURN).unwrap();
+    assert_eq!(engine.begin(), Ok((4, 3)));
+    assert_eq!(engine.log().recent(usize::MAX)[1], "> RECTSTART 9,7");
+}
+
+#[test]
+fn reports_rejected_commands() {
+    let mut engine = spawn(&[], 1, 1, TIMEOUT_TURN).unwrap();
+    let mut board = Board::with_size(1, 1);
+    board.set(0, 0, Some(StoneColor::Black));
+    assert_eq!(
+        engine.board(&board, StoneColor::White),
+        Err(EngineError::Rejected("ERROR board is full".to_string()))
+    );
+}
+
+#[test]
+fn times_out_slow_engines() {
+    let timeout_turn = Duration::from_millis(100);
+    let mut engine = spawn(&["--delay", "3000"], 15, 15, timeout_turn).unwrap();
+    assert_eq!(
+        engine.begin(),
+        Err(EngineError::TimedOut(timeout_turn + TIMEOUT_GRACE))
+    );
+    let log = engine.log().recent(1);
+    assert_eq!(log, ["! no answer within 600 ms"]);
+}
+
+#[test]
+fn detects_crashes() {
+    let mut engine = spawn(&["--crash-after", "1"], 15, 15, TIMEOUT_TURN).unwrap();
+    assert_eq!(engine.begin(), Ok((7, 7)));
+    assert!(matches!(engine.turn(8, 8), Err(EngineError::Crashed(_))));
+    assert!(engine.log().recent(1)[0].starts_with("! engine exited"));
+}
+
+#[test]
+fn reports_missing_programs() {
+    let program = Path::new(env!("CARGO_BIN_EXE_stand_in_engine")).with_file_name("no_such_engine");
+    let result = ExternalEngine::spawn(
+        &program,
+        &[],
+        15,
+        15,
+        RuleSet::Freestyle,
+        TIMEOUT_TURN,
+        EngineLog::default(),
+    );
+    assert!(matches!(result, Err(EngineError::Spawn(path, _)) if path == program));
+}